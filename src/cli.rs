@@ -78,10 +78,27 @@ struct Cli {
     requirements_file: Vec<String>,
     #[arg(long, default_values = vec!["requirements-dev.txt"], help = "Development requirements file to migrate")]
     dev_requirements_file: Vec<String>,
+    #[arg(
+        long,
+        value_name = "GROUP=FILE",
+        value_parser = parse_requirements_group,
+        help = "Requirements file to migrate to a dependency group"
+    )]
+    requirements_group: Vec<(String, String)>,
     #[command(flatten)]
     verbose: Verbosity<InfoLevel>,
 }
 
+/// Parse a requirements file mapped to a dependency group (e.g. `test=requirements-test.txt`).
+fn parse_requirements_group(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((group, file)) if !group.trim().is_empty() && !file.trim().is_empty() => {
+            Ok((group.trim().to_string(), file.trim().to_string()))
+        }
+        _ => Err("expected a value in the form `GROUP=FILE`".to_string()),
+    }
+}
+
 pub fn cli() {
     let cli = Cli::parse();
 
@@ -105,6 +122,7 @@ pub fn cli() {
         &converter_options,
         cli.requirements_file,
         cli.dev_requirements_file,
+        cli.requirements_group,
         cli.package_manager,
     ) {
         Ok(converter) => {
@@ -0,0 +1,286 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::conda::Dependency;
+use owo_colors::OwoColorize;
+use pep508_rs::Requirement;
+use std::str::FromStr;
+use url::Url;
+
+/// Conda packages that are published on `PyPI` under a different name.
+const PYPI_NAMES: [(&str, &str); 9] = [
+    ("matplotlib-base", "matplotlib"),
+    ("msgpack-python", "msgpack"),
+    ("py-opencv", "opencv-python"),
+    ("pyqt", "pyqt5"),
+    ("python-graphviz", "graphviz"),
+    ("python-kaleido", "kaleido"),
+    ("pytables", "tables"),
+    ("pytorch", "torch"),
+    ("ruamel_yaml", "ruamel.yaml"),
+];
+
+/// Conda packages that do not have an equivalent on `PyPI`, usually because they are system
+/// libraries or tools that conda also manages.
+const CONDA_ONLY_PACKAGES: [&str; 46] = [
+    "blas",
+    "bzip2",
+    "c-compiler",
+    "ca-certificates",
+    "compilers",
+    "cudatoolkit",
+    "cudnn",
+    "curl",
+    "cxx-compiler",
+    "ffmpeg",
+    "fortran-compiler",
+    "gcc",
+    "geos",
+    "gfortran",
+    "git",
+    "graphviz",
+    "gxx",
+    "hdf5",
+    "ld_impl_linux-64",
+    "libblas",
+    "libcblas",
+    "libcurl",
+    "libffi",
+    "libgcc",
+    "libgcc-ng",
+    "libgomp",
+    "liblapack",
+    "libopenblas",
+    "libstdcxx-ng",
+    "libuuid",
+    "libzlib",
+    "make",
+    "mkl",
+    "ncurses",
+    "nodejs",
+    "nomkl",
+    "openblas",
+    "openjdk",
+    "openssl",
+    "pandoc",
+    "postgresql",
+    "proj",
+    "readline",
+    "sqlite",
+    "tk",
+    "xz",
+];
+
+/// Dependencies and Python requirement extracted from conda environment dependencies.
+#[derive(Default, Debug, Eq, PartialEq)]
+#[allow(clippy::struct_field_names)]
+pub struct Dependencies {
+    pub dependencies: Option<Vec<String>>,
+    pub requires_python: Option<String>,
+    /// Whether some dependencies could not be migrated, in which case the environment file is the
+    /// only remaining record of them.
+    pub is_partially_migrated: bool,
+}
+
+pub fn get(conda_dependencies: Option<Vec<Dependency>>, environment_file: &str) -> Dependencies {
+    let mut dependencies: Vec<String> = Vec::new();
+    let mut requires_python: Option<String> = None;
+    let mut conda_only_packages: Vec<String> = Vec::new();
+    let mut is_partially_migrated = false;
+
+    for dependency in conda_dependencies.unwrap_or_default() {
+        match dependency {
+            Dependency::Conda(match_spec) => {
+                let Some((name, version)) = parse_match_spec(&match_spec) else {
+                    add_recoverable_error(format!(
+                        "\"{}\" from \"{}\" could not be automatically migrated, try running \"{}\".",
+                        match_spec.bold(),
+                        environment_file.bold(),
+                        format!("uv add {match_spec}").bold(),
+                    ));
+                    is_partially_migrated = true;
+                    continue;
+                };
+
+                match name.as_str() {
+                    "python" => {
+                        requires_python = version;
+                    }
+                    // pip is not needed anymore once the project is managed by uv.
+                    "pip" => (),
+                    _ if is_conda_only(&name) => conda_only_packages.push(match_spec),
+                    _ => dependencies.push(format!(
+                        "{}{}",
+                        get_pypi_name(&name),
+                        version.unwrap_or_default()
+                    )),
+                }
+            }
+            Dependency::Pip(pip_dependencies) => {
+                for pip_dependency in pip_dependencies {
+                    if let Ok(requirement) = Requirement::<Url>::from_str(&pip_dependency) {
+                        dependencies.push(requirement.to_string());
+                    } else {
+                        add_recoverable_error(format!(
+                            "\"{}\" from \"{}\" could not be automatically migrated, try running \"{}\".",
+                            pip_dependency.bold(),
+                            environment_file.bold(),
+                            format!("uv add {pip_dependency}").bold(),
+                        ));
+                        is_partially_migrated = true;
+                    }
+                }
+            }
+        }
+    }
+
+    if !conda_only_packages.is_empty() {
+        is_partially_migrated = true;
+        add_recoverable_error(format!(
+            "The following conda packages from \"{}\" do not have an equivalent on PyPI, so they were not migrated: {}.",
+            environment_file.bold(),
+            conda_only_packages
+                .iter()
+                .map(|p| format!("\"{}\"", p.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+    }
+
+    Dependencies {
+        dependencies: if dependencies.is_empty() {
+            None
+        } else {
+            Some(dependencies)
+        },
+        requires_python,
+        is_partially_migrated,
+    }
+}
+
+fn is_conda_only(name: &str) -> bool {
+    // R packages and conda internal packages (e.g. `_libgcc_mutex`).
+    CONDA_ONLY_PACKAGES.contains(&name) || name.starts_with("r-") || name.starts_with('_')
+}
+
+fn get_pypi_name(name: &str) -> &str {
+    PYPI_NAMES
+        .iter()
+        .find(|(conda_name, _)| *conda_name == name)
+        .map_or(name, |(_, pypi_name)| pypi_name)
+}
+
+/// Parse a conda match specification
+/// (<https://docs.conda.io/projects/conda-build/en/latest/resources/package-spec.html#package-match-specifications>)
+/// into the package name and a PEP 440 version specifier. Build strings are not relevant for
+/// `PyPI`, so they are ignored.
+///
+/// Returns `None` if the specification cannot be expressed with PEP 440 (e.g. when using `|`
+/// operator, or bracket syntax).
+fn parse_match_spec(match_spec: &str) -> Option<(String, Option<String>)> {
+    // Remove channel, and optional subdirectory (e.g. `conda-forge/linux-64::numpy`).
+    let match_spec = match_spec
+        .rsplit_once("::")
+        .map_or(match_spec, |(_, spec)| spec)
+        .trim();
+
+    if match_spec.contains(['|', '[']) {
+        return None;
+    }
+
+    let name_end = match_spec
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(match_spec.len());
+    let name = match_spec[..name_end].to_lowercase();
+    let version = match_spec[name_end..].trim();
+
+    if name.is_empty() {
+        return None;
+    }
+    if version.is_empty() {
+        return Some((name, None));
+    }
+
+    let version = if let Some(version) = version.strip_prefix('=')
+        && !version.starts_with('=')
+    {
+        // `name=version=build` syntax, where `=version` matches `version.*`.
+        let version = version.split('=').next().unwrap_or_default();
+        format!("=={}", to_wildcard(version))
+    } else {
+        // `name version build` syntax, or `name<operator>version` syntax.
+        let version = version.split_whitespace().next().unwrap_or_default();
+
+        version
+            .split(',')
+            .map(|specifier| {
+                if specifier.starts_with(['<', '>', '!', '~']) {
+                    specifier.to_string()
+                } else if let Some(specifier) = specifier.strip_prefix("==") {
+                    format!("=={specifier}")
+                } else if specifier.ends_with('*') {
+                    format!("=={}", to_wildcard(specifier))
+                } else {
+                    format!("=={specifier}")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+
+    Some((name, Some(version)))
+}
+
+/// Convert a conda fuzzy version (e.g. `1.2`, `1.2*` or `1.2.*`) to a PEP 440 wildcard version.
+fn to_wildcard(version: &str) -> String {
+    let version = version.trim_end_matches('*').trim_end_matches('.');
+    format!("{version}.*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("numpy", Some(("numpy", None)))]
+    #[case("NumPy", Some(("numpy", None)))]
+    #[case("numpy=1.26", Some(("numpy", Some("==1.26.*"))))]
+    #[case("numpy=1.26.*", Some(("numpy", Some("==1.26.*"))))]
+    #[case("numpy=1.26.4=py312h8753938_0", Some(("numpy", Some("==1.26.4.*"))))]
+    #[case("numpy==1.26.4", Some(("numpy", Some("==1.26.4"))))]
+    #[case("numpy>=1.26,<2", Some(("numpy", Some(">=1.26,<2"))))]
+    #[case("numpy 1.26.*", Some(("numpy", Some("==1.26.*"))))]
+    #[case("numpy 1.26.4 py312h8753938_0", Some(("numpy", Some("==1.26.4"))))]
+    #[case("conda-forge::numpy>=1.26", Some(("numpy", Some(">=1.26"))))]
+    #[case("conda-forge/linux-64::numpy", Some(("numpy", None)))]
+    #[case("numpy>=1.26|<1.20", None)]
+    #[case("numpy[version='>=1.26']", None)]
+    fn test_parse_match_spec(
+        #[case] match_spec: &str,
+        #[case] expected: Option<(&str, Option<&str>)>,
+    ) {
+        assert_eq!(
+            parse_match_spec(match_spec),
+            expected.map(|(name, version)| (name.to_string(), version.map(ToString::to_string)))
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let conda_dependencies = vec![
+            Dependency::Conda("python=3.12".to_string()),
+            Dependency::Conda("pip".to_string()),
+            Dependency::Conda("pytorch>=2.5".to_string()),
+            Dependency::Conda("cudatoolkit=11.8".to_string()),
+            Dependency::Pip(vec!["requests>=2.31".to_string()]),
+        ];
+
+        assert_eq!(
+            get(Some(conda_dependencies), "environment.yml"),
+            Dependencies {
+                dependencies: Some(vec!["torch>=2.5".to_string(), "requests>=2.31".to_string()]),
+                requires_python: Some("==3.12.*".to_string()),
+                is_partially_migrated: true,
+            }
+        );
+    }
+}
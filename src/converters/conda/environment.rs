@@ -0,0 +1,189 @@
+use crate::schema::conda::{Dependency, Environment};
+
+/// Parse a conda environment file. Environment files only use a small subset of YAML (scalars,
+/// and block sequences that can nest a `pip` sequence), so we do not need a complete YAML parser.
+///
+/// Returns the offending line as an error if a sequence is written using YAML syntax that is not
+/// supported (e.g. flow sequences like `dependencies: [numpy, pip]`), as silently skipping it would
+/// lead to missing dependencies.
+pub fn parse(content: &str) -> Result<Environment, String> {
+    let mut environment = Environment::default();
+    let mut current_key: Option<String> = None;
+    // Indentation of the `- pip:` item, if we are currently reading pip dependencies.
+    let mut pip_indentation: Option<usize> = None;
+
+    for line in content.lines() {
+        let line = strip_comment(line).trim_end();
+
+        if line.trim().is_empty() || line.trim() == "---" {
+            continue;
+        }
+
+        let indentation = line.len() - line.trim_start().len();
+        let line = line.trim_start();
+
+        if indentation == 0 && !line.starts_with('-') {
+            pip_indentation = None;
+
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            let key = key.trim().to_string();
+            let value = unquote(value.trim());
+
+            if key == "name" && !value.is_empty() {
+                environment.name = Some(value.to_string());
+            }
+            if matches!(key.as_str(), "channels" | "dependencies") && !value.is_empty() {
+                return Err(line.to_string());
+            }
+
+            current_key = Some(key);
+            continue;
+        }
+
+        let Some(item) = line.strip_prefix('-') else {
+            continue;
+        };
+        let item = unquote(item.trim());
+
+        if matches!(current_key.as_deref(), Some("channels" | "dependencies"))
+            && (item.starts_with(['[', '{']) || item.contains(": "))
+        {
+            return Err(line.to_string());
+        }
+
+        match current_key.as_deref() {
+            Some("channels") => environment
+                .channels
+                .get_or_insert_default()
+                .push(item.to_string()),
+            Some("dependencies") => {
+                let dependencies = environment.dependencies.get_or_insert_default();
+
+                if let Some(pip_indentation) = pip_indentation
+                    && indentation > pip_indentation
+                {
+                    if let Some(Dependency::Pip(pip_dependencies)) = dependencies.last_mut() {
+                        pip_dependencies.push(item.to_string());
+                    }
+                    continue;
+                }
+
+                if item
+                    .strip_suffix(':')
+                    .is_some_and(|item| item.trim() == "pip")
+                {
+                    pip_indentation = Some(indentation);
+                    dependencies.push(Dependency::Pip(Vec::new()));
+                } else {
+                    pip_indentation = None;
+                    dependencies.push(Dependency::Conda(item.to_string()));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    Ok(environment)
+}
+
+/// Remove comments, which start with `#` at the beginning of the line, or after a whitespace.
+fn strip_comment(line: &str) -> &str {
+    if line.trim_start().starts_with('#') {
+        return "";
+    }
+
+    match line.find(" #") {
+        Some(position) => &line[..position],
+        None => line,
+    }
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(unquoted) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return unquoted;
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_parse() {
+        let content = r#"
+# A comment
+name: foo
+channels:
+  - conda-forge
+  - defaults
+dependencies:
+  - python=3.12  # Another comment
+  - "numpy>=1.26"
+  - pip
+  - pip:
+    - requests>=2.31
+    - 'httpx[cli]==0.28.1'
+  - pandas
+variables:
+  FOO: bar
+"#;
+
+        assert_eq!(
+            parse(content),
+            Ok(Environment {
+                name: Some("foo".to_string()),
+                channels: Some(vec!["conda-forge".to_string(), "defaults".to_string()]),
+                dependencies: Some(vec![
+                    Dependency::Conda("python=3.12".to_string()),
+                    Dependency::Conda("numpy>=1.26".to_string()),
+                    Dependency::Conda("pip".to_string()),
+                    Dependency::Pip(vec![
+                        "requests>=2.31".to_string(),
+                        "httpx[cli]==0.28.1".to_string(),
+                    ]),
+                    Dependency::Conda("pandas".to_string()),
+                ]),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_unindented_sequences() {
+        let content = r"
+dependencies:
+- numpy
+- pip:
+  - requests
+";
+
+        assert_eq!(
+            parse(content),
+            Ok(Environment {
+                dependencies: Some(vec![
+                    Dependency::Conda("numpy".to_string()),
+                    Dependency::Pip(vec!["requests".to_string()]),
+                ]),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("dependencies: [numpy, pip]", "dependencies: [numpy, pip]")]
+    #[case("channels: [conda-forge]", "channels: [conda-forge]")]
+    #[case(
+        "dependencies:\n  - numpy\n  - {pip: [requests]}",
+        "- {pip: [requests]}"
+    )]
+    #[case("dependencies:\n  - numpy\n  - pip: [requests]", "- pip: [requests]")]
+    fn test_parse_unsupported_syntax(#[case] content: &str, #[case] expected: &str) {
+        assert_eq!(parse(content), Err(expected.to_string()));
+    }
+}
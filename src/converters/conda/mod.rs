@@ -0,0 +1,117 @@
+mod dependencies;
+mod environment;
+
+use crate::converters::Converter;
+use crate::converters::ConverterOptions;
+use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::errors::{add_recoverable_error, add_unrecoverable_error};
+use crate::schema::conda::Environment;
+use crate::schema::pep_621::Project;
+use crate::schema::pyproject::PyProject;
+use crate::schema::uv::Uv;
+use crate::toml::PyprojectPrettyFormatter;
+use owo_colors::OwoColorize;
+use std::fs;
+use toml_edit::DocumentMut;
+use toml_edit::visit_mut::VisitMut;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Conda {
+    pub converter_options: ConverterOptions,
+    pub environment_file: String,
+}
+
+impl Conda {
+    fn parse_environment(&self) -> Result<Environment, String> {
+        let environment_content =
+            fs::read_to_string(self.get_project_path().join(&self.environment_file)).unwrap();
+
+        environment::parse(&environment_content)
+    }
+}
+
+impl Converter for Conda {
+    fn build_uv_pyproject(&self) -> String {
+        let pyproject_toml_content =
+            fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
+        let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+        let environment = self.parse_environment().unwrap_or_else(|line| {
+            add_unrecoverable_error(format!(
+                "\"{}\" from \"{}\" uses YAML syntax that is not supported, use block sequences instead (one \"{}\" per line).",
+                line.bold(),
+                self.environment_file.bold(),
+                "- <item>".bold(),
+            ));
+            Environment::default()
+        });
+
+        let dependencies = dependencies::get(environment.dependencies, &self.environment_file);
+
+        let project = Project {
+            // "name" is required by uv.
+            name: Some(environment.name.unwrap_or_default()),
+            requires_python: dependencies.requires_python,
+            dependencies: dependencies.dependencies,
+            ..Default::default()
+        };
+
+        let uv = Uv {
+            package: Some(false),
+            ..Default::default()
+        };
+
+        let mut updated_pyproject = pyproject_toml_content.parse::<DocumentMut>().unwrap();
+        let mut pyproject_updater = PyprojectUpdater {
+            pyproject: &mut updated_pyproject,
+        };
+
+        pyproject_updater.insert_pep_621(&self.build_project(
+            pyproject.project,
+            project,
+            "0.0.1".to_string(),
+        ));
+        pyproject_updater.insert_uv(&uv);
+
+        let mut visitor = PyprojectPrettyFormatter::default();
+        visitor.visit_document_mut(&mut updated_pyproject);
+
+        updated_pyproject.to_string()
+    }
+
+    fn get_package_manager_name(&self) -> String {
+        "conda".to_string()
+    }
+
+    fn get_converter_options(&self) -> &ConverterOptions {
+        &self.converter_options
+    }
+
+    fn respect_locked_versions(&self) -> bool {
+        // Environment files do not lock dependencies.
+        false
+    }
+
+    fn get_migrated_files_to_delete(&self) -> Vec<String> {
+        // Keep the environment file if anything could not be migrated, as it would otherwise be
+        // the only record of what is missing.
+        let fully_migrated = self.parse_environment().is_ok_and(|environment| {
+            !dependencies::get(environment.dependencies, &self.environment_file)
+                .is_partially_migrated
+        });
+
+        if fully_migrated {
+            return vec![self.environment_file.clone()];
+        }
+
+        add_recoverable_error(format!(
+            "\"{}\" was not deleted, as some of its content could not be migrated.",
+            self.environment_file.bold(),
+        ));
+        Vec::new()
+    }
+
+    fn get_constraint_dependencies(&self) -> Option<Vec<String>> {
+        None
+    }
+}
@@ -0,0 +1,200 @@
+use crate::converters::{DependencyGroupsAndDefaultGroups, DependencyGroupsStrategy};
+use crate::errors::add_recoverable_error;
+use crate::schema::hatch::Env;
+use crate::schema::pyproject::DependencyGroupSpecification;
+use crate::schema::utils::SingleOrVec;
+use indexmap::IndexMap;
+use owo_colors::OwoColorize;
+use pep508_rs::Requirement;
+use std::str::FromStr;
+use url::Url;
+
+/// Hatch's `default` environment is the one used during development, so it is migrated to uv's
+/// `dev` dependency group, which is installed by default.
+fn get_group_name(env: &str) -> String {
+    if env == "default" {
+        "dev".to_string()
+    } else {
+        env.to_string()
+    }
+}
+
+fn get(dependencies: &[String], group: &str) -> Vec<String> {
+    let mut pep_508_dependencies: Vec<String> = Vec::new();
+
+    for dependency in dependencies {
+        if let Ok(requirement) = Requirement::<Url>::from_str(dependency) {
+            pep_508_dependencies.push(requirement.to_string());
+        } else {
+            add_recoverable_error(format!(
+                "\"{}\" from \"{}\" group could not be automatically migrated, try running \"{}\".",
+                dependency.bold(),
+                group.bold(),
+                format!("uv add --group {group} {dependency}").bold(),
+            ));
+        }
+    }
+
+    pep_508_dependencies
+}
+
+/// Convert a Hatch environment into the specifications of a dependency group:
+/// - `dependencies` and `extra-dependencies` are added as is
+/// - `features` are added as a reference to the project itself, with the features as extras
+/// - if the environment inherits from a template environment
+///   (<https://hatch.pypa.io/latest/config/environment/overview/#inheritance>) without overriding
+///   its `dependencies`, the dependency group of the template is included with `include-group`
+fn get_env_dependency_group(
+    env_name: &str,
+    env: &Env,
+    envs: &IndexMap<String, Env>,
+    project_name: Option<&str>,
+) -> Vec<DependencyGroupSpecification> {
+    let group = get_group_name(env_name);
+    let mut specifications: Vec<DependencyGroupSpecification> = Vec::new();
+
+    let detached = env.detached.unwrap_or(false);
+    let template = env.template.as_deref().unwrap_or("default");
+
+    if !detached
+        && template != env_name
+        && envs.contains_key(template)
+        && env.dependencies.is_none()
+    {
+        specifications.push(DependencyGroupSpecification::Map {
+            include_group: Some(get_group_name(template)),
+        });
+    }
+
+    for dependencies in [&env.dependencies, &env.extra_dependencies]
+        .into_iter()
+        .flatten()
+    {
+        specifications.extend(
+            get(dependencies, &group)
+                .into_iter()
+                .map(DependencyGroupSpecification::String),
+        );
+    }
+
+    // Features are only installed alongside the project, which detached environments skip.
+    if let Some(features) = &env.features
+        && !features.is_empty()
+        && !detached
+        && !env.skip_install.unwrap_or(false)
+    {
+        if let Some(project_name) = project_name {
+            specifications.push(DependencyGroupSpecification::String(format!(
+                "{project_name}[{}]",
+                features.join(",")
+            )));
+        } else {
+            add_recoverable_error(format!(
+                "Features from \"{}\" could not be migrated, as \"{}\" is not set under \"{}\".",
+                format!("tool.hatch.envs.{env_name}.features").bold(),
+                "name".bold(),
+                "[project]".bold(),
+            ));
+        }
+    }
+
+    specifications
+}
+
+pub fn get_dependency_groups_and_default_groups(
+    existing_dependency_groups: Option<IndexMap<String, Vec<DependencyGroupSpecification>>>,
+    hatch_envs: Option<&IndexMap<String, Env>>,
+    project_name: Option<&str>,
+    dependency_groups_strategy: Option<DependencyGroupsStrategy>,
+) -> DependencyGroupsAndDefaultGroups {
+    let mut dependency_groups = existing_dependency_groups.unwrap_or_default();
+    let mut default_groups: Vec<String> = Vec::new();
+    let mut all_default_groups = false;
+
+    if let Some(hatch_envs) = hatch_envs {
+        for (env_name, env) in hatch_envs {
+            let group = match dependency_groups_strategy {
+                Some(DependencyGroupsStrategy::MergeIntoDev) => "dev".to_string(),
+                _ => get_group_name(env_name),
+            };
+
+            let specifications: Vec<DependencyGroupSpecification> = get_env_dependency_group(
+                env_name,
+                env,
+                hatch_envs,
+                project_name,
+            )
+            .into_iter()
+            // A group cannot include itself, which can happen when merging environments
+            // that inherit from `default` one into `dev` group.
+            .filter(|specification| {
+                !matches!(
+                    specification,
+                    DependencyGroupSpecification::Map { include_group: Some(g) } if *g == group
+                )
+            })
+            .collect();
+
+            if !specifications.is_empty() {
+                dependency_groups
+                    .entry(group)
+                    .or_default()
+                    .extend(specifications);
+            }
+        }
+    }
+
+    if dependency_groups.is_empty() {
+        return (None, None);
+    }
+
+    // If we only have `dev` dependency group, there is nothing to do, as this is already uv's
+    // default.
+    if !dependency_groups.keys().eq(["dev"]) {
+        match dependency_groups_strategy {
+            None | Some(DependencyGroupsStrategy::SetDefaultGroupsAll) => {
+                all_default_groups = true;
+            }
+            Some(DependencyGroupsStrategy::SetDefaultGroups) => {
+                default_groups.extend(dependency_groups.keys().map(ToString::to_string));
+            }
+            // Groups that already include `dev` one (because their environment inherits from
+            // `default` environment) are not included in `dev`, to avoid cycles.
+            Some(DependencyGroupsStrategy::IncludeInDev) => {
+                let groups_to_include: Vec<String> = dependency_groups
+                    .iter()
+                    .filter(|(group, specifications)| {
+                        *group != "dev"
+                            && !specifications.iter().any(|specification| {
+                                matches!(
+                                    specification,
+                                    DependencyGroupSpecification::Map { include_group: Some(g) } if g == "dev"
+                                )
+                            })
+                    })
+                    .map(|(group, _)| group.clone())
+                    .collect();
+
+                dependency_groups
+                    .entry("dev".to_string())
+                    .or_default()
+                    .extend(groups_to_include.into_iter().map(|g| {
+                        DependencyGroupSpecification::Map {
+                            include_group: Some(g),
+                        }
+                    }));
+            }
+            _ => (),
+        }
+    }
+
+    let default_groups = if all_default_groups {
+        Some(SingleOrVec::Single("all".to_string()))
+    } else if default_groups.is_empty() {
+        None
+    } else {
+        Some(SingleOrVec::Vec(default_groups))
+    };
+
+    (Some(dependency_groups), default_groups)
+}
@@ -0,0 +1,245 @@
+mod dependencies;
+
+use crate::converters::Converter;
+use crate::converters::ConverterOptions;
+use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::errors::add_recoverable_error;
+use crate::schema::pep_621::Project;
+use crate::schema::pyproject::PyProject;
+use crate::schema::uv::Uv;
+use crate::toml::PyprojectPrettyFormatter;
+use owo_colors::OwoColorize;
+use std::fs;
+use toml_edit::DocumentMut;
+use toml_edit::visit_mut::VisitMut;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hatch {
+    pub converter_options: ConverterOptions,
+}
+
+impl Converter for Hatch {
+    fn build_uv_pyproject(&self) -> String {
+        let pyproject_toml_content =
+            fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
+        let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+        let hatch = pyproject.tool.unwrap_or_default().hatch.unwrap_or_default();
+
+        let (dependency_groups, uv_default_groups) =
+            dependencies::get_dependency_groups_and_default_groups(
+                pyproject.dependency_groups,
+                hatch.envs.as_ref(),
+                pyproject
+                    .project
+                    .as_ref()
+                    .and_then(|project| project.name.as_deref()),
+                self.get_dependency_groups_strategy(),
+            );
+
+        for (env_name, env) in hatch.envs.iter().flatten() {
+            if let Some(scripts) = &env.scripts {
+                add_recoverable_error(format!(
+                    "Scripts from \"{}\" ({}) cannot be migrated, as uv does not support defining scripts.",
+                    format!("tool.hatch.envs.{env_name}.scripts").bold(),
+                    scripts
+                        .keys()
+                        .map(|s| format!("\"{}\"", s.bold()))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ));
+            }
+        }
+
+        // Hatch projects rely on PEP 621 for project metadata, so the existing `[project]` section
+        // already holds everything we need.
+        let project = Project {
+            // "name" is required by uv.
+            name: Some(String::new()),
+            ..Default::default()
+        };
+
+        let uv = Uv {
+            default_groups: uv_default_groups,
+            ..Uv::default()
+        };
+
+        let mut updated_pyproject = pyproject_toml_content.parse::<DocumentMut>().unwrap();
+        let mut pyproject_updater = PyprojectUpdater {
+            pyproject: &mut updated_pyproject,
+        };
+
+        pyproject_updater.insert_pep_621(&self.build_project(
+            pyproject.project,
+            project,
+            "0.0.1".to_string(),
+        ));
+        pyproject_updater.insert_dependency_groups(dependency_groups.as_ref());
+        pyproject_updater.insert_uv(&uv);
+
+        self.remove_pyproject_hatch_envs_section(&mut updated_pyproject);
+
+        let mut visitor = PyprojectPrettyFormatter::default();
+        visitor.visit_document_mut(&mut updated_pyproject);
+
+        updated_pyproject.to_string()
+    }
+
+    fn get_package_manager_name(&self) -> String {
+        "Hatch".to_string()
+    }
+
+    fn get_converter_options(&self) -> &ConverterOptions {
+        &self.converter_options
+    }
+
+    fn respect_locked_versions(&self) -> bool {
+        // Hatch environments do not lock dependencies.
+        false
+    }
+
+    fn get_migrated_files_to_delete(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn get_constraint_dependencies(&self) -> Option<Vec<String>> {
+        None
+    }
+}
+
+impl Hatch {
+    /// Remove `[tool.hatch.envs]` section from `pyproject.toml`, unless user has explicitly asked
+    /// for the old metadata to be kept. Other Hatch settings are related to the build backend,
+    /// which is kept as is, so they are not removed.
+    fn remove_pyproject_hatch_envs_section(&self, pyproject: &mut DocumentMut) {
+        if self.keep_old_metadata() {
+            return;
+        }
+
+        if let Some(tool) = pyproject.get_mut("tool")
+            && let Some(tool_table) = tool.as_table_mut()
+            && let Some(hatch) = tool_table.get_mut("hatch")
+            && let Some(hatch_table) = hatch.as_table_mut()
+        {
+            hatch_table.remove("envs");
+
+            if hatch_table.is_empty() {
+                tool_table.remove("hatch");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converters::DependencyGroupsStrategy;
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_dependency_groups_strategy_include_in_dev() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        let pyproject_content = r#"
+[project]
+name = "foo"
+version = "1.2.3"
+
+[tool.hatch.envs.default]
+dependencies = ["pytest>=8"]
+
+[tool.hatch.envs.cov]
+extra-dependencies = ["coverage>=7"]
+
+[tool.hatch.envs.lint]
+detached = true
+dependencies = ["ruff>=0.8"]
+        "#;
+
+        let mut pyproject_file = File::create(project_path.join("pyproject.toml")).unwrap();
+        pyproject_file
+            .write_all(pyproject_content.as_bytes())
+            .unwrap();
+
+        let hatch = Hatch {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                dependency_groups_strategy: Some(DependencyGroupsStrategy::IncludeInDev),
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(hatch.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        version = "1.2.3"
+
+        [dependency-groups]
+        dev = [
+            "pytest>=8",
+            { include-group = "lint" },
+        ]
+        cov = [
+            { include-group = "dev" },
+            "coverage>=7",
+        ]
+        lint = ["ruff>=0.8"]
+        "#);
+    }
+
+    #[test]
+    fn test_dependency_groups_strategy_merge_into_dev() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        let pyproject_content = r#"
+[project]
+name = "foo"
+version = "1.2.3"
+
+[tool.hatch.envs.default]
+dependencies = ["pytest>=8"]
+
+[tool.hatch.envs.cov]
+extra-dependencies = ["coverage>=7"]
+
+[tool.hatch.envs.lint]
+detached = true
+dependencies = ["ruff>=0.8"]
+        "#;
+
+        let mut pyproject_file = File::create(project_path.join("pyproject.toml")).unwrap();
+        pyproject_file
+            .write_all(pyproject_content.as_bytes())
+            .unwrap();
+
+        let hatch = Hatch {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                dependency_groups_strategy: Some(DependencyGroupsStrategy::MergeIntoDev),
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(hatch.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        version = "1.2.3"
+
+        [dependency-groups]
+        dev = [
+            "pytest>=8",
+            "coverage>=7",
+            "ruff>=0.8",
+        ]
+        "#);
+    }
+}
@@ -12,15 +12,40 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::fs::{File, remove_file};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::sync::Mutex;
 use std::{format, fs};
 use toml_edit::DocumentMut;
 
+pub mod conda;
+mod env_vars;
+pub mod hatch;
+pub mod pdm;
 pub mod pip;
 pub mod pipenv;
 pub mod poetry;
 mod pyproject_updater;
+pub mod setuptools;
+
+/// Files generated during the migration in addition to `pyproject.toml` (e.g. build hooks), as
+/// pairs of path relative to the project and content.
+static GENERATED_FILES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+/// Register a file to generate during the migration. Existing files are never overwritten, so the
+/// file is not registered if it already exists, in which case `false` is returned, and converters
+/// are expected to report an error.
+fn add_generated_file(project_path: &Path, path: &str, content: String) -> bool {
+    if project_path.join(path).exists() {
+        return false;
+    }
+
+    GENERATED_FILES
+        .lock()
+        .unwrap()
+        .push((path.to_string(), content));
+    true
+}
 
 type DependencyGroupsAndDefaultGroups = (
     Option<IndexMap<String, Vec<DependencyGroupSpecification>>>,
@@ -61,6 +86,9 @@ pub trait Converter: Any + Debug {
                 "Migrated pyproject.toml:".bold(),
                 updated_pyproject_string
             );
+            for (path, content) in GENERATED_FILES.lock().unwrap().iter() {
+                info!("{}\n{}", format!("Generated {path}:").bold(), content);
+            }
             self.manage_migration_warnings();
             return;
         }
@@ -71,11 +99,13 @@ pub trait Converter: Any + Debug {
             .write_all(updated_pyproject_string.as_bytes())
             .unwrap();
 
+        let generated_files = self.write_generated_files();
+
         // If we were not able to lock dependencies with `uv lock`, we abort the migration, and
         // either revert `pyproject.toml` file to its original content, or delete it if there was
         // none.
         if self.lock_dependencies() == Err(()) {
-            self.revert_changes(had_pyproject, old_pyproject);
+            self.revert_changes(had_pyproject, old_pyproject, &generated_files);
 
             error!(
                 "Could not lock dependencies, aborting the migration. Consider using \"{}\" if you don't need to keep versions from the lock file, or \"{}\" if you don't want to lock dependencies at all.",
@@ -86,6 +116,11 @@ pub trait Converter: Any + Debug {
         }
 
         self.remove_constraint_dependencies(updated_pyproject_string);
+
+        if !self.skip_lock() {
+            self.check_lock_file();
+        }
+
         self.delete_migrated_files().unwrap();
 
         if had_errors {
@@ -113,9 +148,34 @@ pub trait Converter: Any + Debug {
         self.manage_migration_warnings();
     }
 
+    /// Write files generated during the migration, returning the paths of the files that were
+    /// written. Files are only created if they do not exist yet, so that files from the user are
+    /// never overwritten.
+    fn write_generated_files(&self) -> Vec<PathBuf> {
+        let mut generated_files = Vec::new();
+
+        for (path, content) in GENERATED_FILES.lock().unwrap().iter() {
+            let file_path = self.get_project_path().join(path);
+
+            match File::create_new(&file_path)
+                .and_then(|mut file| file.write_all(content.as_bytes()))
+            {
+                Ok(()) => generated_files.push(file_path),
+                Err(e) => error!("Could not generate \"{}\": {e}", path.bold()),
+            }
+        }
+
+        generated_files
+    }
+
     /// Revert any change made, in case the migration is aborted after some files have already been
     /// modified.
-    fn revert_changes(&self, had_pyproject: bool, old_pyproject: Option<Vec<u8>>) {
+    fn revert_changes(
+        &self,
+        had_pyproject: bool,
+        old_pyproject: Option<Vec<u8>>,
+        generated_files: &[PathBuf],
+    ) {
         let pyproject_path = self.get_project_path().join("pyproject.toml");
 
         // Some package managers do not use `pyproject.toml`, so we either revert back the content
@@ -127,6 +187,14 @@ pub trait Converter: Any + Debug {
         } else {
             remove_file(pyproject_path).unwrap();
         }
+
+        // Only files generated by the migration are removed, as existing files are never
+        // overwritten.
+        for path in generated_files {
+            if let Err(e) = remove_file(path) {
+                error!("Could not remove \"{}\": {e}", path.display().bold());
+            }
+        }
     }
 
     fn manage_migration_errors(&self) -> bool {
@@ -209,6 +277,7 @@ pub trait Converter: Any + Debug {
             requires_python: current_project.requires_python.or(project.requires_python),
             readme: current_project.readme.or(project.readme),
             license: current_project.license.or(project.license),
+            license_files: current_project.license_files.or(project.license_files),
             maintainers: current_project.maintainers.or(project.maintainers),
             keywords: current_project.keywords.or(project.keywords),
             classifiers: current_project.classifiers.or(project.classifiers),
@@ -317,13 +386,18 @@ pub trait Converter: Any + Debug {
         uv::lock_dependencies(self.get_project_path().as_ref(), &lock_type)
     }
 
+    /// Check the lock file generated by uv against data from the current package manager (e.g.
+    /// hashes of pinned dependencies), before the current package manager files get deleted.
+    fn check_lock_file(&self) {}
+
     /// Get dependencies constraints to set in `constraint-dependencies` under `[tool.uv]` section,
     /// to keep dependencies locked to the same versions as they are with the current package
     /// manager.
     fn get_constraint_dependencies(&self) -> Option<Vec<String>>;
 
-    /// Remove `constraint-dependencies` from `[tool.uv]` in `pyproject.toml`, unless user has
-    /// opted out of keeping versions locked in the current package manager.
+    /// Remove constraints used to keep locked versions from `constraint-dependencies` under
+    /// `[tool.uv]` in `pyproject.toml`, unless user has opted out of keeping versions locked in the
+    /// current package manager.
     ///
     /// Also lock dependencies, to remove `constraints` from `[manifest]` in lock file, unless user
     /// has opted out of locking dependencies.
@@ -335,7 +409,9 @@ pub trait Converter: Any + Debug {
         let mut pyproject_updater = PyprojectUpdater {
             pyproject: &mut updated_pyproject_toml.parse::<DocumentMut>().unwrap(),
         };
-        if let Some(updated_pyproject) = pyproject_updater.remove_constraint_dependencies() {
+        if let Some(updated_pyproject) = pyproject_updater
+            .remove_constraint_dependencies(&self.get_constraint_dependencies().unwrap_or_default())
+        {
             let mut pyproject_file =
                 File::create(self.get_project_path().join("pyproject.toml")).unwrap();
             pyproject_file
@@ -369,4 +445,7 @@ pub enum DependencyGroupsStrategy {
 pub enum BuildBackend {
     Hatch,
     Uv,
+    Setuptools,
+    Flit,
+    PdmBackend,
 }
@@ -0,0 +1,158 @@
+use crate::converters::{DependencyGroupsAndDefaultGroups, DependencyGroupsStrategy};
+use crate::errors::add_recoverable_error;
+use crate::schema::pyproject::DependencyGroupSpecification;
+use crate::schema::utils::SingleOrVec;
+use indexmap::IndexMap;
+use owo_colors::OwoColorize;
+use pep508_rs::Requirement;
+use std::str::FromStr;
+use url::Url;
+
+pub fn get(dependencies: &[String], group: &str) -> Vec<String> {
+    let mut pep_508_dependencies: Vec<String> = Vec::new();
+
+    for dependency in dependencies {
+        // PDM allows setting editable dependencies with `-e`, which is not valid PEP 508.
+        if let Ok(requirement) = Requirement::<Url>::from_str(dependency) {
+            pep_508_dependencies.push(requirement.to_string());
+        } else {
+            add_recoverable_error(format!(
+                "\"{}\" from \"{}\" group could not be automatically migrated, try running \"{}\".",
+                dependency.bold(),
+                group.bold(),
+                format!("uv add --group {group} {dependency}").bold(),
+            ));
+        }
+    }
+
+    pep_508_dependencies
+}
+
+/// Convert overrides from `[tool.pdm.resolution.overrides]`, which can either be a version, a
+/// version specifier or a URL (<https://pdm-project.org/latest/usage/config/#override-the-resolved-package-versions>).
+pub fn get_overrides(overrides: Option<&IndexMap<String, String>>) -> Option<Vec<String>> {
+    let overrides: Vec<String> = overrides?
+        .iter()
+        .map(|(name, specification)| {
+            if specification.is_empty() || specification == "*" {
+                name.clone()
+            } else if specification.contains("://") {
+                format!("{name} @ {specification}")
+            } else if specification
+                .chars()
+                .next()
+                .unwrap_or_default()
+                .is_ascii_digit()
+            {
+                format!("{name}=={specification}")
+            } else {
+                format!("{name}{specification}")
+            }
+        })
+        .collect();
+
+    if overrides.is_empty() {
+        return None;
+    }
+    Some(overrides)
+}
+
+pub fn get_dependency_groups_and_default_groups(
+    existing_dependency_groups: Option<IndexMap<String, Vec<DependencyGroupSpecification>>>,
+    pdm_dev_dependencies: Option<&IndexMap<String, Vec<String>>>,
+    dependency_groups_strategy: Option<DependencyGroupsStrategy>,
+) -> DependencyGroupsAndDefaultGroups {
+    // Recent versions of PDM write development dependencies to `[dependency-groups]`, so we start
+    // from existing groups, and add the ones defined in legacy `[tool.pdm.dev-dependencies]`.
+    let mut dependency_groups = existing_dependency_groups.unwrap_or_default();
+    let mut default_groups: Vec<String> = Vec::new();
+    let mut all_default_groups = false;
+
+    if let Some(pdm_dev_dependencies) = pdm_dev_dependencies {
+        for (group, dependencies) in pdm_dev_dependencies {
+            dependency_groups
+                .entry(match dependency_groups_strategy {
+                    Some(DependencyGroupsStrategy::MergeIntoDev) => "dev".to_string(),
+                    _ => group.clone(),
+                })
+                .or_default()
+                .extend(
+                    get(dependencies, group)
+                        .into_iter()
+                        .map(DependencyGroupSpecification::String),
+                );
+        }
+    }
+
+    if dependency_groups.is_empty() {
+        return (None, None);
+    }
+
+    // PDM installs all development dependency groups by default. If we only have `dev` dependency
+    // group, there is nothing to do, as this is already uv's default.
+    if !dependency_groups.keys().eq(["dev"]) {
+        match dependency_groups_strategy {
+            None | Some(DependencyGroupsStrategy::SetDefaultGroupsAll) => {
+                all_default_groups = true;
+            }
+            Some(DependencyGroupsStrategy::SetDefaultGroups) => {
+                default_groups.extend(dependency_groups.keys().map(ToString::to_string));
+            }
+            Some(DependencyGroupsStrategy::IncludeInDev) => {
+                let groups_to_include: Vec<String> = dependency_groups
+                    .keys()
+                    .filter(|&k| k != "dev")
+                    .cloned()
+                    .collect();
+
+                dependency_groups
+                    .entry("dev".to_string())
+                    .or_default()
+                    .extend(groups_to_include.into_iter().map(|g| {
+                        DependencyGroupSpecification::Map {
+                            include_group: Some(g),
+                        }
+                    }));
+            }
+            _ => (),
+        }
+    }
+
+    let default_groups = if all_default_groups {
+        Some(SingleOrVec::Single("all".to_string()))
+    } else if default_groups.is_empty() {
+        None
+    } else {
+        Some(SingleOrVec::Vec(default_groups))
+    };
+
+    (Some(dependency_groups), default_groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_overrides() {
+        let overrides = IndexMap::from([
+            ("asgiref".to_string(), "3.2.10".to_string()),
+            ("urllib3".to_string(), ">=1.26.2".to_string()),
+            (
+                "pytz".to_string(),
+                "https://example.com/pytz-2020.9-py3-none-any.whl".to_string(),
+            ),
+            ("foo".to_string(), String::new()),
+        ]);
+
+        assert_eq!(
+            get_overrides(Some(&overrides)),
+            Some(vec![
+                "asgiref==3.2.10".to_string(),
+                "urllib3>=1.26.2".to_string(),
+                "pytz @ https://example.com/pytz-2020.9-py3-none-any.whl".to_string(),
+                "foo".to_string(),
+            ])
+        );
+    }
+}
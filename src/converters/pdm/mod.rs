@@ -0,0 +1,350 @@
+mod dependencies;
+mod sources;
+
+use crate::converters::Converter;
+use crate::converters::ConverterOptions;
+use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::errors::add_recoverable_error;
+use crate::schema::pdm::PdmLock;
+use crate::schema::pep_621::Project;
+use crate::schema::pyproject::PyProject;
+use crate::schema::uv::Uv;
+use crate::toml::PyprojectPrettyFormatter;
+use owo_colors::OwoColorize;
+use std::fs;
+use toml_edit::DocumentMut;
+use toml_edit::visit_mut::VisitMut;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pdm {
+    pub converter_options: ConverterOptions,
+}
+
+impl Converter for Pdm {
+    fn build_uv_pyproject(&self) -> String {
+        let pyproject_toml_content =
+            fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
+        let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+        let pdm = pyproject.tool.unwrap_or_default().pdm.unwrap_or_default();
+
+        let (dependency_groups, uv_default_groups) =
+            dependencies::get_dependency_groups_and_default_groups(
+                pyproject.dependency_groups,
+                pdm.dev_dependencies.as_ref(),
+                self.get_dependency_groups_strategy(),
+            );
+
+        // PDM relies on PEP 621 for project metadata, so the existing `[project]` section already
+        // holds everything we need.
+        let project = Project {
+            // "name" is required by uv.
+            name: Some(String::new()),
+            ..Default::default()
+        };
+
+        if let Some(scripts) = &pdm.scripts {
+            add_recoverable_error(format!(
+                "Scripts from \"{}\" ({}) cannot be migrated, as uv does not support defining scripts.",
+                "tool.pdm.scripts".bold(),
+                scripts
+                    .keys()
+                    .map(|s| format!("\"{}\"", s.bold()))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+            ));
+        }
+
+        let uv = Uv {
+            package: pdm.distribution,
+            index: sources::get_indexes(pdm.source),
+            default_groups: uv_default_groups,
+            constraint_dependencies: self.get_constraint_dependencies(),
+            override_dependencies: dependencies::get_overrides(
+                pdm.resolution
+                    .as_ref()
+                    .and_then(|resolution| resolution.overrides.as_ref()),
+            ),
+            ..Uv::default()
+        };
+
+        let mut updated_pyproject = pyproject_toml_content.parse::<DocumentMut>().unwrap();
+        let mut pyproject_updater = PyprojectUpdater {
+            pyproject: &mut updated_pyproject,
+        };
+
+        pyproject_updater.insert_pep_621(&self.build_project(
+            pyproject.project,
+            project,
+            "0.0.1".to_string(),
+        ));
+        pyproject_updater.insert_dependency_groups(dependency_groups.as_ref());
+        pyproject_updater.insert_uv(&uv);
+
+        self.remove_pyproject_pdm_section(
+            &mut updated_pyproject,
+            pyproject
+                .build_system
+                .and_then(|build_system| build_system.build_backend)
+                .as_deref()
+                == Some("pdm.backend"),
+        );
+
+        let mut visitor = PyprojectPrettyFormatter::default();
+        visitor.visit_document_mut(&mut updated_pyproject);
+
+        updated_pyproject.to_string()
+    }
+
+    fn get_package_manager_name(&self) -> String {
+        "PDM".to_string()
+    }
+
+    fn get_converter_options(&self) -> &ConverterOptions {
+        &self.converter_options
+    }
+
+    fn get_migrated_files_to_delete(&self) -> Vec<String> {
+        vec![
+            "pdm.lock".to_string(),
+            "pdm.toml".to_string(),
+            ".pdm-python".to_string(),
+        ]
+    }
+
+    fn get_constraint_dependencies(&self) -> Option<Vec<String>> {
+        let pdm_lock_path = self.get_project_path().join("pdm.lock");
+
+        if self.is_dry_run() || !self.respect_locked_versions() || !pdm_lock_path.exists() {
+            return None;
+        }
+
+        let pdm_lock_content = fs::read_to_string(pdm_lock_path).unwrap();
+        let Ok(pdm_lock) = toml::from_str::<PdmLock>(pdm_lock_content.as_str()) else {
+            add_recoverable_error(format!(
+                "\"{}\" could not be parsed, so dependencies were not kept to their previous locked versions.",
+                "pdm.lock".bold(),
+            ));
+            return None;
+        };
+
+        let constraint_dependencies: Vec<String> = pdm_lock
+            .package
+            .unwrap_or_default()
+            .iter()
+            .map(|p| format!("{}=={}", p.name, p.version))
+            .collect();
+
+        if constraint_dependencies.is_empty() {
+            None
+        } else {
+            Some(constraint_dependencies)
+        }
+    }
+}
+
+impl Pdm {
+    /// Remove `[tool.pdm]` section from `pyproject.toml`, unless user has explicitly asked for the
+    /// old metadata to be kept.
+    /// If the project uses `pdm-backend` as a build backend, which is kept as is, we only remove
+    /// the keys that are not related to the build backend.
+    fn remove_pyproject_pdm_section(&self, pyproject: &mut DocumentMut, uses_pdm_backend: bool) {
+        if self.keep_old_metadata() {
+            return;
+        }
+
+        if let Some(tool) = pyproject.get_mut("tool")
+            && let Some(tool_table) = tool.as_table_mut()
+        {
+            if uses_pdm_backend {
+                if let Some(pdm) = tool_table.get_mut("pdm")
+                    && let Some(pdm_table) = pdm.as_table_mut()
+                {
+                    let keys_to_keep = ["build", "version"];
+                    let mut found_keys_to_keep = false;
+
+                    for (key, _) in &pdm_table.clone() {
+                        if keys_to_keep.contains(&key) {
+                            found_keys_to_keep = true;
+                        } else {
+                            pdm_table.remove(key);
+                        }
+                    }
+
+                    // If none of the keys to keep was found, remove the entire section.
+                    if !found_keys_to_keep {
+                        tool_table.remove("pdm");
+                    }
+                }
+            } else {
+                tool_table.remove("pdm");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converters::DependencyGroupsStrategy;
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_pdm_backend_keeps_build_settings() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        let pyproject_content = r#"
+[build-system]
+requires = ["pdm-backend"]
+build-backend = "pdm.backend"
+
+[project]
+name = "foo"
+dynamic = ["version"]
+dependencies = ["arrow>=1.2.3"]
+
+[tool.pdm.version]
+source = "scm"
+
+[tool.pdm.build]
+includes = ["src/foo"]
+
+[tool.pdm.dev-dependencies]
+test = ["pytest>=8"]
+        "#;
+
+        let mut pyproject_file = File::create(project_path.join("pyproject.toml")).unwrap();
+        pyproject_file
+            .write_all(pyproject_content.as_bytes())
+            .unwrap();
+
+        let pdm = Pdm {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                ignore_locked_versions: true,
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(pdm.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        dependencies = ["arrow>=1.2.3"]
+        dynamic = ["version"]
+
+
+        [build-system]
+        requires = ["pdm-backend"]
+        build-backend = "pdm.backend"
+
+        [dependency-groups]
+        test = ["pytest>=8"]
+
+        [tool.uv]
+        default-groups = "all"
+
+        [tool.pdm.version]
+        source = "scm"
+
+        [tool.pdm.build]
+        includes = ["src/foo"]
+        "#);
+    }
+
+    #[test]
+    fn test_existing_dependency_groups() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        let pyproject_content = r#"
+[project]
+name = "foo"
+version = "1.2.3"
+
+[dependency-groups]
+lint = ["ruff>=0.8"]
+
+[tool.pdm.dev-dependencies]
+dev = ["pytest>=8"]
+        "#;
+
+        let mut pyproject_file = File::create(project_path.join("pyproject.toml")).unwrap();
+        pyproject_file
+            .write_all(pyproject_content.as_bytes())
+            .unwrap();
+
+        let pdm = Pdm {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                ignore_locked_versions: true,
+                dependency_groups_strategy: Some(DependencyGroupsStrategy::IncludeInDev),
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(pdm.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        version = "1.2.3"
+
+        [dependency-groups]
+        lint = ["ruff>=0.8"]
+        dev = [
+            "pytest>=8",
+            { include-group = "lint" },
+        ]
+        "#);
+    }
+
+    #[test]
+    fn test_dependency_groups_strategy_merge_into_dev() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        let pyproject_content = r#"
+[project]
+name = "foo"
+version = "1.2.3"
+
+[tool.pdm.dev-dependencies]
+test = ["pytest>=8"]
+lint = ["ruff>=0.8"]
+        "#;
+
+        let mut pyproject_file = File::create(project_path.join("pyproject.toml")).unwrap();
+        pyproject_file
+            .write_all(pyproject_content.as_bytes())
+            .unwrap();
+
+        let pdm = Pdm {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                ignore_locked_versions: true,
+                dependency_groups_strategy: Some(DependencyGroupsStrategy::MergeIntoDev),
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(pdm.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        version = "1.2.3"
+
+        [dependency-groups]
+        dev = [
+            "pytest>=8",
+            "ruff>=0.8",
+        ]
+        "#);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::pdm::{Source, SourceType};
+use crate::schema::uv::Index;
+use owo_colors::OwoColorize;
+
+const PYPI_URL: &str = "https://pypi.org/simple";
+
+pub fn get_indexes(pdm_sources: Option<Vec<Source>>) -> Option<Vec<Index>> {
+    let mut indexes = Vec::new();
+
+    for source in pdm_sources? {
+        if source.include_packages.is_some() || source.exclude_packages.is_some() {
+            add_recoverable_error(format!(
+                "\"{}\" and \"{}\" from \"{}\" source cannot be expressed with uv, so the source was migrated without them.",
+                "include_packages".bold(),
+                "exclude_packages".bold(),
+                source.name.bold(),
+            ));
+        }
+
+        // A source named `pypi` overrides PyPI URL in PDM
+        // (https://pdm-project.org/latest/usage/config/#configure-the-package-indexes), which
+        // corresponds to setting `default = true` in uv, unless the URL is PyPI one, in which case
+        // there is nothing to do, as PyPI is already the default index.
+        if source.name.to_lowercase() == "pypi" {
+            if source.url.trim_end_matches('/') == PYPI_URL {
+                continue;
+            }
+
+            indexes.push(Index {
+                name: source.name,
+                url: Some(source.url),
+                default: Some(true),
+                ..Default::default()
+            });
+            continue;
+        }
+
+        indexes.push(Index {
+            name: source.name,
+            url: Some(source.url),
+            format: (source.type_ == Some(SourceType::FindLinks)).then(|| "flat".to_string()),
+            ..Default::default()
+        });
+    }
+
+    if indexes.is_empty() {
+        return None;
+    }
+    Some(indexes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_indexes() {
+        let sources = vec![
+            Source {
+                name: "pypi".to_string(),
+                url: "https://mirror.example.com/simple".to_string(),
+                ..Default::default()
+            },
+            Source {
+                name: "foo".to_string(),
+                url: "https://foo.example.com/simple".to_string(),
+                type_: Some(SourceType::Index),
+                ..Default::default()
+            },
+            Source {
+                name: "bar".to_string(),
+                url: "https://bar.example.com/wheels/".to_string(),
+                type_: Some(SourceType::FindLinks),
+                ..Default::default()
+            },
+        ];
+
+        let expected = vec![
+            Index {
+                name: "pypi".to_string(),
+                url: Some("https://mirror.example.com/simple".to_string()),
+                default: Some(true),
+                ..Default::default()
+            },
+            Index {
+                name: "foo".to_string(),
+                url: Some("https://foo.example.com/simple".to_string()),
+                ..Default::default()
+            },
+            Index {
+                name: "bar".to_string(),
+                url: Some("https://bar.example.com/wheels/".to_string()),
+                format: Some("flat".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(get_indexes(Some(sources)), Some(expected));
+    }
+
+    #[test]
+    fn test_get_indexes_pypi() {
+        let sources = vec![Source {
+            name: "pypi".to_string(),
+            url: "https://pypi.org/simple/".to_string(),
+            ..Default::default()
+        }];
+
+        assert_eq!(get_indexes(Some(sources)), None);
+    }
+}
@@ -0,0 +1,476 @@
+use crate::converters::pip::sources::IndexOptions;
+use crate::errors::add_recoverable_error;
+use crate::utils::normalize_dependency_name;
+use indexmap::IndexMap;
+use log::debug;
+use owo_colors::OwoColorize;
+use pep508_rs::{Requirement, VersionOrUrl};
+use std::collections::HashSet;
+use std::path::Path;
+use std::str::FromStr;
+use url::Url;
+
+/// Reason for which a package is pinned in a requirements file compiled by pip-tools, from `# via`
+/// annotations (<https://pip-tools.readthedocs.io/en/stable/cli/pip-compile/#cmdoption-annotate>).
+#[derive(Debug, Eq, PartialEq)]
+pub enum Via {
+    /// The package is required from a requirements file (e.g. `-r requirements.in`), or from the
+    /// project itself (e.g. `foo (pyproject.toml)`).
+    File(String),
+    /// The package is constrained from a constraints file (e.g. `-c requirements.txt`).
+    Constraint(String),
+    /// The package is a dependency of another pinned package.
+    Package(String),
+}
+
+/// Requirements file compiled by pip-tools.
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct CompiledRequirements {
+    /// Arguments of the `pip-compile` command written in the header of the file.
+    pub command: Vec<String>,
+    /// Pinned packages, indexed by their normalized name, along with the reasons they are pinned.
+    pub pins: IndexMap<String, Vec<Via>>,
+    /// Pinned requirements that use a version specifier (e.g. `foo==1.2.3`), without hashes, used
+    /// as constraints to keep locked versions.
+    pub locked_versions: Vec<String>,
+}
+
+/// Options of `pip-compile` that take a value
+/// (<https://pip-tools.readthedocs.io/en/stable/cli/pip-compile/>).
+const OPTIONS_WITH_VALUE: [&str; 17] = [
+    "-o",
+    "--output-file",
+    "--extra",
+    "-i",
+    "--index-url",
+    "--extra-index-url",
+    "-f",
+    "--find-links",
+    "--trusted-host",
+    "-P",
+    "--upgrade-package",
+    "--unsafe-package",
+    "--pip-args",
+    "-c",
+    "--constraint",
+    "--cache-dir",
+    "--build-deps-for",
+];
+
+/// Options of `pip-compile` that uv handles differently, and that cannot be migrated.
+const UNSUPPORTED_OPTIONS: [&str; 5] = [
+    "--no-build-isolation",
+    "--unsafe-package",
+    "--pip-args",
+    "--build-deps-for",
+    "--all-build-deps",
+];
+
+/// Options of the `pip-compile` command used to compile a requirements file.
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct CompileOptions {
+    /// Files the requirements were compiled from (e.g. `requirements.in` or `pyproject.toml`).
+    pub source_files: Vec<String>,
+    /// Extras of the project that were compiled alongside its dependencies.
+    pub extras: Vec<String>,
+    pub all_extras: bool,
+    /// Whether pre-releases were allowed.
+    pub pre: bool,
+    pub index_options: IndexOptions,
+    pub unsupported_options: Vec<String>,
+}
+
+impl CompileOptions {
+    /// Whether the requirements were compiled from the metadata of the project itself, rather
+    /// than from `.in` files.
+    pub fn compiles_project(&self) -> bool {
+        self.source_files.iter().any(|file| {
+            matches!(
+                Path::new(file).file_name().and_then(|name| name.to_str()),
+                Some("pyproject.toml" | "setup.py" | "setup.cfg")
+            )
+        })
+    }
+}
+
+/// Parse the arguments of a `pip-compile` command, written in the header of compiled files (e.g.
+/// `--extra=dev --index-url=https://example.com/simple pyproject.toml`).
+pub fn parse_compile_options(command: &[String]) -> CompileOptions {
+    let mut compile_options = CompileOptions::default();
+    let mut arguments = command.iter();
+
+    while let Some(argument) = arguments.next() {
+        if !argument.starts_with('-') {
+            compile_options.source_files.push(argument.clone());
+            continue;
+        }
+
+        let (option, value) = match argument.split_once('=') {
+            Some((option, value)) => (option, Some(value.to_string())),
+            None if OPTIONS_WITH_VALUE.contains(&argument.as_str()) => {
+                (argument.as_str(), arguments.next().cloned())
+            }
+            None => (argument.as_str(), None),
+        };
+
+        if UNSUPPORTED_OPTIONS.contains(&option) {
+            if !compile_options
+                .unsupported_options
+                .iter()
+                .any(|o| o == option)
+            {
+                compile_options.unsupported_options.push(option.to_string());
+            }
+            continue;
+        }
+
+        let index_options = &mut compile_options.index_options;
+
+        match (option, value) {
+            ("--extra", Some(value)) => compile_options
+                .extras
+                .extend(value.split(',').map(|extra| extra.trim().to_string())),
+            ("--all-extras", _) => compile_options.all_extras = true,
+            ("--pre", _) => compile_options.pre = true,
+            ("-i" | "--index-url", Some(value)) => index_options.index_url = Some(value),
+            ("--extra-index-url", Some(value)) => index_options.extra_index_urls.push(value),
+            ("-f" | "--find-links", Some(value)) => index_options.find_links.push(value),
+            ("--trusted-host", Some(value)) => index_options.trusted_hosts.push(value),
+            _ => (),
+        }
+    }
+
+    compile_options
+}
+
+/// Parse a requirements file compiled by pip-tools, keeping the command used to compile it, and
+/// `# via` annotations, which are written either on a single line (e.g. `# via httpx`), or on the
+/// following lines (e.g. `# via` followed by `#   httpcore` and `#   httpx`).
+pub fn parse(content: &str) -> CompiledRequirements {
+    let mut compiled_requirements = CompiledRequirements::default();
+    let mut current_pin: Option<String> = None;
+    let mut in_via_block = false;
+
+    for line in content.lines() {
+        let Some(comment) = line.trim().strip_prefix('#') else {
+            in_via_block = false;
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('-') {
+                // Editable requirements (e.g. `-e ./libs/foo`) are not pins, but hashes of the
+                // current pin are written on the following lines.
+                if !line.starts_with("--hash") {
+                    current_pin = None;
+                }
+                continue;
+            }
+
+            let requirement = line
+                .split_once(" \\")
+                .map_or(line, |(requirement, _)| requirement);
+            let requirement = requirement.trim_end_matches('\\').trim();
+            current_pin = get_name(requirement);
+
+            if let Ok(requirement) = Requirement::<Url>::from_str(requirement)
+                && matches!(
+                    requirement.version_or_url,
+                    Some(VersionOrUrl::VersionSpecifier(_))
+                )
+            {
+                compiled_requirements
+                    .locked_versions
+                    .push(requirement.to_string());
+            }
+
+            if let Some(name) = &current_pin {
+                compiled_requirements.pins.entry(name.clone()).or_default();
+            }
+            continue;
+        };
+
+        if let Some(command) = comment.trim().strip_prefix("pip-compile")
+            && compiled_requirements.command.is_empty()
+            && current_pin.is_none()
+        {
+            compiled_requirements.command = command
+                .split_whitespace()
+                .map(ToString::to_string)
+                .collect();
+            continue;
+        }
+
+        let Some(pin) = &current_pin else {
+            continue;
+        };
+
+        let entry = if let Some(via) = comment.strip_prefix(" via") {
+            in_via_block = true;
+            via.trim()
+        } else if in_via_block && comment.starts_with("   ") {
+            comment.trim()
+        } else {
+            in_via_block = false;
+            continue;
+        };
+
+        if !entry.is_empty()
+            && let Some(via) = parse_via(entry)
+        {
+            compiled_requirements
+                .pins
+                .entry(pin.clone())
+                .or_default()
+                .push(via);
+        }
+    }
+
+    compiled_requirements
+}
+
+fn parse_via(entry: &str) -> Option<Via> {
+    if let Some(file) = entry.strip_prefix("-r ") {
+        return Some(Via::File(file.trim().to_string()));
+    }
+    if let Some(file) = entry.strip_prefix("-c ") {
+        return Some(Via::Constraint(file.trim().to_string()));
+    }
+    // Packages required from the project itself, e.g. `foo (pyproject.toml)` or
+    // `foo (setup.py)`.
+    if let Some((_, file)) = entry.split_once(" (") {
+        return Some(Via::File(file.trim_end_matches(')').to_string()));
+    }
+    get_name(entry).map(Via::Package)
+}
+
+/// Get the normalized name of a requirement.
+pub fn get_name(requirement: &str) -> Option<String> {
+    if let Ok(requirement) = Requirement::<Url>::from_str(requirement) {
+        return Some(normalize_dependency_name(requirement.name.as_ref()));
+    }
+
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(normalize_dependency_name(&name))
+    }
+}
+
+/// Get the top-level requirements that pulled in a pinned package, by walking up `# via`
+/// annotations. Top-level requirements are the ones required from a requirements file that still
+/// declares them (or from the project itself).
+fn get_top_level_requirements(
+    compiled_requirements: &CompiledRequirements,
+    declared_requirements: &HashSet<String>,
+    name: &str,
+) -> Vec<String> {
+    let mut top_level_requirements: Vec<String> = Vec::new();
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut to_visit: Vec<&str> = vec![name];
+
+    while let Some(current) = to_visit.pop() {
+        if !visited.insert(current) {
+            continue;
+        }
+
+        for via in compiled_requirements
+            .pins
+            .get(current)
+            .into_iter()
+            .flatten()
+        {
+            match via {
+                Via::File(file) => {
+                    if (Path::new(file).extension().is_none_or(|ext| ext != "in")
+                        || declared_requirements.contains(current))
+                        && !top_level_requirements.iter().any(|r| r == current)
+                    {
+                        top_level_requirements.push(current.to_string());
+                    }
+                }
+                Via::Package(parent) => to_visit.push(parent),
+                Via::Constraint(_) => (),
+            }
+        }
+    }
+
+    top_level_requirements
+}
+
+/// Report which top-level requirements pulled in each package pinned in a compiled requirements
+/// file, and warn about pinned packages that cannot be traced back to any requirements file, as
+/// this means that the compiled file is outdated.
+///
+/// `declared_requirements` holds the normalized names of the requirements declared in the `.in`
+/// file the requirements were compiled from. As the report is only useful to understand why a
+/// package is pinned, it is logged at debug level, so it is only shown with `--verbose`.
+pub fn check_provenance(
+    compiled_requirements: &CompiledRequirements,
+    compiled_file: &str,
+    source_file: &str,
+    declared_requirements: &HashSet<String>,
+) {
+    // Files compiled with `--no-annotate` do not hold any provenance information.
+    if compiled_requirements.pins.values().all(Vec::is_empty) {
+        return;
+    }
+
+    let mut stale_pins: Vec<&str> = Vec::new();
+
+    for name in compiled_requirements.pins.keys() {
+        let top_level_requirements =
+            get_top_level_requirements(compiled_requirements, declared_requirements, name);
+
+        if top_level_requirements.is_empty() {
+            stale_pins.push(name);
+        } else if top_level_requirements.iter().any(|r| r == name) {
+            debug!("\"{name}\" is directly required in \"{compiled_file}\".");
+        } else {
+            debug!(
+                "\"{name}\" is pinned in \"{compiled_file}\" because of {}.",
+                top_level_requirements
+                    .iter()
+                    .map(|r| format!("\"{r}\""))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            );
+        }
+    }
+
+    if !stale_pins.is_empty() {
+        add_recoverable_error(format!(
+            "The following packages pinned in \"{}\" are not required from \"{}\" anymore: {}. \"{}\" is probably outdated, consider running \"{}\" before migrating.",
+            compiled_file.bold(),
+            source_file.bold(),
+            stale_pins
+                .iter()
+                .map(|p| format!("\"{}\"", p.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+            compiled_file.bold(),
+            format!("pip-compile {source_file}").bold(),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPILED_REQUIREMENTS: &str = r"#
+# This file is autogenerated by pip-compile with Python 3.13
+# by the following command:
+#
+#    pip-compile --strip-extras requirements.in
+#
+arrow==1.3.0
+    # via -r requirements.in
+-e ./libs/foo
+    # via -r requirements.in
+python-dateutil==2.9.0.post0
+    # via
+    #   -c constraints.txt
+    #   arrow
+six==1.17.0 \
+    --hash=sha256:4721f391ed90541fddacab5acf947aa0d3dc7d27b2e1e8eda2be8970586c3274
+    # via python-dateutil
+Typing_Extensions==4.12.2
+    # via -r requirements.in
+";
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(COMPILED_REQUIREMENTS),
+            CompiledRequirements {
+                command: vec!["--strip-extras".to_string(), "requirements.in".to_string()],
+                pins: IndexMap::from([
+                    (
+                        "arrow".to_string(),
+                        vec![Via::File("requirements.in".to_string())]
+                    ),
+                    (
+                        "python-dateutil".to_string(),
+                        vec![
+                            Via::Constraint("constraints.txt".to_string()),
+                            Via::Package("arrow".to_string())
+                        ]
+                    ),
+                    (
+                        "six".to_string(),
+                        vec![Via::Package("python-dateutil".to_string())]
+                    ),
+                    (
+                        "typing-extensions".to_string(),
+                        vec![Via::File("requirements.in".to_string())]
+                    ),
+                ]),
+                locked_versions: vec![
+                    "arrow==1.3.0".to_string(),
+                    "python-dateutil==2.9.0.post0".to_string(),
+                    "six==1.17.0".to_string(),
+                    "typing-extensions==4.12.2".to_string(),
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_compile_options() {
+        let command: Vec<String> = "--extra=dev --extra test,docs --pre -i https://example.com/simple --find-links=./wheels --no-build-isolation --output-file requirements-dev.txt pyproject.toml"
+            .split_whitespace()
+            .map(ToString::to_string)
+            .collect();
+
+        let compile_options = parse_compile_options(&command);
+
+        assert_eq!(
+            compile_options,
+            CompileOptions {
+                source_files: vec!["pyproject.toml".to_string()],
+                extras: vec!["dev".to_string(), "test".to_string(), "docs".to_string()],
+                all_extras: false,
+                pre: true,
+                index_options: IndexOptions {
+                    index_url: Some("https://example.com/simple".to_string()),
+                    find_links: vec!["./wheels".to_string()],
+                    ..Default::default()
+                },
+                unsupported_options: vec!["--no-build-isolation".to_string()],
+            }
+        );
+        assert!(compile_options.compiles_project());
+    }
+
+    #[test]
+    fn test_get_top_level_requirements() {
+        let compiled_requirements = parse(COMPILED_REQUIREMENTS);
+        let declared_requirements = HashSet::from(["arrow".to_string()]);
+
+        assert_eq!(
+            get_top_level_requirements(&compiled_requirements, &declared_requirements, "six"),
+            vec!["arrow".to_string()]
+        );
+        assert_eq!(
+            get_top_level_requirements(&compiled_requirements, &declared_requirements, "arrow"),
+            vec!["arrow".to_string()]
+        );
+        // `typing-extensions` is not declared in `requirements.in` anymore.
+        assert!(
+            get_top_level_requirements(
+                &compiled_requirements,
+                &declared_requirements,
+                "typing-extensions"
+            )
+            .is_empty()
+        );
+    }
+}
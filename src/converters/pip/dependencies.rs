@@ -1,54 +1,781 @@
+use crate::converters::env_vars;
+use crate::converters::pip::compiled;
+use crate::converters::pip::direct_references;
+use crate::converters::pip::sources::IndexOptions;
+use crate::converters::{DependencyGroupsAndDefaultGroups, DependencyGroupsStrategy};
 use crate::errors::add_recoverable_error;
+use crate::schema::pyproject::DependencyGroupSpecification;
+use crate::schema::utils::SingleOrVec;
+use crate::schema::uv::SourceContainer;
+use crate::utils::normalize_dependency_name;
+use indexmap::IndexMap;
 use owo_colors::OwoColorize;
-use pep508_rs::Requirement;
+use pep508_rs::{Requirement, VersionOrUrl};
+use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 use std::str::FromStr;
 use url::Url;
 
-pub fn get(project_path: &Path, requirements_files: Vec<String>) -> Option<Vec<String>> {
-    let mut dependencies: Vec<String> = Vec::new();
+/// Dependencies, constraints, index options and sources found in requirements files, along with
+/// the files that were read.
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct Requirements {
+    pub dependencies: Option<Vec<String>>,
+    pub constraints: Option<Vec<String>>,
+    pub index_options: IndexOptions,
+    pub sources: IndexMap<String, SourceContainer>,
+    pub hashes: Vec<HashedRequirement>,
+    pub files: Vec<PathBuf>,
+    /// Whether the project itself is installed from requirements (e.g. with `-e .`).
+    pub installs_project: bool,
+}
+
+/// Requirement pinned with hashes of its artifacts
+/// (<https://pip.pypa.io/en/stable/topics/secure-installs/#hash-checking-mode>).
+#[derive(Debug, Eq, PartialEq)]
+pub struct HashedRequirement {
+    /// Normalized name of the package.
+    pub name: String,
+    /// Requirements file the requirement is pinned in.
+    pub file: PathBuf,
+    pub hashes: Vec<String>,
+}
+
+struct RequirementsParser<'a> {
+    project_path: &'a Path,
+    visited_files: HashSet<PathBuf>,
+    dependencies: Vec<String>,
+    constraints: Vec<String>,
+    index_options: IndexOptions,
+    sources: IndexMap<String, SourceContainer>,
+    hashes: Vec<HashedRequirement>,
+    installs_project: bool,
+}
+
+impl RequirementsParser<'_> {
+    /// Parse a requirements file, following references to other requirements files
+    /// (`-r`/`--requirement`) and constraints files (`-c`/`--constraint`). Files that were already
+    /// visited are skipped, both to avoid adding the same dependencies twice and to guard against
+    /// cycles.
+    fn parse(&mut self, requirements_file: &Path, is_constraints_file: bool) {
+        if !self.visited_files.insert(requirements_file.to_path_buf()) {
+            return;
+        }
 
-    for requirements_file in requirements_files {
         let requirements_content =
-            fs::read_to_string(project_path.join(requirements_file.clone())).unwrap();
+            fs::read_to_string(self.project_path.join(requirements_file)).unwrap();
 
-        for line in requirements_content.lines() {
+        for line in join_lines(&requirements_content) {
             let line = line.trim();
 
-            // Ignore empty lines, comments. Also ignore lines starting with `-` to ignore arguments
-            // (package names cannot start with a hyphen). No argument is supported yet, so we can
-            // simply ignore all of them.
-            if line.is_empty() || line.starts_with('#') || line.starts_with('-') {
+            // Ignore empty lines and comments.
+            if line.is_empty() || line.starts_with('#') {
                 continue;
             }
 
-            let dependency = match line.split_once(" #") {
-                Some((dependency, _)) => dependency.trim(),
+            let line = match line.split_once(" #") {
+                Some((line, _)) => line.trim(),
                 None => line,
             };
-            let dependency = match dependency.split_once('\\') {
-                Some((dependency, _)) => dependency.trim(),
-                None => dependency,
-            };
+
+            if self.parse_index_option(line) {
+                continue;
+            }
+
+            if !check_env_vars(requirements_file, line) {
+                continue;
+            }
+
+            if let Some((reference, is_constraints_reference)) = parse_reference(line) {
+                self.parse_reference(
+                    requirements_file,
+                    reference,
+                    is_constraints_file || is_constraints_reference,
+                );
+                continue;
+            }
+
+            if let Some(requirement) = parse_option(line, Some("-e"), "--editable") {
+                // Editable requirements have no meaning in constraints files.
+                if !is_constraints_file {
+                    self.parse_direct_reference(requirements_file, requirement, true);
+                }
+                continue;
+            }
+
+            // Ignore other lines starting with `-` to ignore arguments (package names cannot start
+            // with a hyphen), as no other argument is supported yet.
+            if line.starts_with('-') {
+                continue;
+            }
+
+            let (dependency, hashes) = parse_hashes(line);
 
             let dependency_specification = Requirement::<Url>::from_str(dependency);
 
-            if let Ok(dependency_specification) = dependency_specification {
-                dependencies.push(dependency_specification.to_string());
+            if !hashes.is_empty()
+                && let Ok(dependency_specification) = &dependency_specification
+            {
+                self.hashes.push(HashedRequirement {
+                    name: normalize_dependency_name(dependency_specification.name.as_ref()),
+                    file: requirements_file.to_path_buf(),
+                    hashes,
+                });
+            }
+
+            let uses_project_root =
+                dependency.contains(&format!("${{{}}}", env_vars::PROJECT_ROOT));
+
+            // Local files referenced with a URL are migrated to uv sources, so that their paths
+            // can be made relative, unless they are already relative to the project root.
+            let is_file_reference = !uses_project_root
+                && dependency_specification.as_ref().is_ok_and(|specification| {
+                    matches!(&specification.version_or_url, Some(VersionOrUrl::Url(url)) if url.scheme() == "file")
+                });
+
+            if let Ok(dependency_specification) = dependency_specification
+                && (is_constraints_file || !is_file_reference)
+            {
+                if is_constraints_file {
+                    if !self
+                        .constraints
+                        .contains(&dependency_specification.to_string())
+                    {
+                        self.constraints.push(dependency_specification.to_string());
+                    }
+                } else if uses_project_root {
+                    // uv also expands `${PROJECT_ROOT}`, so the requirement is kept as is, instead of
+                    // its normalized form, in which the variable would be percent-encoded.
+                    self.dependencies.push(dependency.to_string());
+                } else {
+                    self.dependencies.push(dependency_specification.to_string());
+                }
+            } else if !is_constraints_file {
+                self.parse_direct_reference(requirements_file, dependency, false);
             } else {
                 add_recoverable_error(format!(
                     "\"{}\" from \"{}\" could not be automatically migrated, try running \"{}\".",
                     dependency.bold(),
-                    requirements_file.bold(),
+                    requirements_file.display().bold(),
                     format!("uv add {dependency}").bold(),
                 ));
             }
         }
     }
 
-    if dependencies.is_empty() {
-        return None;
+    /// Parse a requirement that points to a local path or a VCS repository, adding the package as
+    /// a dependency, and its location as a uv source.
+    fn parse_direct_reference(
+        &mut self,
+        requirements_file: &Path,
+        requirement: &str,
+        editable: bool,
+    ) {
+        // The project itself is installed by uv when it is a package, so it is not added as a
+        // dependency of itself.
+        if let Some(project_reference) =
+            direct_references::get_project_reference(self.project_path, requirement)
+        {
+            self.installs_project = true;
+
+            if let Some(extras) = project_reference.extras {
+                add_recoverable_error(format!(
+                    "Extras \"{}\" of the project installed from \"{}\" are not installed by default by uv, use \"{}\" to install them.",
+                    extras.bold(),
+                    requirements_file.display().bold(),
+                    "--extra".bold(),
+                ));
+            }
+            return;
+        }
+
+        if let Some(direct_reference) =
+            direct_references::get(self.project_path, requirement, editable)
+        {
+            self.dependencies.push(direct_reference.dependency);
+            self.sources.insert(
+                direct_reference.name,
+                SourceContainer::SourceIndex(direct_reference.source),
+            );
+        } else {
+            let (line, uv_add_command) = if editable {
+                (
+                    format!("-e {requirement}"),
+                    format!("uv add --editable {requirement}"),
+                )
+            } else {
+                (requirement.to_string(), format!("uv add {requirement}"))
+            };
+
+            add_recoverable_error(format!(
+                "\"{}\" from \"{}\" could not be automatically migrated, try running \"{}\".",
+                line.bold(),
+                requirements_file.display().bold(),
+                uv_add_command.bold(),
+            ));
+        }
+    }
+
+    /// Parse index options, returning whether the line holds one.
+    fn parse_index_option(&mut self, line: &str) -> bool {
+        if let Some(index_url) = parse_option(line, Some("-i"), "--index-url") {
+            // As pip does, the last index URL takes precedence.
+            self.index_options.index_url = Some(index_url.to_string());
+            return true;
+        }
+
+        for (short_option, long_option, values) in [
+            (
+                None,
+                "--extra-index-url",
+                &mut self.index_options.extra_index_urls,
+            ),
+            (
+                Some("-f"),
+                "--find-links",
+                &mut self.index_options.find_links,
+            ),
+            (
+                None,
+                "--trusted-host",
+                &mut self.index_options.trusted_hosts,
+            ),
+        ] {
+            if let Some(value) = parse_option(line, short_option, long_option) {
+                if !values.iter().any(|v| v == value) {
+                    values.push(value.to_string());
+                }
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Parse a file referenced from another one. As pip does, the path of the referenced file is
+    /// relative to the file that references it.
+    fn parse_reference(
+        &mut self,
+        requirements_file: &Path,
+        reference: &str,
+        is_constraints_file: bool,
+    ) {
+        let referenced_file = normalize_path(
+            &requirements_file
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(reference),
+        );
+
+        if !self.project_path.join(&referenced_file).is_file() {
+            add_recoverable_error(format!(
+                "\"{}\" referenced from \"{}\" could not be found, so it was not migrated.",
+                reference.bold(),
+                requirements_file.display().bold(),
+            ));
+            return;
+        }
+
+        // With pip-tools, development requirements are usually constrained to the versions
+        // compiled for production requirements (e.g. `-c requirements.txt` in
+        // `requirements-dev.in`). Those versions are locked anyway, so they are not kept as
+        // constraints.
+        if is_constraints_file
+            && referenced_file.extension().is_some_and(|ext| ext == "txt")
+            && self
+                .project_path
+                .join(referenced_file.with_extension("in"))
+                .exists()
+        {
+            return;
+        }
+
+        self.parse(&referenced_file, is_constraints_file);
+    }
+}
+
+/// Parse an option that takes a value, either using the long form (e.g. `--requirement base.txt`
+/// or `--requirement=base.txt`) or the short form (e.g. `-r base.txt` or `-rbase.txt`), returning
+/// the value.
+fn parse_option<'a>(
+    line: &'a str,
+    short_option: Option<&str>,
+    long_option: &str,
+) -> Option<&'a str> {
+    let value = if let Some(value) = line.strip_prefix(long_option) {
+        value
+            .strip_prefix('=')
+            .or_else(|| value.strip_prefix([' ', '\t']))
+    } else {
+        line.strip_prefix(short_option?)
+    };
+
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+/// Join lines ending with a backslash with the following ones, as pip does, so that requirements
+/// split across multiple lines (e.g. to list hashes) are parsed as a whole. Comments cannot be
+/// continued.
+fn join_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut continued_line: Option<String> = None;
+
+    for line in content.lines() {
+        let is_comment = line.trim_start().starts_with('#') || line.contains(" #");
+
+        if !is_comment && let Some(line) = line.strip_suffix('\\') {
+            continued_line.get_or_insert_default().push_str(line);
+            continue;
+        }
+
+        match continued_line.take() {
+            // Comments ending a continuation are not part of the requirement.
+            Some(continued_line) if is_comment => {
+                lines.push(continued_line);
+                lines.push(line.to_string());
+            }
+            Some(mut continued_line) => {
+                continued_line.push_str(line);
+                lines.push(continued_line);
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+
+    if let Some(continued_line) = continued_line {
+        lines.push(continued_line);
+    }
+
+    lines
+}
+
+/// Check that environment variables referenced in a line (e.g. `${TOKEN}`), that pip expands,
+/// can also be expanded by uv, returning whether the line can be migrated. uv only expands
+/// `${PROJECT_ROOT}` in requirements. Index options are checked when converting them to indexes.
+fn check_env_vars(requirements_file: &Path, line: &str) -> bool {
+    let is_requirement =
+        !line.starts_with('-') || parse_option(line, Some("-e"), "--editable").is_some();
+
+    let unsupported_env_vars: Vec<&str> = env_vars::get_names(line)
+        .into_iter()
+        .filter(|name| !is_requirement || *name != env_vars::PROJECT_ROOT)
+        .collect();
+
+    if unsupported_env_vars.is_empty() {
+        return true;
+    }
+
+    add_recoverable_error(format!(
+        "\"{}\" from \"{}\" uses environment variables ({}) that uv does not expand in \"{}\", so it could not be automatically migrated.",
+        line.bold(),
+        requirements_file.display().bold(),
+        env_vars::format_names(&unsupported_env_vars),
+        "pyproject.toml".bold(),
+    ));
+
+    false
+}
+
+/// Split a requirement from its hashes (e.g. `django==4.1.7 --hash=sha256:<hash>`), returning the
+/// requirement and the hashes. Other per-requirement options are ignored.
+fn parse_hashes(line: &str) -> (&str, Vec<String>) {
+    let Some((requirement, options)) = line.split_once(" --") else {
+        return (line.trim(), Vec::new());
+    };
+
+    let mut hashes: Vec<String> = Vec::new();
+    let mut options = options.split_whitespace();
+
+    // The first option was stripped from its leading hyphens when splitting.
+    let mut option = options.next().map(|option| format!("--{option}"));
+    while let Some(current_option) = option {
+        if let Some(hash) = current_option.strip_prefix("--hash=") {
+            hashes.push(hash.to_string());
+        } else if current_option == "--hash"
+            && let Some(hash) = options.next()
+        {
+            hashes.push(hash.to_string());
+        }
+        option = options.next().map(ToString::to_string);
+    }
+
+    (requirement.trim(), hashes)
+}
+
+/// Parse a reference to a requirements file (e.g. `-r base.txt` or `--requirement=base.txt`) or a
+/// constraints file (e.g. `-c constraints.txt` or `--constraint=constraints.txt`), returning the
+/// referenced file, and whether this is a constraints file.
+fn parse_reference(line: &str) -> Option<(&str, bool)> {
+    if let Some(reference) = parse_option(line, Some("-r"), "--requirement") {
+        return Some((reference, false));
+    }
+    if let Some(reference) = parse_option(line, Some("-c"), "--constraint") {
+        return Some((reference, true));
+    }
+    None
+}
+
+/// Lexically normalize a path, so that the same file referenced from different places is only
+/// parsed once.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized_path = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir if normalized_path.file_name().is_some() => {
+                normalized_path.pop();
+            }
+            _ => normalized_path.push(component),
+        }
+    }
+
+    normalized_path
+}
+
+/// Get the files referenced from requirements files, either as requirements files
+/// (`-r`/`--requirement`) or as constraints files (`-c`/`--constraint`), following references
+/// recursively. Paths are relative to the project, and lexically normalized.
+pub fn get_referenced_files(
+    project_path: &Path,
+    requirements_files: &[&String],
+) -> HashSet<PathBuf> {
+    let mut referenced_files = HashSet::new();
+    let mut visited_files = HashSet::new();
+    let mut to_visit: Vec<PathBuf> = requirements_files
+        .iter()
+        .map(|file| normalize_path(Path::new(file)))
+        .collect();
+
+    while let Some(file) = to_visit.pop() {
+        if !visited_files.insert(file.clone()) {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(project_path.join(&file)) else {
+            continue;
+        };
+
+        for line in join_lines(&content) {
+            if let Some((reference, _)) = parse_reference(line.trim()) {
+                let referenced_file = normalize_path(
+                    &file
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(reference),
+                );
+
+                referenced_files.insert(referenced_file.clone());
+                to_visit.push(referenced_file);
+            }
+        }
+    }
+
+    referenced_files
+}
+
+/// Get the normalized names of the requirements declared in a requirements file, and in the files
+/// it references with `-r`/`--requirement`. Issues are not reported, as they already are when
+/// parsing dependencies.
+pub fn get_declared_names(project_path: &Path, requirements_file: &str) -> HashSet<String> {
+    let mut declared_names = HashSet::new();
+    let mut visited_files = HashSet::new();
+    let mut to_visit = vec![normalize_path(Path::new(requirements_file))];
+
+    while let Some(file) = to_visit.pop() {
+        if !visited_files.insert(file.clone()) {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(project_path.join(&file)) else {
+            continue;
+        };
+
+        for line in join_lines(&content) {
+            let line = match line.split_once(" #") {
+                Some((line, _)) => line.trim(),
+                None => line.trim(),
+            };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some((reference, false)) = parse_reference(line) {
+                to_visit.push(normalize_path(
+                    &file
+                        .parent()
+                        .unwrap_or_else(|| Path::new(""))
+                        .join(reference),
+                ));
+                continue;
+            }
+
+            if line.starts_with('-') {
+                continue;
+            }
+
+            if let Some(name) = compiled::get_name(parse_hashes(line).0) {
+                declared_names.insert(name);
+            }
+        }
+    }
+
+    declared_names
+}
+
+/// Get dependencies and constraints from requirements files. Files from `ignored_files` are not
+/// parsed, which allows skipping files already referenced from production requirements when
+/// parsing development requirements.
+pub fn get(
+    project_path: &Path,
+    requirements_files: Vec<String>,
+    ignored_files: &[PathBuf],
+) -> Requirements {
+    let mut parser = RequirementsParser {
+        project_path,
+        visited_files: ignored_files.iter().cloned().collect(),
+        dependencies: Vec::new(),
+        constraints: Vec::new(),
+        index_options: IndexOptions::default(),
+        sources: IndexMap::new(),
+        hashes: Vec::new(),
+        installs_project: false,
+    };
+
+    for requirements_file in requirements_files {
+        parser.parse(&normalize_path(Path::new(&requirements_file)), false);
+    }
+
+    Requirements {
+        dependencies: if parser.dependencies.is_empty() {
+            None
+        } else {
+            Some(parser.dependencies)
+        },
+        constraints: if parser.constraints.is_empty() {
+            None
+        } else {
+            Some(parser.constraints)
+        },
+        index_options: parser.index_options,
+        sources: parser.sources,
+        hashes: parser.hashes,
+        files: parser
+            .visited_files
+            .into_iter()
+            .filter(|file| !ignored_files.contains(file))
+            .collect(),
+        installs_project: parser.installs_project,
+    }
+}
+
+/// Convert dependencies from development requirements and requirements mapped to other groups to
+/// dependency groups, setting default groups according to the selected strategy.
+pub fn get_dependency_groups_and_default_groups(
+    groups_dependencies: IndexMap<String, Vec<String>>,
+    dependency_groups_strategy: Option<DependencyGroupsStrategy>,
+) -> DependencyGroupsAndDefaultGroups {
+    let mut dependency_groups: IndexMap<String, Vec<DependencyGroupSpecification>> =
+        IndexMap::new();
+    let mut default_groups: Vec<String> = Vec::new();
+    let mut all_default_groups = false;
+
+    for (group, dependencies) in groups_dependencies {
+        dependency_groups
+            .entry(match dependency_groups_strategy {
+                Some(DependencyGroupsStrategy::MergeIntoDev) => "dev".to_string(),
+                _ => group,
+            })
+            .or_default()
+            .extend(
+                dependencies
+                    .into_iter()
+                    .map(DependencyGroupSpecification::String),
+            );
+    }
+
+    if dependency_groups.is_empty() {
+        return (None, None);
+    }
+
+    // pip installs all requirements files that are passed to it, so all groups are installed by
+    // default. If we only have `dev` dependency group, there is nothing to do, as this is already
+    // uv's default.
+    if !dependency_groups.keys().eq(["dev"]) {
+        match dependency_groups_strategy {
+            None | Some(DependencyGroupsStrategy::SetDefaultGroupsAll) => {
+                all_default_groups = true;
+            }
+            Some(DependencyGroupsStrategy::SetDefaultGroups) => {
+                default_groups.extend(dependency_groups.keys().map(ToString::to_string));
+            }
+            Some(DependencyGroupsStrategy::IncludeInDev) => {
+                let groups_to_include: Vec<String> = dependency_groups
+                    .keys()
+                    .filter(|&k| k != "dev")
+                    .cloned()
+                    .collect();
+
+                dependency_groups
+                    .entry("dev".to_string())
+                    .or_default()
+                    .extend(groups_to_include.into_iter().map(|g| {
+                        DependencyGroupSpecification::Map {
+                            include_group: Some(g),
+                        }
+                    }));
+            }
+            _ => (),
+        }
+    }
+
+    let default_groups = if all_default_groups {
+        Some(SingleOrVec::Single("all".to_string()))
+    } else if default_groups.is_empty() {
+        None
+    } else {
+        Some(SingleOrVec::Vec(default_groups))
+    };
+
+    (Some(dependency_groups), default_groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    #[rstest]
+    #[case("-r base.txt", Some(("base.txt", false)))]
+    #[case("-rbase.txt", Some(("base.txt", false)))]
+    #[case("--requirement base.txt", Some(("base.txt", false)))]
+    #[case("--requirement=base.txt", Some(("base.txt", false)))]
+    #[case("-c constraints.txt", Some(("constraints.txt", true)))]
+    #[case("-cconstraints.txt", Some(("constraints.txt", true)))]
+    #[case("--constraint constraints.txt", Some(("constraints.txt", true)))]
+    #[case("--constraint=constraints.txt", Some(("constraints.txt", true)))]
+    #[case("--requirements base.txt", None)]
+    #[case("-e .", None)]
+    #[case("-r", None)]
+    fn test_parse_reference(#[case] line: &str, #[case] expected: Option<(&str, bool)>) {
+        assert_eq!(parse_reference(line), expected);
+    }
+
+    #[test]
+    fn test_join_lines() {
+        assert_eq!(
+            join_lines(
+                "django==4.1.7 \\\n    --hash=sha256:44f7 \\\n    --hash=sha256:f2f4\n# Comment \\\narrow==1.3.0"
+            ),
+            vec![
+                "django==4.1.7     --hash=sha256:44f7     --hash=sha256:f2f4",
+                "# Comment \\",
+                "arrow==1.3.0",
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("arrow==1.3.0", ("arrow==1.3.0", Vec::new()))]
+    #[case("arrow==1.3.0 --hash=sha256:44f7 --hash sha256:f2f4", ("arrow==1.3.0", vec!["sha256:44f7", "sha256:f2f4"]))]
+    #[case("arrow==1.3.0 --config-settings=foo=bar --hash=sha256:44f7", ("arrow==1.3.0", vec!["sha256:44f7"]))]
+    fn test_parse_hashes(#[case] line: &str, #[case] expected: (&str, Vec<&str>)) {
+        let (requirement, hashes) = parse_hashes(line);
+        assert_eq!(
+            (
+                requirement,
+                hashes.iter().map(String::as_str).collect::<Vec<&str>>()
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_get_references() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        fs::create_dir(project_path.join("requirements")).unwrap();
+
+        for (file, content) in [
+            (
+                "requirements/base.txt",
+                "arrow==1.3.0\n-c ../constraints.txt",
+            ),
+            ("requirements/prod.txt", "-r base.txt\ngunicorn==23.0.0"),
+            (
+                "requirements/test.txt",
+                "-r ./base.txt\n-r test.txt\npytest==8.3.4",
+            ),
+            ("constraints.txt", "six==1.17.0"),
+        ] {
+            let mut requirements_file = File::create(project_path.join(file)).unwrap();
+            requirements_file.write_all(content.as_bytes()).unwrap();
+        }
+
+        let requirements = get(project_path, vec!["requirements/prod.txt".to_string()], &[]);
+        assert_eq!(
+            requirements.dependencies,
+            Some(vec![
+                "arrow==1.3.0".to_string(),
+                "gunicorn==23.0.0".to_string()
+            ])
+        );
+        assert_eq!(
+            requirements.constraints,
+            Some(vec!["six==1.17.0".to_string()])
+        );
+
+        let dev_requirements = get(
+            project_path,
+            vec!["requirements/test.txt".to_string()],
+            &requirements.files,
+        );
+        assert_eq!(
+            dev_requirements,
+            Requirements {
+                dependencies: Some(vec!["pytest==8.3.4".to_string()]),
+                constraints: None,
+                index_options: IndexOptions::default(),
+                sources: IndexMap::new(),
+                hashes: Vec::new(),
+                files: vec![PathBuf::from("requirements/test.txt")],
+                installs_project: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_declared_names() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        fs::create_dir(project_path.join("requirements")).unwrap();
+
+        for (file, content) in [
+            (
+                "requirements/base.in",
+                "Arrow>=1.2.3 # via pip-compile\n-c ../constraints.txt",
+            ),
+            (
+                "requirements/dev.in",
+                "-r base.in\n-e ./libs/foo\npytest==8.3.4 \\\n    --hash=sha256:abc",
+            ),
+            ("requirements/docs.in", "mkdocs"),
+            ("constraints.txt", "six==1.17.0"),
+        ] {
+            let mut requirements_file = File::create(project_path.join(file)).unwrap();
+            requirements_file.write_all(content.as_bytes()).unwrap();
+        }
+
+        assert_eq!(
+            get_declared_names(project_path, "requirements/dev.in"),
+            HashSet::from(["arrow".to_string(), "pytest".to_string()])
+        );
     }
-    Some(dependencies)
 }
@@ -0,0 +1,109 @@
+use crate::converters::pip::dependencies::HashedRequirement;
+use crate::errors::add_recoverable_error;
+use crate::schema::uv::UvLock;
+use crate::utils::normalize_dependency_name;
+use log::debug;
+use owo_colors::OwoColorize;
+
+/// Check that the hashes of requirements pinned with `--hash` can all be found in the lock file
+/// generated by uv for the same packages, adding an error for each requirement for which some
+/// hashes are missing, as this means that uv resolved different artifacts than the ones pip was
+/// installing.
+pub fn check(lock: &UvLock, hashed_requirements: &[HashedRequirement]) {
+    let mut has_mismatches = false;
+
+    for hashed_requirement in hashed_requirements {
+        let locked_hashes: Vec<String> = lock
+            .package
+            .iter()
+            .flatten()
+            .filter(|package| normalize_dependency_name(&package.name) == hashed_requirement.name)
+            .flat_map(|package| package.sdist.iter().chain(package.wheels.iter().flatten()))
+            .filter_map(|artifact| artifact.hash.as_ref())
+            .map(|hash| hash.to_lowercase())
+            .collect();
+
+        let missing_hashes: Vec<&String> = hashed_requirement
+            .hashes
+            .iter()
+            .filter(|hash| !locked_hashes.contains(&hash.to_lowercase()))
+            .collect();
+
+        if missing_hashes.is_empty() {
+            continue;
+        }
+
+        has_mismatches = true;
+
+        add_recoverable_error(format!(
+            "The following hashes pinned for \"{}\" in \"{}\" could not be found in \"{}\": {}. uv may have resolved different artifacts than the ones previously installed.",
+            hashed_requirement.name.bold(),
+            hashed_requirement.file.display().bold(),
+            "uv.lock".bold(),
+            missing_hashes
+                .iter()
+                .map(|hash| format!("\"{}\"", hash.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+    }
+
+    if !has_mismatches && !hashed_requirements.is_empty() {
+        debug!("All hashes pinned in requirements files were found in \"uv.lock\".");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::MIGRATION_ERRORS;
+    use crate::schema::uv::{LockedArtifact, LockedPackage};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_check() {
+        let lock = UvLock {
+            package: Some(vec![LockedPackage {
+                name: "Django".to_string(),
+                version: Some("4.1.7".to_string()),
+                sdist: Some(LockedArtifact {
+                    hash: Some("sha256:44f7".to_string()),
+                }),
+                wheels: Some(vec![LockedArtifact {
+                    hash: Some("sha256:f2f4".to_string()),
+                }]),
+            }]),
+        };
+
+        check(
+            &lock,
+            &[
+                HashedRequirement {
+                    name: "django".to_string(),
+                    file: PathBuf::from("requirements.txt"),
+                    hashes: vec!["sha256:44f7".to_string(), "sha256:F2F4".to_string()],
+                },
+                HashedRequirement {
+                    name: "sqlparse".to_string(),
+                    file: PathBuf::from("requirements-hashes.txt"),
+                    hashes: vec!["sha256:0323".to_string()],
+                },
+            ],
+        );
+
+        let migration_errors = MIGRATION_ERRORS.lock().unwrap();
+        let hash_errors: Vec<&String> = migration_errors
+            .iter()
+            .map(|migration_error| &migration_error.error)
+            .filter(|error| error.contains("requirements-hashes.txt"))
+            .collect();
+
+        assert_eq!(hash_errors.len(), 1);
+        assert!(hash_errors[0].contains("sha256:0323"));
+        assert!(
+            !migration_errors
+                .iter()
+                .any(|migration_error| migration_error.error.contains("sha256:44f7"))
+        );
+    }
+}
@@ -1,15 +1,24 @@
+mod compiled;
 mod dependencies;
+mod direct_references;
+mod hashes;
+mod sources;
+
+pub use dependencies::get_referenced_files;
 
 use crate::converters::Converter;
 use crate::converters::ConverterOptions;
 use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::errors::add_recoverable_error;
 use crate::schema::pep_621::Project;
-use crate::schema::pyproject::{DependencyGroupSpecification, PyProject};
-use crate::schema::uv::Uv;
+use crate::schema::pyproject::PyProject;
+use crate::schema::uv::{Uv, UvLock};
 use crate::toml::PyprojectPrettyFormatter;
 use indexmap::IndexMap;
+use owo_colors::OwoColorize;
 use std::default::Default;
 use std::fs;
+use std::iter;
 use toml_edit::DocumentMut;
 use toml_edit::visit_mut::VisitMut;
 
@@ -18,6 +27,8 @@ pub struct Pip {
     pub converter_options: ConverterOptions,
     pub requirements_files: Vec<String>,
     pub dev_requirements_files: Vec<String>,
+    /// Requirements files to migrate to dependency groups, indexed by group name.
+    pub requirements_groups: IndexMap<String, Vec<String>>,
     pub is_pip_tools: bool,
 }
 
@@ -27,34 +38,110 @@ impl Converter for Pip {
             fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
         let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
 
-        let dev_dependencies = dependencies::get(
+        let requirements = dependencies::get(
             &self.get_project_path(),
-            self.dev_requirements_files.clone(),
+            self.requirements_files.clone(),
+            &[],
         );
+        // Files already referenced from production requirements are skipped, as their
+        // dependencies are already installed.
+        let groups_requirements: Vec<(String, dependencies::Requirements)> =
+            iter::once(("dev".to_string(), self.dev_requirements_files.clone()))
+                .chain(self.requirements_groups.clone())
+                .map(|(group, files)| {
+                    (
+                        group,
+                        dependencies::get(&self.get_project_path(), files, &requirements.files),
+                    )
+                })
+                .collect();
+
+        let compile_options = if self.is_pip_tools {
+            self.parse_compiled_requirements()
+        } else {
+            Vec::new()
+        };
 
-        let dependency_groups = dev_dependencies.map(|dependencies| {
-            IndexMap::from([(
-                "dev".to_string(),
-                dependencies
-                    .iter()
-                    .map(|dep| DependencyGroupSpecification::String(dep.clone()))
-                    .collect(),
-            )])
-        });
+        // When the project itself is installed from requirements (e.g. with `-e .`), it is a
+        // package that uv should install.
+        let installs_project = requirements.installs_project
+            || groups_requirements
+                .iter()
+                .any(|(_, requirements)| requirements.installs_project);
+
+        let mut constraint_dependencies: Vec<String> = self
+            .get_constraint_dependencies()
+            .into_iter()
+            .flatten()
+            .chain(requirements.constraints.into_iter().flatten())
+            .collect();
+        let mut index_options = requirements.index_options;
+        let mut uv_sources = requirements.sources;
+        let mut groups_dependencies: IndexMap<String, Vec<String>> = IndexMap::new();
+
+        for (group, group_requirements) in groups_requirements {
+            if let Some(dependencies) = group_requirements.dependencies {
+                groups_dependencies
+                    .entry(group)
+                    .or_default()
+                    .extend(dependencies);
+            }
+            constraint_dependencies.extend(group_requirements.constraints.into_iter().flatten());
+            index_options.merge(group_requirements.index_options);
+            uv_sources.extend(group_requirements.sources);
+        }
+
+        let mut prerelease = false;
+
+        for (group, compiled_file, options) in compile_options {
+            prerelease |= options.pre;
+
+            if options.compiles_project() {
+                add_project_extras(
+                    pyproject.project.as_ref(),
+                    group,
+                    &compiled_file,
+                    &options,
+                    &mut groups_dependencies,
+                );
+            }
+
+            index_options.merge(options.index_options);
+        }
+
+        let (dependency_groups, uv_default_groups) =
+            dependencies::get_dependency_groups_and_default_groups(
+                groups_dependencies,
+                self.get_dependency_groups_strategy(),
+            );
 
         let project = Project {
             // "name" is required by uv.
             name: Some(String::new()),
-            dependencies: dependencies::get(
-                &self.get_project_path(),
-                self.requirements_files.clone(),
-            ),
+            dependencies: requirements.dependencies,
             ..Default::default()
         };
 
         let uv = Uv {
-            package: Some(false),
-            constraint_dependencies: self.get_constraint_dependencies(),
+            package: Some(installs_project),
+            index: sources::get_indexes(&index_options),
+            allow_insecure_host: if index_options.trusted_hosts.is_empty() {
+                None
+            } else {
+                Some(index_options.trusted_hosts)
+            },
+            prerelease: prerelease.then(|| "allow".to_string()),
+            sources: if uv_sources.is_empty() {
+                None
+            } else {
+                Some(uv_sources)
+            },
+            constraint_dependencies: if constraint_dependencies.is_empty() {
+                None
+            } else {
+                Some(constraint_dependencies)
+            },
+            default_groups: uv_default_groups,
             ..Default::default()
         };
 
@@ -103,6 +190,7 @@ impl Converter for Pip {
             .requirements_files
             .iter()
             .chain(&self.dev_requirements_files)
+            .chain(self.requirements_groups.values().flatten())
         {
             files_to_delete.push(requirements_file.clone());
 
@@ -115,29 +203,191 @@ impl Converter for Pip {
         files_to_delete
     }
 
+    fn check_lock_file(&self) {
+        let Ok(lock_content) = fs::read_to_string(self.get_project_path().join("uv.lock")) else {
+            return;
+        };
+        let Ok(lock) = toml::from_str::<UvLock>(&lock_content) else {
+            return;
+        };
+
+        // For pip-tools, hashes are pinned in `.txt` files compiled from `.in` files.
+        let requirements_files: Vec<String> = self
+            .requirements_files
+            .iter()
+            .chain(&self.dev_requirements_files)
+            .chain(self.requirements_groups.values().flatten())
+            .map(|file| {
+                if self.is_pip_tools {
+                    file.replace(".in", ".txt")
+                } else {
+                    file.clone()
+                }
+            })
+            .filter(|file| self.get_project_path().join(file).exists())
+            .collect();
+
+        let requirements = dependencies::get(&self.get_project_path(), requirements_files, &[]);
+
+        hashes::check(&lock, &requirements.hashes);
+    }
+
     fn get_constraint_dependencies(&self) -> Option<Vec<String>> {
         if !self.is_pip_tools || self.is_dry_run() || !self.respect_locked_versions() {
             return None;
         }
 
-        if let Some(dependencies) = dependencies::get(
-            self.get_project_path().as_path(),
-            self.requirements_files
-                .clone()
-                .into_iter()
-                .chain(self.dev_requirements_files.clone())
-                .map(|f| f.replace(".in", ".txt"))
-                .collect(),
-        ) {
-            if dependencies.is_empty() {
-                return None;
+        let requirements_files: Vec<String> = self
+            .requirements_files
+            .iter()
+            .chain(&self.dev_requirements_files)
+            .chain(self.requirements_groups.values().flatten())
+            .cloned()
+            .collect();
+
+        // Constraints declared by the user (e.g. with `-c constraints.txt`) are set in the same
+        // list as locked versions, and must be kept once locking is done, so locked versions that
+        // are identical to one of them are not returned.
+        let user_constraints =
+            dependencies::get(&self.get_project_path(), requirements_files.clone(), &[])
+                .constraints
+                .unwrap_or_default();
+
+        let mut locked_versions: Vec<String> = Vec::new();
+
+        for requirements_file in requirements_files {
+            let Ok(content) = fs::read_to_string(
+                self.get_project_path()
+                    .join(requirements_file.replace(".in", ".txt")),
+            ) else {
+                continue;
+            };
+
+            for locked_version in compiled::parse(&content).locked_versions {
+                if !user_constraints.contains(&locked_version)
+                    && !locked_versions.contains(&locked_version)
+                {
+                    locked_versions.push(locked_version);
+                }
             }
-            return Some(dependencies);
         }
-        None
+
+        Some(locked_versions)
     }
 }
 
+impl Pip {
+    /// Parse `.txt` files compiled from `.in` files, checking that the packages they pin can still
+    /// be traced back to the requirements declared in the `.in` file they were compiled from (or
+    /// in files it references), and returning the options of the
+    /// `pip-compile` command used to compile each file, along with the dependency group the file
+    /// is migrated to (if any).
+    fn parse_compiled_requirements(&self) -> Vec<(Option<&str>, String, compiled::CompileOptions)> {
+        let mut compile_options = Vec::new();
+
+        for (group, requirements_file) in self
+            .requirements_files
+            .iter()
+            .map(|file| (None, file))
+            .chain(
+                self.dev_requirements_files
+                    .iter()
+                    .map(|file| (Some("dev"), file)),
+            )
+            .chain(self.requirements_groups.iter().flat_map(|(group, files)| {
+                files.iter().map(move |file| (Some(group.as_str()), file))
+            }))
+        {
+            let compiled_file = requirements_file.replace(".in", ".txt");
+
+            let Ok(content) = fs::read_to_string(self.get_project_path().join(&compiled_file))
+            else {
+                continue;
+            };
+
+            let compiled_requirements = compiled::parse(&content);
+            compiled::check_provenance(
+                &compiled_requirements,
+                &compiled_file,
+                requirements_file,
+                &dependencies::get_declared_names(&self.get_project_path(), requirements_file),
+            );
+
+            let options = compiled::parse_compile_options(&compiled_requirements.command);
+
+            if !options.unsupported_options.is_empty() {
+                add_recoverable_error(format!(
+                    "The following options used to compile \"{}\" could not be migrated, as uv handles them differently: {}.",
+                    compiled_file.bold(),
+                    options
+                        .unsupported_options
+                        .iter()
+                        .map(|o| format!("\"{}\"", o.bold()))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ));
+            }
+
+            compile_options.push((group, compiled_file, options));
+        }
+
+        compile_options
+    }
+}
+
+/// Add extras of the project that were compiled alongside its dependencies (e.g. with
+/// `pip-compile --extra=dev pyproject.toml`) to the dependency group of the compiled file, as a
+/// reference to the project itself, so that they keep being installed together.
+fn add_project_extras(
+    project: Option<&Project>,
+    group: Option<&str>,
+    compiled_file: &str,
+    options: &compiled::CompileOptions,
+    groups_dependencies: &mut IndexMap<String, Vec<String>>,
+) {
+    let extras: Vec<String> = if options.all_extras {
+        project
+            .and_then(|project| project.optional_dependencies.as_ref())
+            .map(|optional_dependencies| optional_dependencies.keys().cloned().collect())
+            .unwrap_or_default()
+    } else {
+        options.extras.clone()
+    };
+
+    if extras.is_empty() {
+        return;
+    }
+
+    let Some(group) = group else {
+        add_recoverable_error(format!(
+            "Extras ({}) compiled in \"{}\" could not be migrated, as uv does not install extras by default. Use \"{}\" to install them.",
+            extras
+                .iter()
+                .map(|e| format!("\"{}\"", e.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+            compiled_file.bold(),
+            format!("uv sync --extra {}", extras.join(" --extra ")).bold(),
+        ));
+        return;
+    };
+
+    let Some(project_name) = project.and_then(|project| project.name.as_ref()) else {
+        add_recoverable_error(format!(
+            "Extras compiled in \"{}\" could not be migrated, as \"{}\" is not set under \"{}\".",
+            compiled_file.bold(),
+            "name".bold(),
+            "[project]".bold(),
+        ));
+        return;
+    };
+
+    groups_dependencies
+        .entry(group.to_string())
+        .or_default()
+        .push(format!("{project_name}[{}]", extras.join(",")));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +430,7 @@ mod tests {
             },
             requirements_files: vec!["requirements.txt".to_string()],
             dev_requirements_files: Vec::new(),
+            requirements_groups: IndexMap::new(),
             is_pip_tools: false,
         };
 
@@ -227,6 +478,7 @@ mod tests {
             },
             requirements_files: vec!["requirements.txt".to_string()],
             dev_requirements_files: Vec::new(),
+            requirements_groups: IndexMap::new(),
             is_pip_tools: false,
         };
 
@@ -0,0 +1,173 @@
+use crate::converters::env_vars;
+use crate::schema::uv::Index;
+use url::Url;
+
+const PYPI_URL: &str = "https://pypi.org/simple";
+
+/// Index options defined in requirements files
+/// (<https://pip.pypa.io/en/stable/reference/requirements-file-format/#global-options>).
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct IndexOptions {
+    pub index_url: Option<String>,
+    pub extra_index_urls: Vec<String>,
+    pub find_links: Vec<String>,
+    pub trusted_hosts: Vec<String>,
+}
+
+impl IndexOptions {
+    /// Merge options from another set of requirements files. As pip only uses one index URL, the
+    /// one already set (e.g. from production requirements) takes precedence.
+    pub fn merge(&mut self, other: Self) {
+        if self.index_url.is_none() {
+            self.index_url = other.index_url;
+        }
+
+        for (values, other_values) in [
+            (&mut self.extra_index_urls, other.extra_index_urls),
+            (&mut self.find_links, other.find_links),
+            (&mut self.trusted_hosts, other.trusted_hosts),
+        ] {
+            for value in other_values {
+                if !values.contains(&value) {
+                    values.push(value);
+                }
+            }
+        }
+    }
+}
+
+/// Get a name for the index, since uv requires one, based on the host of the URL (or the last
+/// component of the path, for local directories).
+fn get_index_name(url: &str, indexes: &[Index]) -> String {
+    let name = match Url::parse(url) {
+        Ok(url) if url.host_str().is_some() => url.host_str().unwrap_or_default().to_string(),
+        _ => url
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    };
+    // Names of indexes can only contain alphanumeric characters, dashes, underscores and periods,
+    // while URLs can reference environment variables (e.g. `https://${HOST}/simple`).
+    let name = name.replace(
+        |c: char| !c.is_ascii_alphanumeric() && !['-', '_', '.'].contains(&c),
+        "",
+    );
+
+    let mut unique_name = name.clone();
+    let mut suffix = 2;
+    while indexes.iter().any(|index| index.name == unique_name) {
+        unique_name = format!("{name}-{suffix}");
+        suffix += 1;
+    }
+
+    unique_name
+}
+
+pub fn get_indexes(index_options: &IndexOptions) -> Option<Vec<Index>> {
+    let mut indexes: Vec<Index> = Vec::new();
+
+    // `--index-url` replaces PyPI, which corresponds to setting `default = true` in uv, unless the
+    // URL is PyPI one, in which case there is nothing to do, as PyPI is already the default index.
+    if let Some(index_url) = &index_options.index_url
+        && index_url.trim_end_matches('/') != PYPI_URL
+    {
+        let name = get_index_name(index_url, &indexes);
+        indexes.push(Index {
+            url: Some(env_vars::get_index_url(&name, index_url)),
+            name,
+            default: Some(true),
+            ..Default::default()
+        });
+    }
+
+    for extra_index_url in &index_options.extra_index_urls {
+        let name = get_index_name(extra_index_url, &indexes);
+        indexes.push(Index {
+            url: Some(env_vars::get_index_url(&name, extra_index_url)),
+            name,
+            ..Default::default()
+        });
+    }
+
+    // https://docs.astral.sh/uv/concepts/indexes/#flat-indexes
+    for find_links in &index_options.find_links {
+        let name = get_index_name(find_links, &indexes);
+        indexes.push(Index {
+            url: Some(env_vars::get_index_url(&name, find_links)),
+            name,
+            format: Some("flat".to_string()),
+            ..Default::default()
+        });
+    }
+
+    if indexes.is_empty() {
+        return None;
+    }
+    Some(indexes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_indexes() {
+        let index_options = IndexOptions {
+            index_url: Some("https://mirror.example.com/simple".to_string()),
+            extra_index_urls: vec![
+                "https://mirror.example.com/other/simple".to_string(),
+                "https://foo.example.com/simple/".to_string(),
+            ],
+            find_links: vec![
+                "https://foo.example.com/wheels/".to_string(),
+                "./wheels".to_string(),
+            ],
+            trusted_hosts: Vec::new(),
+        };
+
+        let expected = vec![
+            Index {
+                name: "mirror.example.com".to_string(),
+                url: Some("https://mirror.example.com/simple".to_string()),
+                default: Some(true),
+                ..Default::default()
+            },
+            Index {
+                name: "mirror.example.com-2".to_string(),
+                url: Some("https://mirror.example.com/other/simple".to_string()),
+                ..Default::default()
+            },
+            Index {
+                name: "foo.example.com".to_string(),
+                url: Some("https://foo.example.com/simple/".to_string()),
+                ..Default::default()
+            },
+            Index {
+                name: "foo.example.com-2".to_string(),
+                url: Some("https://foo.example.com/wheels/".to_string()),
+                format: Some("flat".to_string()),
+                ..Default::default()
+            },
+            Index {
+                name: "wheels".to_string(),
+                url: Some("./wheels".to_string()),
+                format: Some("flat".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(get_indexes(&index_options), Some(expected));
+    }
+
+    #[test]
+    fn test_get_indexes_pypi() {
+        let index_options = IndexOptions {
+            index_url: Some("https://pypi.org/simple/".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(get_indexes(&index_options), None);
+    }
+}
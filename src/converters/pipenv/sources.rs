@@ -1,3 +1,4 @@
+use crate::converters::env_vars;
 use crate::schema::pipenv::Source;
 use crate::schema::uv::Index;
 
@@ -7,7 +8,7 @@ pub fn get_indexes(pipenv_sources: Option<Vec<Source>>) -> Option<Vec<Index>> {
             .iter()
             .map(|source| Index {
                 name: source.name.clone(),
-                url: Some(source.url.clone()),
+                url: Some(env_vars::get_index_url(&source.name, &source.url)),
                 // https://pipenv.pypa.io/en/stable/indexes.html#index-restricted-packages
                 explicit: (source.name.to_lowercase() != "pypi").then_some(true),
                 ..Default::default()
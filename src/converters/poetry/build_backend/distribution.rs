@@ -0,0 +1,656 @@
+use crate::converters::poetry::build_backend::{
+    BuildBackendObject, get_include_distribution_format, get_packages_distribution_format,
+};
+use crate::schema::hatch::{BuildTarget, Hatch};
+use crate::schema::poetry::{Include, Package, Poetry};
+use crate::schema::utils::SingleOrVec;
+use crate::schema::uv::UvBuildBackend;
+use owo_colors::OwoColorize;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+/// Files that every build backend adds to distributions as metadata, and that are therefore not
+/// compared.
+const METADATA_FILE_PREFIXES: [&str; 5] = ["LICENSE", "LICENCE", "COPYING", "NOTICE", "AUTHORS"];
+
+/// Directory of wheels in which scripts are stored
+/// (<https://packaging.python.org/en/latest/specifications/binary-distribution-format/#the-data-directory>).
+/// The distribution name and version that prefix `.data` are omitted, as they are the same for
+/// all build backends.
+const WHEEL_SCRIPTS_DIRECTORY: &str = ".data/scripts";
+
+/// Files included in the source distribution and wheels, with paths relative to the root of each
+/// distribution.
+#[derive(Default, Debug, PartialEq, Eq)]
+struct DistributionFiles {
+    sdist: BTreeSet<String>,
+    wheel: BTreeSet<String>,
+}
+
+/// A file of the project, with its path relative to the project root.
+struct ProjectFile {
+    path: String,
+    vcs_ignored: bool,
+}
+
+/// Glob pattern matched against relative paths. A pattern matches a path if it matches the path
+/// itself, or one of its parent directories, in which case all files under the directory match.
+struct Pattern {
+    regex: Regex,
+    anchored: bool,
+    negated: bool,
+}
+
+impl Pattern {
+    /// Pattern that only matches from the root.
+    fn anchored(pattern: &str) -> Self {
+        Self::new(pattern.trim_start_matches('/'), true, false)
+    }
+
+    /// Pattern that matches at any depth, unless it starts with `/`, as used by uv excludes
+    /// (<https://docs.astral.sh/uv/concepts/build-backend/#file-inclusion-and-exclusion>).
+    fn unanchored(pattern: &str) -> Self {
+        Self::new(
+            pattern.trim_start_matches('/'),
+            pattern.starts_with('/'),
+            false,
+        )
+    }
+
+    /// Pattern following `.gitignore` semantics (<https://git-scm.com/docs/gitignore>), which
+    /// Hatch also uses, where patterns containing a separator only match from the root.
+    fn gitignore(pattern: &str) -> Self {
+        let (pattern, negated) = match pattern.strip_prefix('!') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
+        let pattern = pattern.trim_end_matches('/');
+
+        Self::new(
+            pattern.trim_start_matches('/'),
+            pattern.contains('/'),
+            negated,
+        )
+    }
+
+    fn new(pattern: &str, anchored: bool, negated: bool) -> Self {
+        let mut regex = String::from("^");
+        let mut chars = pattern.trim_start_matches("./").chars().peekable();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+
+                    if chars.peek() == Some(&'/') {
+                        chars.next();
+                        regex.push_str("(?:.*/)?");
+                    } else {
+                        regex.push_str(".*");
+                    }
+                }
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                _ => regex.push_str(&regex::escape(&char.to_string())),
+            }
+        }
+        regex.push('$');
+
+        Self {
+            regex: Regex::new(&regex).unwrap(),
+            anchored,
+            negated,
+        }
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        let components: Vec<&str> = path.split('/').collect();
+        let starts = if self.anchored {
+            0..1
+        } else {
+            0..components.len()
+        };
+
+        starts.into_iter().any(|start| {
+            (start + 1..=components.len())
+                .any(|end| self.regex.is_match(&components[start..end].join("/")))
+        })
+    }
+}
+
+/// Whether a path matches any of the patterns.
+fn matches_any(patterns: &[Pattern], path: &str) -> bool {
+    patterns.iter().any(|pattern| pattern.matches(path))
+}
+
+/// List the files of the project, flagging the ones ignored by VCS. Only `.gitignore` at the root
+/// of the project is considered. Git directory, virtual environments and Python bytecode are
+/// skipped, as no build backend includes them.
+fn get_project_files(project_path: &Path) -> Vec<ProjectFile> {
+    let ignore_patterns: Vec<Pattern> = fs::read_to_string(project_path.join(".gitignore"))
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(Pattern::gitignore)
+        .collect();
+
+    let mut paths = Vec::new();
+    collect_files(project_path, "", &mut paths);
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            // As with Git, the last pattern matching the path wins.
+            let vcs_ignored = ignore_patterns
+                .iter()
+                .rfind(|pattern| pattern.matches(&path))
+                .is_some_and(|pattern| !pattern.negated);
+
+            ProjectFile { path, vcs_ignored }
+        })
+        .collect()
+}
+
+fn collect_files(directory: &Path, prefix: &str, paths: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let path = format!("{prefix}{name}");
+        let entry_path = entry.path();
+
+        if entry_path.is_dir() {
+            if name != ".git" && name != "__pycache__" && !entry_path.join("pyvenv.cfg").exists() {
+                collect_files(&entry_path, &format!("{path}/"), paths);
+            }
+        } else if entry_path
+            .extension()
+            .is_none_or(|extension| extension != "pyc")
+        {
+            paths.push(path);
+        }
+    }
+}
+
+/// Whether a file is added to distributions as metadata by all build backends, i.e.
+/// `pyproject.toml`, readme and license files at the root of the project.
+fn is_metadata_file(path: &str, readme: &[String]) -> bool {
+    path == "pyproject.toml"
+        || readme.iter().any(|readme| readme == path)
+        || (!path.contains('/')
+            && METADATA_FILE_PREFIXES
+                .iter()
+                .any(|prefix| path.to_uppercase().starts_with(prefix)))
+}
+
+/// Normalize a package name to the name of its module, as both Poetry and uv do when guessing the
+/// module from the project name.
+fn get_module_name(name: &str) -> String {
+    name.replace(['-', '.'], "_").to_lowercase()
+}
+
+/// Join a directory and a path relative to it, where an empty directory is the root.
+fn join(directory: &str, path: &str) -> String {
+    let directory = directory.trim_start_matches("./").trim_end_matches('/');
+
+    if directory.is_empty() || directory == "." {
+        path.to_string()
+    } else {
+        format!("{directory}/{path}")
+    }
+}
+
+/// Get the files Poetry includes in distributions, following
+/// <https://python-poetry.org/docs/pyproject/#packages> and
+/// <https://python-poetry.org/docs/pyproject/#exclude-and-include>.
+///
+/// Files from `packages` exclude the ones ignored by VCS and the ones matching `exclude`, while
+/// files from `include` are always added. In wheels, packages are placed relatively to `from`,
+/// under `to`, while files from `include` keep their path. The build script, if any, is added to
+/// the source distribution, and file scripts are added to the source distribution, and to the
+/// scripts of wheels.
+fn get_poetry_files(
+    project_path: &Path,
+    project_files: &[ProjectFile],
+    poetry: &Poetry,
+) -> DistributionFiles {
+    let mut files = DistributionFiles::default();
+
+    let exclude: Vec<Pattern> = poetry
+        .exclude
+        .iter()
+        .flatten()
+        .map(|exclude| Pattern::anchored(exclude))
+        .collect();
+
+    let default_packages = get_poetry_default_packages(project_path, poetry.name.as_ref());
+
+    for Package {
+        include,
+        format,
+        from,
+        to,
+    } in poetry.packages.as_ref().unwrap_or(&default_packages)
+    {
+        let from = from.as_deref().unwrap_or_default();
+        let pattern = Pattern::anchored(&join(from, include));
+        let (add_to_sdist, add_to_wheel) = get_packages_distribution_format(format.as_ref());
+
+        for file in project_files {
+            if file.vcs_ignored
+                || !pattern.matches(&file.path)
+                || exclude.iter().any(|exclude| exclude.matches(&file.path))
+            {
+                continue;
+            }
+
+            if add_to_sdist {
+                files.sdist.insert(file.path.clone());
+            }
+            if add_to_wheel {
+                let path = file
+                    .path
+                    .strip_prefix(&join(from, ""))
+                    .unwrap_or(&file.path);
+
+                files
+                    .wheel
+                    .insert(join(to.as_deref().unwrap_or_default(), path));
+            }
+        }
+    }
+
+    // The build script is needed to build wheels from the source distribution.
+    if let Some(build_script) = poetry.get_build_script() {
+        files
+            .sdist
+            .insert(build_script.trim_start_matches("./").to_string());
+    }
+
+    for file_script in poetry.get_file_scripts() {
+        if let Some(name) = Path::new(&file_script).file_name() {
+            files
+                .wheel
+                .insert(join(WHEEL_SCRIPTS_DIRECTORY, &name.to_string_lossy()));
+        }
+        files.sdist.insert(file_script);
+    }
+
+    for inc in poetry.include.iter().flatten() {
+        let (path, format) = match inc {
+            Include::Map { path, format } => (path, format.as_ref()),
+            Include::String(path) => (path, None),
+        };
+        let pattern = Pattern::anchored(path);
+        let (add_to_sdist, add_to_wheel) = get_include_distribution_format(format);
+
+        for file in project_files
+            .iter()
+            .filter(|file| pattern.matches(&file.path))
+        {
+            if add_to_sdist {
+                files.sdist.insert(file.path.clone());
+            }
+            if add_to_wheel {
+                files.wheel.insert(file.path.clone());
+            }
+        }
+    }
+
+    files
+}
+
+/// When `packages` is not set, Poetry looks for a package or a module named after the project,
+/// either at the root of the project or in `src`.
+fn get_poetry_default_packages(project_path: &Path, name: Option<&String>) -> Vec<Package> {
+    let Some(name) = name else {
+        return Vec::new();
+    };
+    let module_name = get_module_name(name);
+
+    [
+        (module_name.clone(), None),
+        (format!("{module_name}.py"), None),
+        (module_name.clone(), Some("src".to_string())),
+        (format!("{module_name}.py"), Some("src".to_string())),
+    ]
+    .into_iter()
+    .find(|(include, from)| {
+        project_path
+            .join(from.as_deref().unwrap_or_default())
+            .join(include)
+            .exists()
+    })
+    .map(|(include, from)| {
+        vec![Package {
+            include,
+            format: None,
+            from,
+            to: None,
+        }]
+    })
+    .unwrap_or_default()
+}
+
+/// Get the files uv build backend includes in distributions, following
+/// <https://docs.astral.sh/uv/concepts/build-backend/#file-inclusion-and-exclusion>.
+///
+/// Modules are looked up in `module-root`, and added to both distributions, while files matching
+/// `source-include` are only added to the source distribution. Files from the `scripts` data
+/// directory are added to the source distribution, and to the scripts of wheels. uv does not rely
+/// on VCS to exclude files.
+fn get_uv_files(
+    project_files: &[ProjectFile],
+    project_name: Option<&String>,
+    uv: &UvBuildBackend,
+) -> DistributionFiles {
+    let mut files = DistributionFiles::default();
+
+    let module_root = uv.module_root.as_deref().unwrap_or("src");
+    let module_names = match &uv.module_name {
+        Some(SingleOrVec::Single(module_name)) => vec![module_name.clone()],
+        Some(SingleOrVec::Vec(module_names)) => module_names.clone(),
+        None => project_name
+            .map(|name| get_module_name(name))
+            .into_iter()
+            .collect(),
+    };
+    let source_exclude: Vec<Pattern> = uv
+        .source_exclude
+        .iter()
+        .flatten()
+        .map(|exclude| Pattern::unanchored(exclude))
+        .collect();
+    let wheel_exclude: Vec<Pattern> = uv
+        .wheel_exclude
+        .iter()
+        .flatten()
+        .map(|exclude| Pattern::unanchored(exclude))
+        .collect();
+
+    let modules: Vec<Pattern> = module_names
+        .iter()
+        .flat_map(|module_name| {
+            let module_path = join(module_root, &module_name.replace('.', "/"));
+            [
+                Pattern::anchored(&module_path),
+                Pattern::anchored(&format!("{module_path}.py")),
+            ]
+        })
+        .collect();
+    let source_include: Vec<Pattern> = uv
+        .source_include
+        .iter()
+        .flatten()
+        .map(|include| Pattern::anchored(include))
+        .collect();
+
+    let scripts_directory = uv
+        .data
+        .as_ref()
+        .and_then(|data| data.scripts.as_deref())
+        .map(|scripts| join(scripts, ""));
+
+    for file in project_files {
+        if let Some(scripts_directory) = &scripts_directory
+            && let Some(path) = file.path.strip_prefix(scripts_directory.as_str())
+        {
+            files.sdist.insert(file.path.clone());
+            files.wheel.insert(join(WHEEL_SCRIPTS_DIRECTORY, path));
+        }
+
+        let is_module = matches_any(&modules, &file.path);
+
+        if (is_module || matches_any(&source_include, &file.path))
+            && !matches_any(&source_exclude, &file.path)
+        {
+            files.sdist.insert(file.path.clone());
+        }
+
+        if is_module {
+            let path = file
+                .path
+                .strip_prefix(&join(module_root, ""))
+                .unwrap_or(&file.path)
+                .to_string();
+
+            if !matches_any(&wheel_exclude, &path) {
+                files.wheel.insert(path);
+            }
+        }
+    }
+
+    files
+}
+
+/// Get the files Hatch includes in distributions, following
+/// <https://hatch.pypa.io/latest/config/build/#file-selection>.
+///
+/// Files matching `include` and not matching `exclude` are added, unless they are ignored by VCS,
+/// while files from `force-include` are always added. In wheels, paths are rewritten with
+/// `sources`, and files from `shared-scripts` are added to scripts.
+fn get_hatch_files(
+    project_path: &Path,
+    project_files: &[ProjectFile],
+    project_name: Option<&String>,
+    hatch: &Hatch,
+) -> DistributionFiles {
+    let targets = hatch
+        .build
+        .as_ref()
+        .and_then(|build| build.targets.as_ref());
+    let get_target = |name: &str| targets.and_then(|targets| targets.get(name));
+
+    DistributionFiles {
+        sdist: get_hatch_target_files(project_files, get_target("sdist"), None),
+        wheel: get_hatch_target_files(
+            project_files,
+            get_target("wheel"),
+            Some(&get_hatch_default_wheel_include(project_path, project_name)),
+        ),
+    }
+}
+
+/// Without `include`, Hatch adds all files to the source distribution, and looks for a package
+/// named after the project, either at the root of the project or in `src`, for wheels
+/// (<https://hatch.pypa.io/latest/plugins/builder/wheel/#default-file-selection>).
+fn get_hatch_default_wheel_include(project_path: &Path, project_name: Option<&String>) -> String {
+    let module_name = project_name
+        .map(|name| get_module_name(name))
+        .unwrap_or_default();
+
+    if project_path.join(&module_name).exists() || !project_path.join("src").exists() {
+        module_name
+    } else {
+        format!("src/{module_name}")
+    }
+}
+
+fn get_hatch_target_files(
+    project_files: &[ProjectFile],
+    target: Option<&BuildTarget>,
+    default_include: Option<&String>,
+) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+
+    let include: Vec<Pattern> = match target.and_then(|target| target.include.as_ref()) {
+        Some(include) => include
+            .iter()
+            .map(|include| Pattern::gitignore(include))
+            .collect(),
+        None => default_include
+            .iter()
+            .map(|include| Pattern::anchored(include))
+            .collect(),
+    };
+    let exclude: Vec<Pattern> = target
+        .and_then(|target| target.exclude.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|exclude| Pattern::gitignore(exclude))
+        .collect();
+    // Hatch strips `src` from wheels when selecting the default package from it.
+    let default_sources = match &default_include {
+        Some(default_include) if default_include.starts_with("src/") => {
+            vec![("src".to_string(), String::new())]
+        }
+        _ => Vec::new(),
+    };
+    let sources: Vec<(String, String)> =
+        target
+            .and_then(|target| target.sources.as_ref())
+            .map_or(default_sources, |sources| {
+                sources
+                    .iter()
+                    .map(|(from, to)| (from.clone(), to.clone()))
+                    .collect()
+            });
+
+    for file in project_files {
+        if file.vcs_ignored
+            || (!include.is_empty() && !matches_any(&include, &file.path))
+            || matches_any(&exclude, &file.path)
+        {
+            continue;
+        }
+
+        let path = sources
+            .iter()
+            .find_map(|(from, to)| {
+                file.path
+                    .strip_prefix(&join(from, ""))
+                    .map(|path| join(to, path))
+            })
+            .unwrap_or_else(|| file.path.clone());
+
+        files.insert(path);
+    }
+
+    for (source, destination) in target
+        .and_then(|target| target.force_include.as_ref())
+        .into_iter()
+        .flatten()
+    {
+        let pattern = Pattern::anchored(source);
+
+        for file in project_files
+            .iter()
+            .filter(|file| pattern.matches(&file.path))
+        {
+            let path = file
+                .path
+                .strip_prefix(source.as_str())
+                .map_or_else(|| file.path.clone(), |path| format!("{destination}{path}"));
+
+            files.insert(path);
+        }
+    }
+
+    for (source, destination) in target
+        .and_then(|target| target.shared_scripts.as_ref())
+        .into_iter()
+        .flatten()
+    {
+        if project_files.iter().any(|file| &file.path == source) {
+            files.insert(join(WHEEL_SCRIPTS_DIRECTORY, destination));
+        }
+    }
+
+    files
+}
+
+/// Compare the files Poetry includes in distributions with the ones the new build backend
+/// includes, returning a report of added and removed files for each distribution. Returns `None`
+/// if files cannot be computed for the new build backend.
+pub fn get_differences(
+    project_path: &Path,
+    poetry: &Poetry,
+    build_backend: &BuildBackendObject,
+) -> Option<Vec<String>> {
+    let project_files = get_project_files(project_path);
+
+    let new_files = match build_backend {
+        BuildBackendObject::Uv(uv) => get_uv_files(&project_files, poetry.name.as_ref(), uv),
+        BuildBackendObject::Hatch(hatch) => {
+            get_hatch_files(project_path, &project_files, poetry.name.as_ref(), hatch)
+        }
+        _ => return None,
+    };
+    let poetry_files = get_poetry_files(project_path, &project_files, poetry);
+
+    let readme: Vec<String> = match &poetry.readme {
+        Some(SingleOrVec::Single(readme)) => vec![readme.clone()],
+        Some(SingleOrVec::Vec(readme)) => readme.clone(),
+        None => Vec::new(),
+    };
+
+    Some(
+        [
+            ("source distribution", &poetry_files.sdist, &new_files.sdist),
+            ("wheels", &poetry_files.wheel, &new_files.wheel),
+        ]
+        .into_iter()
+        .filter_map(|(distribution, poetry_files, new_files)| {
+            let format_files = |files: Vec<&String>| {
+                files
+                    .iter()
+                    .map(|file| format!("\"{}\"", file.bold()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            };
+            let added: Vec<&String> = new_files
+                .difference(poetry_files)
+                .filter(|file| !is_metadata_file(file, &readme))
+                .collect();
+            let removed: Vec<&String> = poetry_files
+                .difference(new_files)
+                .filter(|file| !is_metadata_file(file, &readme))
+                .collect();
+
+            let mut changes = Vec::new();
+            if !added.is_empty() {
+                changes.push(format!("added: {}", format_files(added)));
+            }
+            if !removed.is_empty() {
+                changes.push(format!("removed: {}", format_files(removed)));
+            }
+
+            if changes.is_empty() {
+                None
+            } else {
+                Some(format!(
+                    "Files included in {distribution} differ after migrating build backend to {build_backend} ({}).",
+                    changes.join("; "),
+                ))
+            }
+        })
+        .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Pattern::anchored("foo"), "foo/bar.py", true)]
+    #[case(Pattern::anchored("foo"), "src/foo/bar.py", false)]
+    #[case(Pattern::anchored("foo/**/*.py"), "foo/bar/baz.py", true)]
+    #[case(Pattern::anchored("foo/**/*.py"), "foo/baz.py", true)]
+    #[case(Pattern::anchored("foo/*.py"), "foo/bar/baz.py", false)]
+    #[case(Pattern::unanchored("foo/tests"), "src/foo/tests/test_foo.py", true)]
+    #[case(Pattern::unanchored("/foo"), "src/foo/bar.py", false)]
+    #[case(Pattern::gitignore("*.so"), "foo/_speedups.so", true)]
+    #[case(Pattern::gitignore("build/"), "build/lib/foo.py", true)]
+    #[case(Pattern::gitignore("/foo/bar"), "baz/foo/bar", false)]
+    fn test_pattern_matches(#[case] pattern: Pattern, #[case] path: &str, #[case] expected: bool) {
+        assert_eq!(pattern.matches(path), expected);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::converters::poetry::build_backend::{
+    get_include_distribution_format, get_module_root, get_packages_distribution_format,
+};
+use crate::schema::flit::{Flit, Module, Sdist};
+use crate::schema::poetry::{Include, Package};
+use crate::utils::non_empty_vec;
+use owo_colors::OwoColorize;
+use std::path::Path;
+
+/// Construct Flit package metadata (<https://flit.pypa.io/en/stable/pyproject_toml.html>) from
+/// Poetry `packages` (<https://python-poetry.org/docs/pyproject/#packages>) and `include`/`exclude`
+/// (<https://python-poetry.org/docs/pyproject/#exclude-and-include>).
+///
+/// Flit builds a single module, located either at the root of the project or in a `src`
+/// directory, so Poetry `packages` can only be converted to `module` if they match that layout.
+///
+/// Poetry `include` and `exclude` are converted to Flit `sdist` `include` and `exclude`. Since
+/// wheels are built from the source distribution, excluded files are also removed from wheels.
+pub fn get_build_backend(
+    project_path: &Path,
+    packages: Option<&Vec<Package>>,
+    include: Option<&Vec<Include>>,
+    exclude: Option<&Vec<String>>,
+) -> (Option<Flit>, Vec<String>) {
+    let mut errors = Vec::new();
+
+    let mut module_name: Option<String> = None;
+    let mut sdist_include: Vec<String> = Vec::new();
+
+    // https://python-poetry.org/docs/pyproject/#packages
+    if let Some(packages) = packages {
+        if packages.len() > 1 {
+            errors.push(format!(
+                "\"{}\" cannot be converted to Flit, as it contains more than one package, which cannot be expressed with Flit.",
+                "poetry.packages".bold(),
+            ));
+        }
+
+        if let Some(Package {
+            include,
+            format,
+            from,
+            to,
+        }) = packages.first()
+        {
+            let root = get_module_root(from.as_ref());
+
+            let error_reason = if to.is_some() {
+                Some(format!("it uses \"{}\"", "to".bold()))
+            } else if include.contains('*') {
+                Some("it uses globs".to_string())
+            } else if !root.is_empty() && root != "src" {
+                Some(format!(
+                    "it uses \"{}\" with a directory other than \"{}\"",
+                    "from".bold(),
+                    "src".bold(),
+                ))
+            } else {
+                match get_packages_distribution_format(format.as_ref()) {
+                    (true, true) => None,
+                    (true, false) => {
+                        Some("it is configured to be added to source distribution only".to_string())
+                    }
+                    _ => Some("it is configured to be added to wheels only".to_string()),
+                }
+            };
+
+            if let Some(error_reason) = error_reason {
+                errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to Flit, as {error_reason}, which cannot be expressed with Flit.",
+                    include.bold(),
+                    "poetry.packages.include".bold(),
+                ));
+            } else {
+                let include_with_root = if root.is_empty() {
+                    include.clone()
+                } else {
+                    format!("{root}/{include}")
+                };
+                let include = if project_path.join(include_with_root).is_file() {
+                    include.trim_end_matches(".py")
+                } else {
+                    include
+                };
+
+                module_name = Some(include.replace('/', "."));
+            }
+        }
+    }
+
+    // https://python-poetry.org/docs/pyproject/#exclude-and-include
+    if let Some(include) = include {
+        for inc in include {
+            let (path, format) = match inc {
+                Include::Map { path, format } => (path, format.as_ref()),
+                Include::String(path) => (path, None),
+            };
+            let (add_to_sdist, add_to_wheel) = get_include_distribution_format(format);
+
+            if add_to_wheel {
+                errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to Flit, as it is configured to be added to wheels, which cannot be expressed with Flit.",
+                    path.bold(),
+                    "poetry.include".bold(),
+                ));
+            } else if add_to_sdist {
+                sdist_include.push(path.clone());
+            }
+        }
+    }
+
+    let sdist = Sdist {
+        include: non_empty_vec(sdist_include),
+        exclude: exclude.cloned(),
+    };
+
+    let flit = Flit {
+        module: module_name.map(|name| Module { name: Some(name) }),
+        sdist: if sdist == Sdist::default() {
+            None
+        } else {
+            Some(sdist)
+        },
+    };
+
+    if flit == Flit::default() {
+        (None, errors)
+    } else {
+        (Some(flit), errors)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::converters::poetry::build_backend::{
     get_include_distribution_format, get_packages_distribution_format,
 };
-use crate::schema::hatch::{Build, BuildTarget, Hatch};
+use crate::schema::hatch::{Build, BuildHook, BuildTarget, Hatch, Version};
 use crate::schema::poetry::{Include, Package};
 use crate::utils::non_empty_index_map;
 use crate::utils::non_empty_vec;
@@ -9,6 +9,10 @@ use indexmap::IndexMap;
 use owo_colors::OwoColorize;
 use std::path::{MAIN_SEPARATOR, Path, PathBuf};
 
+/// Path of the build hook that runs Poetry build script, which is the default path of custom build
+/// hooks in Hatch (<https://hatch.pypa.io/latest/plugins/build-hook/custom/>).
+pub const BUILD_HOOK_PATH: &str = "hatch_build.py";
+
 #[derive(Default)]
 struct HatchTargetsInclude {
     sdist_include: Option<Vec<String>>,
@@ -48,29 +52,83 @@ impl HatchTargetsInclude {
 /// apply path rewriting in `wheel` target.
 ///
 /// Poetry `exclude` is converted as is to hatch `exclude`.
+///
+/// Poetry build script (<https://python-poetry.org/docs/building-extension-modules/>) is run from
+/// a custom build hook in `wheel` target, generated in [`BUILD_HOOK_PATH`].
+///
+/// Poetry file scripts (<https://python-poetry.org/docs/pyproject/#scripts>) are converted to
+/// `shared-scripts` in `wheel` target, and added to sdist `include` if it is set, as wheels are
+/// built from the source distribution.
+///
+/// `version` is set when the version is computed dynamically at build time.
 pub fn get_build_backend(
     project_path: &Path,
     packages: Option<&Vec<Package>>,
     include: Option<&Vec<Include>>,
     exclude: Option<&Vec<String>>,
+    build_script: Option<&String>,
+    file_scripts: &[String],
+    version: Option<Version>,
 ) -> (Option<Hatch>, Vec<String>) {
     let mut errors = Vec::new();
 
+    if let Some(build_script) = build_script
+        && project_path.join(BUILD_HOOK_PATH).exists()
+    {
+        errors.push(format!(
+            "Build script \"{}\" from \"{}\" cannot be converted to Hatch, as \"{}\" already exists.",
+            build_script.bold(),
+            "poetry.build".bold(),
+            BUILD_HOOK_PATH.bold(),
+        ));
+    }
+
     let mut targets = IndexMap::new();
     let (hatch_targets_include, include_errors) = get_include(project_path, packages, include);
     errors.extend(include_errors);
 
+    let mut sdist_include = hatch_targets_include.sdist_include;
+    if let Some(sdist_include) = sdist_include.as_mut() {
+        sdist_include.extend(file_scripts.iter().cloned());
+    }
+
     let sdist_target = BuildTarget {
-        include: hatch_targets_include.sdist_include,
+        include: sdist_include,
         force_include: hatch_targets_include.sdist_force_include,
         exclude: exclude.cloned(),
         sources: None,
+        shared_scripts: None,
+        hooks: None,
     };
     let wheel_target = BuildTarget {
         include: hatch_targets_include.wheel_include,
         force_include: hatch_targets_include.wheel_force_include,
         exclude: exclude.cloned(),
         sources: hatch_targets_include.wheel_sources,
+        shared_scripts: if file_scripts.is_empty() {
+            None
+        } else {
+            Some(
+                file_scripts
+                    .iter()
+                    .map(|path| {
+                        let name = Path::new(path).file_name().map_or_else(
+                            || path.clone(),
+                            |name| name.to_string_lossy().to_string(),
+                        );
+                        (path.clone(), name)
+                    })
+                    .collect(),
+            )
+        },
+        hooks: build_script.map(|_| {
+            IndexMap::from([(
+                "custom".to_string(),
+                BuildHook {
+                    path: Some(BUILD_HOOK_PATH.to_string()),
+                },
+            )])
+        }),
     };
 
     if sdist_target != BuildTarget::default() {
@@ -80,19 +138,96 @@ pub fn get_build_backend(
         targets.insert("wheel".to_string(), wheel_target);
     }
 
-    let hatch = if targets.is_empty() {
+    let hatch = if targets.is_empty() && version.is_none() {
         None
     } else {
         Some(Hatch {
-            build: Some(Build {
-                targets: Some(targets),
-            }),
+            version,
+            build: if targets.is_empty() {
+                None
+            } else {
+                Some(Build {
+                    targets: Some(targets),
+                })
+            },
+            envs: None,
         })
     };
 
     (hatch, errors)
 }
 
+/// Get the content of the custom build hook that runs Poetry build script the same way Poetry does.
+///
+/// If Poetry generates a setup file (<https://python-poetry.org/docs/building-extension-modules/>),
+/// the hook calls `build(setup_kwargs)` from the script, and builds extension modules with
+/// setuptools using the updated arguments. Otherwise, the hook executes the script with the Python
+/// interpreter used for the build.
+pub fn get_build_hook(build_script: &str, generates_setup_file: bool) -> String {
+    let (imports, run_build_script) = if generates_setup_file {
+        (
+            r"import importlib.util
+import os
+",
+            format!(
+                r#"        # Poetry calls `build` function of the build script to update setuptools arguments, and
+        # builds extension modules with setuptools.
+        spec = importlib.util.spec_from_file_location(
+            "build_script", os.path.join(self.root, "{build_script}")
+        )
+        build_script = importlib.util.module_from_spec(spec)
+        spec.loader.exec_module(build_script)
+
+        setup_kwargs = {{
+            "name": self.metadata.name,
+            "version": self.metadata.version,
+            "packages": [],
+            "py_modules": [],
+        }}
+        build_script.build(setup_kwargs)
+
+        from setuptools import setup
+
+        cwd = os.getcwd()
+        os.chdir(self.root)
+        try:
+            setup(**setup_kwargs, script_args=["build_ext", "--inplace"])
+        finally:
+            os.chdir(cwd)
+"#
+            ),
+        )
+    } else {
+        (
+            r"import subprocess
+import sys
+",
+            format!(
+                r#"        subprocess.check_call([sys.executable, "{build_script}"], cwd=self.root)
+"#
+            ),
+        )
+    };
+
+    format!(
+        r#"{imports}
+from hatchling.builders.hooks.plugin.interface import BuildHookInterface
+
+
+class CustomBuildHook(BuildHookInterface):
+    """Run the build script previously used by Poetry."""
+
+    def initialize(self, version, build_data):
+{run_build_script}
+        # Build scripts compile extension modules, so wheels are specific to the platform, and
+        # compiled files must be included even if they are ignored by VCS.
+        build_data["pure_python"] = False
+        build_data["infer_tag"] = True
+        build_data["artifacts"].extend(["*.so", "*.pyd"])
+"#
+    )
+}
+
 /// Inclusion behavior: <https://hatch.pypa.io/latest/config/build/#patterns>
 /// Path rewriting behavior: <https://hatch.pypa.io/latest/config/build/#rewriting-paths>
 fn get_include(
@@ -1,18 +1,28 @@
+use crate::converters::poetry::dynamic_versioning;
 use crate::converters::{BuildBackend, ConverterOptions};
 use crate::errors::{add_recoverable_error, add_unrecoverable_error};
+use crate::schema::flit::Flit;
 use crate::schema::hatch::Hatch;
-use crate::schema::poetry::{Format, Poetry};
+use crate::schema::pdm;
+use crate::schema::poetry::{Format, Poetry, PoetryDynamicVersioning};
 use crate::schema::pyproject::BuildSystem;
+use crate::schema::setuptools::Setuptools;
 use crate::schema::utils::SingleOrVec;
 use crate::schema::uv::UvBuildBackend;
 use crate::uv::get_version;
 use owo_colors::OwoColorize;
 use pep440_rs::Version;
+use pep508_rs::Requirement;
 use std::fmt::Display;
 use std::str::FromStr;
 use std::string::ToString;
+use url::Url;
 
+pub mod distribution;
+pub mod flit;
 pub mod hatch;
+pub mod pdm_backend;
+pub mod setuptools;
 pub mod uv;
 
 /// Minimum version to use for `uv_build`. This corresponds to the version that stabilized the build
@@ -20,10 +30,29 @@ pub mod uv;
 const MIN_UV_BUILD_VERSION: &str = "0.7.19";
 /// Default bounds to use for `uv_build` when no version is found.
 const UV_BUILD_DEFAULT_BOUNDS: &str = ">=0.10.0,<0.11.0";
+/// Build backends that build packages from Poetry metadata. `poetry-dynamic-versioning` provides
+/// its own backend, that wraps Poetry one to set the version dynamically.
+const POETRY_BUILD_BACKENDS: [&str; 2] = [
+    "poetry.core.masonry.api",
+    "poetry_dynamic_versioning.backend",
+];
+/// Minimum version of setuptools that supports reading project metadata from `pyproject.toml`,
+/// including PEP 639 license expressions and license files
+/// (<https://setuptools.pypa.io/en/latest/userguide/pyproject_config.html>).
+const SETUPTOOLS_BUILD_REQUIREMENT: &str = "setuptools>=77";
+/// Version range of `flit_core` that supports `[tool.flit.module]` and `[tool.flit.sdist]`
+/// alongside project metadata, including PEP 639 license expressions and license files
+/// (<https://flit.pypa.io/en/stable/pyproject_toml.html>).
+const FLIT_CORE_BUILD_REQUIREMENT: &str = "flit_core>=3.12,<4";
+/// Packages required at build time by Poetry build backends.
+const POETRY_BUILD_REQUIREMENTS: [&str; 3] = ["poetry", "poetry-core", "poetry-dynamic-versioning"];
 
 pub enum BuildBackendObject {
     Uv(UvBuildBackend),
     Hatch(Hatch),
+    Setuptools(Setuptools),
+    Flit(Flit),
+    PdmBackend(pdm::Build),
 }
 
 impl Display for BuildBackendObject {
@@ -31,6 +60,9 @@ impl Display for BuildBackendObject {
         match self {
             Self::Uv(_) => write!(f, "uv"),
             Self::Hatch(_) => write!(f, "Hatch"),
+            Self::Setuptools(_) => write!(f, "setuptools"),
+            Self::Flit(_) => write!(f, "Flit"),
+            Self::PdmBackend(_) => write!(f, "pdm-backend"),
         }
     }
 }
@@ -56,71 +88,166 @@ fn get_uv_build() -> String {
     format!("uv_build{UV_BUILD_DEFAULT_BOUNDS}")
 }
 
+/// Whether the build system builds packages from Poetry metadata.
+pub fn is_poetry_build_system(build_system: &BuildSystem) -> bool {
+    build_system
+        .build_backend
+        .as_ref()
+        .is_some_and(|build_backend| POETRY_BUILD_BACKENDS.contains(&build_backend.as_str()))
+}
+
+fn get_hatch_build_system(
+    current_requires: &[String],
+    dynamic_version: bool,
+    build_script: bool,
+) -> BuildSystem {
+    let mut requires = vec!["hatchling".to_string()];
+
+    // https://github.com/ofek/hatch-vcs
+    if dynamic_version {
+        requires.push("hatch-vcs".to_string());
+    }
+
+    // Build scripts can rely on packages that are required at build time (e.g. Cython), so we
+    // keep them, except the ones that are specific to Poetry.
+    if build_script {
+        requires.extend(
+            current_requires
+                .iter()
+                .filter(|requirement| {
+                    Requirement::<Url>::from_str(requirement).is_ok_and(|requirement| {
+                        !POETRY_BUILD_REQUIREMENTS.contains(&requirement.name.as_ref())
+                    })
+                })
+                .cloned(),
+        );
+    }
+
+    BuildSystem {
+        requires,
+        build_backend: Some("hatchling.build".to_string()),
+    }
+}
+
 pub fn get_new_build_system(
     current_build_system: Option<BuildSystem>,
     keep_current_build_backend: bool,
     new_build_system: Option<&BuildBackendObject>,
     build_backend: Option<BuildBackend>,
+    dynamic_version: bool,
+    build_script: bool,
 ) -> Option<BuildSystem> {
     if keep_current_build_backend {
         return None;
     }
 
-    if current_build_system?.build_backend? == "poetry.core.masonry.api" {
-        return match new_build_system {
-            Some(BuildBackendObject::Hatch(_)) => Some(BuildSystem {
-                requires: vec!["hatchling".to_string()],
-                build_backend: Some("hatchling.build".to_string()),
-            }),
-            None if build_backend == Some(BuildBackend::Hatch) => Some(BuildSystem {
-                requires: vec!["hatchling".to_string()],
-                build_backend: Some("hatchling.build".to_string()),
-            }),
-            _ => Some(BuildSystem {
+    let current_build_system = current_build_system?;
+
+    if is_poetry_build_system(&current_build_system) {
+        return Some(match (new_build_system, build_backend) {
+            (Some(BuildBackendObject::Hatch(_)), _) | (None, Some(BuildBackend::Hatch)) => {
+                get_hatch_build_system(
+                    &current_build_system.requires,
+                    dynamic_version,
+                    build_script,
+                )
+            }
+            (Some(BuildBackendObject::Setuptools(_)), _)
+            | (None, Some(BuildBackend::Setuptools)) => BuildSystem {
+                requires: vec![SETUPTOOLS_BUILD_REQUIREMENT.to_string()],
+                build_backend: Some("setuptools.build_meta".to_string()),
+            },
+            (Some(BuildBackendObject::Flit(_)), _) | (None, Some(BuildBackend::Flit)) => {
+                BuildSystem {
+                    requires: vec![FLIT_CORE_BUILD_REQUIREMENT.to_string()],
+                    build_backend: Some("flit_core.buildapi".to_string()),
+                }
+            }
+            (Some(BuildBackendObject::PdmBackend(_)), _)
+            | (None, Some(BuildBackend::PdmBackend)) => BuildSystem {
+                requires: vec!["pdm-backend".to_string()],
+                build_backend: Some("pdm.backend".to_string()),
+            },
+            _ => BuildSystem {
                 requires: vec![get_uv_build()],
                 build_backend: Some("uv_build".to_string()),
-            }),
-        };
+            },
+        });
     }
 
     None
 }
 
-/// Get build backend based on converter options. If `--build-backend` is not set or set to `hatch`,
-/// Hatch is selected. If `--build-backend` is set to `uv`, Uv is selected.
+/// Get build backend based on converter options. If `--build-backend` is not set, uv is selected if
+/// package distribution can be expressed with it, and Hatch otherwise. If `--build-backend` is
+/// set, the corresponding build backend is selected.
+///
+/// If the version is set dynamically with `poetry-dynamic-versioning`, or if a build script is
+/// used, Hatch is the only build backend that can be selected, as other build backends support
+/// neither dynamic versions nor build scripts.
 pub fn get_build_backend(
     converter_options: &ConverterOptions,
     build_system: Option<&BuildSystem>,
     poetry: &Poetry,
+    dynamic_versioning: Option<&PoetryDynamicVersioning>,
 ) -> Option<BuildBackendObject> {
     if converter_options.keep_current_build_backend {
         return None;
     }
 
+    let file_scripts = poetry.get_file_scripts();
+
+    let get_uv_build_backend = || {
+        uv::get_build_backend(
+            poetry.name.as_ref(),
+            &converter_options.project_path,
+            poetry.packages.as_ref(),
+            poetry.include.as_ref(),
+            poetry.exclude.as_ref(),
+            &file_scripts,
+            build_system,
+        )
+    };
+    let get_hatch_build_backend = || {
+        hatch::get_build_backend(
+            &converter_options.project_path,
+            poetry.packages.as_ref(),
+            poetry.include.as_ref(),
+            poetry.exclude.as_ref(),
+            poetry.get_build_script(),
+            &file_scripts,
+            dynamic_versioning.map(dynamic_versioning::get_hatch_version),
+        )
+    };
+
+    let mut unsupported_features = Vec::new();
+    if dynamic_versioning.is_some() {
+        unsupported_features.push(format!(
+            "version is set dynamically with \"{}\"",
+            "poetry-dynamic-versioning".bold(),
+        ));
+    }
+    if let Some(build_script) = poetry.get_build_script() {
+        unsupported_features.push(format!("build script \"{}\" is used", build_script.bold()));
+    }
+
     match &converter_options.build_backend {
         None => {
-            let (uv, errors) = uv::get_build_backend(
-                poetry.name.as_ref(),
-                &converter_options.project_path,
-                poetry.packages.as_ref(),
-                poetry.include.as_ref(),
-                poetry.exclude.as_ref(),
-                build_system,
-            );
+            let (uv, errors) = get_uv_build_backend();
 
-            if errors.is_empty() {
+            if errors.is_empty() && unsupported_features.is_empty() {
                 uv.map(BuildBackendObject::Uv)
             } else {
-                add_recoverable_error(
+                add_recoverable_error(if errors.is_empty() {
+                    format!(
+                        "Migrating build backend to Hatch, as {}, which cannot be expressed with uv.",
+                        unsupported_features.join(" and "),
+                    )
+                } else {
                     "Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.".to_string()
-                );
+                });
 
-                let (hatch, errors) = hatch::get_build_backend(
-                    &converter_options.project_path,
-                    poetry.packages.as_ref(),
-                    poetry.include.as_ref(),
-                    poetry.exclude.as_ref(),
-                );
+                let (hatch, errors) = get_hatch_build_backend();
 
                 if errors.is_empty() {
                     hatch.map(BuildBackendObject::Hatch)
@@ -142,63 +269,118 @@ pub fn get_build_backend(
                 }
             }
         }
-        Some(BuildBackend::Uv) => {
-            let (uv, errors) = uv::get_build_backend(
-                poetry.name.as_ref(),
-                &converter_options.project_path,
-                poetry.packages.as_ref(),
-                poetry.include.as_ref(),
-                poetry.exclude.as_ref(),
-                build_system,
-            );
+        Some(BuildBackend::Uv) => select_build_backend(
+            get_uv_build_backend(),
+            BuildBackendObject::Uv,
+            "uv",
+            &unsupported_features,
+            converter_options.ignore_errors,
+        ),
+        Some(BuildBackend::Hatch) => {
+            let (hatch, errors) = get_hatch_build_backend();
 
             if errors.is_empty() {
-                uv.map(BuildBackendObject::Uv)
+                hatch.map(BuildBackendObject::Hatch)
             } else {
                 for error in errors {
                     add_unrecoverable_error(error.clone());
                 }
 
                 if converter_options.ignore_errors {
-                    uv.map(BuildBackendObject::Uv)
+                    hatch.map(BuildBackendObject::Hatch)
                 } else {
                     add_unrecoverable_error(format!(
-                        "Package distribution could not be migrated to uv build backend due to the issues above. Consider using Hatch build backend with \"{}\".",
-                        "--build-backend hatch".bold(),
+                        "Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with \"{}\".",
+                        "--keep-current-build-backend".bold(),
                     ));
 
                     None
                 }
             }
         }
-        Some(BuildBackend::Hatch) => {
-            let (hatch, errors) = hatch::get_build_backend(
+        Some(BuildBackend::Setuptools) => select_build_backend(
+            setuptools::get_build_backend(
                 &converter_options.project_path,
                 poetry.packages.as_ref(),
                 poetry.include.as_ref(),
                 poetry.exclude.as_ref(),
-            );
+            ),
+            BuildBackendObject::Setuptools,
+            "setuptools",
+            &unsupported_features,
+            converter_options.ignore_errors,
+        ),
+        Some(BuildBackend::Flit) => select_build_backend(
+            flit::get_build_backend(
+                &converter_options.project_path,
+                poetry.packages.as_ref(),
+                poetry.include.as_ref(),
+                poetry.exclude.as_ref(),
+            ),
+            BuildBackendObject::Flit,
+            "Flit",
+            &unsupported_features,
+            converter_options.ignore_errors,
+        ),
+        Some(BuildBackend::PdmBackend) => select_build_backend(
+            pdm_backend::get_build_backend(
+                poetry.packages.as_ref(),
+                poetry.include.as_ref(),
+                poetry.exclude.as_ref(),
+            ),
+            BuildBackendObject::PdmBackend,
+            "pdm-backend",
+            &unsupported_features,
+            converter_options.ignore_errors,
+        ),
+    }
+}
 
-            if errors.is_empty() {
-                hatch.map(BuildBackendObject::Hatch)
-            } else {
-                for error in errors {
-                    add_unrecoverable_error(error.clone());
-                }
+/// Select a build backend explicitly chosen with `--build-backend`, that supports neither dynamic
+/// versions nor build scripts, reporting errors that prevent package distribution from being
+/// migrated to it.
+fn select_build_backend<T>(
+    (build_backend, mut errors): (Option<T>, Vec<String>),
+    to_build_backend_object: fn(T) -> BuildBackendObject,
+    name: &str,
+    unsupported_features: &[String],
+    ignore_errors: bool,
+) -> Option<BuildBackendObject> {
+    errors.extend(unsupported_features.iter().map(|feature| {
+        format!(
+            "Build backend cannot be migrated to {name}, as {feature}, which cannot be expressed with {name}."
+        )
+    }));
 
-                if converter_options.ignore_errors {
-                    hatch.map(BuildBackendObject::Hatch)
-                } else {
-                    add_unrecoverable_error(format!(
-                        "Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with \"{}\".",
-                        "--keep-current-build-backend".bold(),
-                    ));
+    if errors.is_empty() {
+        return build_backend.map(to_build_backend_object);
+    }
 
-                    None
-                }
-            }
-        }
+    for error in errors {
+        add_unrecoverable_error(error);
     }
+
+    if ignore_errors {
+        build_backend.map(to_build_backend_object)
+    } else {
+        add_unrecoverable_error(format!(
+            "Package distribution could not be migrated to {name} build backend due to the issues above. Consider using Hatch build backend with \"{}\".",
+            "--build-backend hatch".bold(),
+        ));
+
+        None
+    }
+}
+
+/// Get the directory containing a package, relative to the project root, without trailing slash
+/// (e.g., "src" for `from = "src/"`), which is an empty string for the project root.
+fn get_module_root(from: Option<&String>) -> String {
+    from.map_or_else(String::new, |from| {
+        match from.trim_start_matches("./").trim_end_matches('/') {
+            "." => String::new(),
+            root => root.to_string(),
+        }
+    })
 }
 
 /// Get the distributions to include an item from `packages` to.
@@ -0,0 +1,133 @@
+use crate::converters::poetry::build_backend::{
+    get_include_distribution_format, get_module_root, get_packages_distribution_format,
+};
+use crate::schema::pdm::Build;
+use crate::schema::poetry::{Include, Package};
+use crate::utils::non_empty_vec;
+use owo_colors::OwoColorize;
+
+/// Construct pdm-backend package metadata (<https://backend.pdm-project.org/build_config/>) from
+/// Poetry `packages` (<https://python-poetry.org/docs/pyproject/#packages>) and `include`/`exclude`
+/// (<https://python-poetry.org/docs/pyproject/#exclude-and-include>).
+///
+/// Poetry `packages` and `include` are converted to pdm-backend `includes` if they are added to
+/// both distributions, and to `source-includes` if they are only added to the source distribution.
+/// The directory set with `from` is converted to `package-dir`, which only supports a single
+/// directory.
+///
+/// Poetry `exclude` is converted as is to pdm-backend `excludes`.
+pub fn get_build_backend(
+    packages: Option<&Vec<Package>>,
+    include: Option<&Vec<Include>>,
+    exclude: Option<&Vec<String>>,
+) -> (Option<Build>, Vec<String>) {
+    let mut errors = Vec::new();
+
+    let mut includes: Vec<String> = Vec::new();
+    let mut source_includes: Vec<String> = Vec::new();
+    let mut package_dir: Option<String> = None;
+
+    // https://python-poetry.org/docs/pyproject/#packages
+    if let Some(packages) = packages {
+        let mut roots: Vec<String> = packages
+            .iter()
+            .map(|package| get_module_root(package.from.as_ref()))
+            .collect();
+        roots.dedup();
+
+        if roots.len() > 1 {
+            errors.push(format!(
+                "\"{}\" cannot be converted to pdm-backend, as packages are located in different directories with \"{}\", which cannot be expressed with pdm-backend.",
+                "poetry.packages".bold(),
+                "from".bold(),
+            ));
+        }
+
+        // `package-dir` defaults to "src" if the directory exists, so it is explicitly set to the
+        // project root if packages are located there.
+        package_dir = roots.first().map(|root| {
+            if root.is_empty() {
+                ".".to_string()
+            } else {
+                root.clone()
+            }
+        });
+
+        for Package {
+            include,
+            format,
+            from,
+            to,
+        } in packages
+        {
+            let root = get_module_root(from.as_ref());
+
+            // Packages located in a different directory than the package directory were already
+            // reported above.
+            if roots.first() != Some(&root) {
+                continue;
+            }
+
+            if to.is_some() {
+                errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to pdm-backend, as it uses \"{}\", which cannot be expressed with pdm-backend.",
+                    include.bold(),
+                    "poetry.packages.include".bold(),
+                    "to".bold(),
+                ));
+                continue;
+            }
+
+            let include_with_root = if root.is_empty() {
+                include.clone()
+            } else {
+                format!("{root}/{include}")
+            };
+
+            match get_packages_distribution_format(format.as_ref()) {
+                (true, true) => includes.push(include_with_root),
+                (true, false) => source_includes.push(include_with_root),
+                (false, true) => errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to pdm-backend, as it is configured to be added to wheels only, which cannot be expressed with pdm-backend.",
+                    include.bold(),
+                    "poetry.packages.include".bold(),
+                )),
+                (false, false) => (),
+            }
+        }
+    }
+
+    // https://python-poetry.org/docs/pyproject/#exclude-and-include
+    if let Some(include) = include {
+        for inc in include {
+            let (path, format) = match inc {
+                Include::Map { path, format } => (path, format.as_ref()),
+                Include::String(path) => (path, None),
+            };
+
+            match get_include_distribution_format(format) {
+                (true, true) => includes.push(path.clone()),
+                (true, false) => source_includes.push(path.clone()),
+                (false, true) => errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to pdm-backend, as it is configured to be added to wheels only, which cannot be expressed with pdm-backend.",
+                    path.bold(),
+                    "poetry.include".bold(),
+                )),
+                (false, false) => (),
+            }
+        }
+    }
+
+    let build = Build {
+        includes: non_empty_vec(includes),
+        excludes: exclude.cloned(),
+        source_includes: non_empty_vec(source_includes),
+        package_dir,
+    };
+
+    if build == Build::default() {
+        (None, errors)
+    } else {
+        (Some(build), errors)
+    }
+}
@@ -0,0 +1,239 @@
+use crate::converters::poetry::build_backend::{
+    get_include_distribution_format, get_module_root, get_packages_distribution_format,
+};
+use crate::schema::poetry::{Include, Package};
+use crate::schema::setuptools::{Find, Packages, Setuptools};
+use crate::utils::non_empty_vec;
+use owo_colors::OwoColorize;
+use std::path::Path;
+
+/// Path of the manifest that lists the files to add to or remove from the source distribution
+/// (<https://setuptools.pypa.io/en/latest/userguide/miscellaneous.html>).
+pub const MANIFEST_PATH: &str = "MANIFEST.in";
+
+/// Construct setuptools package metadata
+/// (<https://setuptools.pypa.io/en/latest/userguide/pyproject_config.html>) from Poetry `packages`
+/// (<https://python-poetry.org/docs/pyproject/#packages>) and `include`/`exclude`
+/// (<https://python-poetry.org/docs/pyproject/#exclude-and-include>).
+///
+/// Poetry `packages` are converted to setuptools package discovery, using the directories set with
+/// `from` as places to look for packages, and modules are converted to `py-modules`.
+///
+/// setuptools only allows to add files to the source distribution from a manifest, so package
+/// data, and Poetry `include` and `exclude` are converted to [`MANIFEST_PATH`], generated from
+/// [`get_manifest`].
+pub fn get_build_backend(
+    project_path: &Path,
+    packages: Option<&Vec<Package>>,
+    include: Option<&Vec<Include>>,
+    exclude: Option<&Vec<String>>,
+) -> (Option<Setuptools>, Vec<String>) {
+    let mut errors = Vec::new();
+
+    let mut py_modules: Vec<String> = Vec::new();
+    let mut find_where: Vec<String> = Vec::new();
+    let mut find_include: Vec<String> = Vec::new();
+
+    // https://python-poetry.org/docs/pyproject/#packages
+    if let Some(packages) = packages {
+        for Package {
+            include,
+            format,
+            from,
+            to,
+        } in packages
+        {
+            let root = get_module_root(from.as_ref());
+            let include_with_root = if root.is_empty() {
+                include.clone()
+            } else {
+                format!("{root}/{include}")
+            };
+
+            let error_reason = if to.is_some() {
+                Some(format!("it uses \"{}\"", "to".bold()))
+            } else if include.contains('*') {
+                Some("it uses globs".to_string())
+            } else if !root.is_empty() && project_path.join(&include_with_root).is_file() {
+                Some(format!("it uses \"{}\" on a file", "from".bold()))
+            } else {
+                match get_packages_distribution_format(format.as_ref()) {
+                    (true, true) => None,
+                    (true, false) => {
+                        Some("it is configured to be added to source distribution only".to_string())
+                    }
+                    _ => Some("it is configured to be added to wheels only".to_string()),
+                }
+            };
+
+            if let Some(error_reason) = error_reason {
+                errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to setuptools, as {error_reason}, which cannot be expressed with setuptools.",
+                    include.bold(),
+                    "poetry.packages.include".bold(),
+                ));
+                continue;
+            }
+
+            if project_path.join(&include_with_root).is_file() {
+                py_modules.push(include.trim_end_matches(".py").replace('/', "."));
+            } else {
+                let name = include.replace('/', ".");
+                let root = if root.is_empty() {
+                    ".".to_string()
+                } else {
+                    root
+                };
+
+                if !find_where.contains(&root) {
+                    find_where.push(root);
+                }
+                find_include.push(name.clone());
+                find_include.push(format!("{name}.*"));
+            }
+        }
+    }
+
+    // https://python-poetry.org/docs/pyproject/#exclude-and-include
+    if let Some(include) = include {
+        for inc in include {
+            let (path, format) = match inc {
+                Include::Map { path, format } => (path, format.as_ref()),
+                Include::String(path) => (path, None),
+            };
+
+            if get_include_distribution_format(format).1 {
+                errors.push(format!(
+                    "\"{}\" from \"{}\" cannot be converted to setuptools, as it is configured to be added to wheels, which cannot be expressed with setuptools.",
+                    path.bold(),
+                    "poetry.include".bold(),
+                ));
+            }
+        }
+    }
+
+    let setuptools = Setuptools {
+        py_modules: non_empty_vec(py_modules),
+        packages: if find_include.is_empty() {
+            None
+        } else {
+            Some(Packages::Find {
+                find: Find {
+                    where_: if find_where == ["."] {
+                        None
+                    } else {
+                        Some(find_where)
+                    },
+                    include: Some(find_include),
+                    ..Find::default()
+                },
+            })
+        },
+        ..Setuptools::default()
+    };
+
+    let has_manifest = get_manifest(project_path, &setuptools, include, exclude).is_some();
+
+    if has_manifest && project_path.join(MANIFEST_PATH).exists() {
+        errors.push(format!(
+            "Package distribution cannot be converted to setuptools, as \"{}\" already exists.",
+            MANIFEST_PATH.bold(),
+        ));
+    }
+
+    // Even without package metadata, setuptools needs to be selected to generate the manifest.
+    if setuptools == Setuptools::default() && !has_manifest {
+        (None, errors)
+    } else {
+        (Some(setuptools), errors)
+    }
+}
+
+/// Get the content of the manifest that adds packages and Poetry `include` to the source
+/// distribution, and removes Poetry `exclude` from it
+/// (<https://setuptools.pypa.io/en/latest/userguide/miscellaneous.html#using-manifest-in>).
+/// Since wheels are built from the source distribution, excluded files are also removed from
+/// wheels, as long as they are not modules.
+///
+/// Poetry adds all files inside packages to distributions, while setuptools only adds modules, so
+/// package directories are fully added to the source distribution, which adds their data files to
+/// wheels, as `include-package-data` is enabled by default
+/// (<https://setuptools.pypa.io/en/latest/userguide/datafiles.html#include-package-data>).
+pub fn get_manifest(
+    project_path: &Path,
+    setuptools: &Setuptools,
+    include: Option<&Vec<Include>>,
+    exclude: Option<&Vec<String>>,
+) -> Option<String> {
+    let mut commands: Vec<String> = get_package_directories(project_path, setuptools)
+        .into_iter()
+        .map(|directory| format!("graft {directory}"))
+        .collect();
+    let has_packages = !commands.is_empty();
+
+    if let Some(include) = include {
+        for inc in include {
+            let (path, format) = match inc {
+                Include::Map { path, format } => (path, format.as_ref()),
+                Include::String(path) => (path, None),
+            };
+
+            if get_include_distribution_format(format).0 {
+                if project_path.join(path).is_dir() {
+                    commands.push(format!("graft {path}"));
+                } else {
+                    commands.push(format!("include {path}"));
+                }
+            }
+        }
+    }
+
+    if let Some(exclude) = exclude {
+        for path in exclude {
+            if project_path.join(path).is_dir() {
+                commands.push(format!("prune {path}"));
+            } else {
+                commands.push(format!("exclude {path}"));
+            }
+        }
+    }
+
+    // Bytecode files are ignored by Poetry, as they are usually excluded from VCS.
+    if has_packages {
+        commands.push("global-exclude *.py[cod]".to_string());
+    }
+
+    if commands.is_empty() {
+        None
+    } else {
+        Some(format!("{}\n", commands.join("\n")))
+    }
+}
+
+/// Get the directories of the packages found by setuptools package discovery, looking for each
+/// package in the directories set with `where`.
+fn get_package_directories(project_path: &Path, setuptools: &Setuptools) -> Vec<String> {
+    let Some(Packages::Find { find }) = &setuptools.packages else {
+        return Vec::new();
+    };
+    let roots = find.where_.clone().unwrap_or_else(|| vec![".".to_string()]);
+
+    find.include
+        .iter()
+        .flatten()
+        .filter(|name| !name.ends_with(".*"))
+        .filter_map(|name| {
+            let path = name.replace('.', "/");
+
+            roots.iter().find_map(|root| {
+                let directory = if root == "." {
+                    path.clone()
+                } else {
+                    format!("{root}/{path}")
+                };
+
+                project_path.join(&directory).is_dir().then_some(directory)
+            })
+        })
+        .collect()
+}
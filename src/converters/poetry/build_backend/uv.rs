@@ -1,12 +1,14 @@
 use crate::converters::poetry::build_backend::{
-    get_include_distribution_format, get_packages_distribution_format,
+    get_include_distribution_format, get_module_root, get_packages_distribution_format,
+    is_poetry_build_system,
 };
 use crate::schema::poetry::{Include, Package};
 use crate::schema::pyproject::BuildSystem;
 use crate::schema::utils::SingleOrVec;
-use crate::schema::uv::UvBuildBackend;
+use crate::schema::uv::{UvBuildBackend, UvBuildBackendData};
 use crate::utils::non_empty_vec;
 use owo_colors::OwoColorize;
+use std::fs;
 use std::path::Path;
 
 pub fn get_build_backend(
@@ -15,6 +17,7 @@ pub fn get_build_backend(
     packages: Option<&Vec<Package>>,
     include: Option<&Vec<Include>>,
     exclude: Option<&Vec<String>>,
+    file_scripts: &[String],
     build_system: Option<&BuildSystem>,
 ) -> (Option<UvBuildBackend>, Vec<String>) {
     let mut errors = Vec::new();
@@ -28,10 +31,27 @@ pub fn get_build_backend(
 
     // https://python-poetry.org/docs/pyproject/#packages
     if let Some(packages) = packages {
+        // Packages can be located in a different directory than the project root with `from`
+        // (e.g., "src"), which corresponds to `module-root` in uv. Since uv only supports a single
+        // module root, all packages must share the same directory.
+        let mut roots: Vec<String> = packages
+            .iter()
+            .map(|package| get_module_root(package.from.as_ref()))
+            .collect();
+        roots.dedup();
+
         // By default, uv expects the modules to be in a "src" directory. Since Poetry does not
         // provide a similar option, we want to default to the same thing as Poetry, i.e. an empty
-        // string.
-        module_root = Some(String::new());
+        // string if `from` is not used.
+        module_root = Some(roots.first().cloned().unwrap_or_default());
+
+        if roots.len() > 1 {
+            errors.push(format!(
+                "\"{}\" cannot be converted to uv, as packages are located in different directories with \"{}\", which cannot be expressed with uv.",
+                "poetry.packages".bold(),
+                "from".bold(),
+            ));
+        }
 
         for Package {
             include,
@@ -40,19 +60,11 @@ pub fn get_build_backend(
             to,
         } in packages
         {
-            let mut has_error = false;
+            let root = get_module_root(from.as_ref());
 
-            if from.is_some() {
-                errors.push(
-                    format!(
-                        "\"{}\" from \"{}\" cannot be converted to uv, as it uses \"{}\", which cannot be expressed with uv.",
-                        include.bold(),
-                        "poetry.packages.include".bold(),
-                        "from".bold(),
-                    )
-                );
-                has_error = true;
-            }
+            // Packages located in a different directory than the module root were already
+            // reported above.
+            let mut has_error = module_root.as_ref() != Some(&root);
 
             if to.is_some() {
                 errors.push(
@@ -68,8 +80,14 @@ pub fn get_build_backend(
 
             let (add_to_sdist, add_to_wheel) = get_packages_distribution_format(format.as_ref());
 
+            let include_with_root = if root.is_empty() {
+                include.clone()
+            } else {
+                format!("{root}/{include}")
+            };
+
             let contains_glob = include.contains('*');
-            let is_file = project_path.join(include).is_file();
+            let is_file = project_path.join(&include_with_root).is_file();
 
             if contains_glob || is_file {
                 let reason = if contains_glob {
@@ -97,7 +115,7 @@ pub fn get_build_backend(
                         )
                     );
                 } else if add_to_sdist && !has_error {
-                    source_include.push(include.clone());
+                    source_include.push(include_with_root);
                 }
             } else {
                 let name = include.replace('/', ".");
@@ -135,7 +153,7 @@ pub fn get_build_backend(
         // directory that matches the project name.
         // Otherwise, we keep it to `None`, to use uv's default, which looks into `src` directory.
         if let Some(build_system) = build_system
-            && build_system.build_backend == Some("poetry.core.masonry.api".to_string())
+            && is_poetry_build_system(build_system)
             && project_name.is_some_and(|name| project_path.join(name).exists())
         {
             module_root = Some(String::new());
@@ -183,7 +201,17 @@ pub fn get_build_backend(
         wheel_exclude.extend(exclude.clone());
     }
 
+    // https://python-poetry.org/docs/pyproject/#scripts
+    let (data_scripts, scripts_error_reason) = get_data_scripts(project_path, file_scripts);
+    if let Some(reason) = scripts_error_reason {
+        errors.push(format!(
+            "File scripts from \"{}\" cannot be converted to uv, as {reason}, which cannot be expressed with uv.",
+            "poetry.scripts".bold(),
+        ));
+    }
+
     let uv_build_backend = if module_name.is_empty()
+        && data_scripts.is_none()
         && source_include.is_empty()
         && source_exclude.is_empty()
         && wheel_exclude.is_empty()
@@ -202,6 +230,10 @@ pub fn get_build_backend(
             source_exclude: non_empty_vec(source_exclude),
             wheel_exclude: non_empty_vec(wheel_exclude),
             namespace,
+            data: data_scripts.map(|scripts| UvBuildBackendData {
+                scripts: Some(scripts),
+                ..UvBuildBackendData::default()
+            }),
             ..UvBuildBackend::default()
         })
     };
@@ -209,6 +241,66 @@ pub fn get_build_backend(
     (uv_build_backend, errors)
 }
 
+/// uv installs all the files from a single directory as scripts
+/// (<https://docs.astral.sh/uv/concepts/build-backend/#include-and-exclude-configuration>), so
+/// Poetry file scripts can only be converted if they are the only files of a directory, other than
+/// the root of the project. Returns the directory, or the reason why it cannot be used.
+fn get_data_scripts(
+    project_path: &Path,
+    file_scripts: &[String],
+) -> (Option<String>, Option<String>) {
+    if file_scripts.is_empty() {
+        return (None, None);
+    }
+
+    let mut directories: Vec<String> = file_scripts
+        .iter()
+        .map(|path| {
+            Path::new(path)
+                .parent()
+                .map(|parent| parent.to_string_lossy().to_string())
+                .unwrap_or_default()
+        })
+        .collect();
+    directories.sort();
+    directories.dedup();
+
+    let [directory] = directories.as_slice() else {
+        return (
+            None,
+            Some("they are located in different directories".to_string()),
+        );
+    };
+
+    if directory.is_empty() {
+        return (
+            None,
+            Some("they are located at the root of the project".to_string()),
+        );
+    }
+
+    let has_other_files = fs::read_dir(project_path.join(directory))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .any(|entry| {
+            let path = format!("{directory}/{}", entry.file_name().to_string_lossy());
+            !file_scripts.contains(&path)
+        });
+
+    if has_other_files {
+        return (
+            None,
+            Some(format!(
+                "\"{}\" contains other files than file scripts",
+                directory.bold(),
+            )),
+        );
+    }
+
+    (Some(directory.clone()), None)
+}
+
 fn has_init_file(project_path: &Path, include: &String, from: Option<&String>) -> bool {
     let path = from.map_or_else(
         || project_path.join(include).join("__init__.py"),
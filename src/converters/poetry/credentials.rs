@@ -0,0 +1,125 @@
+use crate::converters::env_vars;
+use crate::errors::add_recoverable_error;
+use crate::schema::uv::Index;
+use owo_colors::OwoColorize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Table;
+
+/// Require authentication for indexes that Poetry has credentials for, either from
+/// `POETRY_HTTP_BASIC_<NAME>_USERNAME` and `POETRY_HTTP_BASIC_<NAME>_PASSWORD` environment
+/// variables, or from `http-basic` settings of `poetry.toml` and `auth.toml`
+/// (<https://python-poetry.org/docs/repositories/#configuring-credentials>).
+///
+/// uv does not read credentials from any of those, so for each of these indexes, the environment
+/// variables that uv reads credentials from are reported.
+pub fn set_authentication(project_path: &Path, indexes: Option<&mut Vec<Index>>) {
+    let Some(indexes) = indexes else {
+        return;
+    };
+
+    let http_basic_settings: Vec<Table> = [
+        Some(project_path.join("poetry.toml")),
+        get_config_dir().map(|config_dir| config_dir.join("auth.toml")),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|path| get_http_basic_settings(&path))
+    .collect();
+
+    for index in indexes {
+        if !has_env_vars_credentials(&index.name)
+            && !http_basic_settings.iter().any(|settings| {
+                settings
+                    .keys()
+                    .any(|name| name.eq_ignore_ascii_case(&index.name))
+            })
+        {
+            continue;
+        }
+
+        index.authenticate = Some("always".to_string());
+
+        let (username_env_var, password_env_var) =
+            env_vars::get_index_credentials_names(&index.name);
+
+        add_recoverable_error(format!(
+            "Credentials of index \"{}\" are configured in Poetry, but uv reads them from \"{}\" and \"{}\" environment variables, which should be set before running uv.",
+            index.name.bold(),
+            username_env_var.bold(),
+            password_env_var.bold(),
+        ));
+    }
+}
+
+/// Whether credentials are set for a source through `POETRY_HTTP_BASIC_<NAME>_USERNAME` or
+/// `POETRY_HTTP_BASIC_<NAME>_PASSWORD` environment variables.
+fn has_env_vars_credentials(source_name: &str) -> bool {
+    let prefix = format!(
+        "POETRY_HTTP_BASIC_{}",
+        source_name.to_uppercase().replace(['-', '.'], "_")
+    );
+
+    env::var_os(format!("{prefix}_USERNAME")).is_some()
+        || env::var_os(format!("{prefix}_PASSWORD")).is_some()
+}
+
+/// Get `http-basic` settings from a Poetry configuration file, indexed by source name.
+fn get_http_basic_settings(path: &Path) -> Option<Table> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut settings = content.parse::<Table>().ok()?;
+
+    match settings.remove("http-basic")? {
+        toml::Value::Table(http_basic) => Some(http_basic),
+        _ => None,
+    }
+}
+
+/// Get the directory holding Poetry configuration, which can be overridden with
+/// `POETRY_CONFIG_DIR` (<https://python-poetry.org/docs/configuration/>).
+fn get_config_dir() -> Option<PathBuf> {
+    if let Some(config_dir) = env::var_os("POETRY_CONFIG_DIR") {
+        return Some(PathBuf::from(config_dir));
+    }
+
+    let config_dir = if cfg!(windows) {
+        PathBuf::from(env::var_os("APPDATA")?)
+    } else if cfg!(target_os = "macos") {
+        PathBuf::from(env::var_os("HOME")?).join("Library/Application Support")
+    } else {
+        env::var_os("XDG_CONFIG_HOME").map_or_else(
+            || env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")),
+            |config_home| Some(PathBuf::from(config_home)),
+        )?
+    };
+
+    Some(config_dir.join("pypoetry"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_get_http_basic_settings() {
+        let tmp_dir = tempdir().unwrap();
+        let path = tmp_dir.path().join("poetry.toml");
+
+        let mut file = File::create(&path).unwrap();
+        file.write_all(b"[virtualenvs]\nin-project = true\n\n[http-basic.foo]\nusername = \"user\"\npassword = \"pass\"\n")
+            .unwrap();
+
+        assert_eq!(
+            get_http_basic_settings(&path).map(|settings| settings.keys().cloned().collect()),
+            Some(vec!["foo".to_string()])
+        );
+        assert_eq!(
+            get_http_basic_settings(&tmp_dir.path().join("auth.toml")),
+            None
+        );
+    }
+}
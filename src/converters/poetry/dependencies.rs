@@ -1,15 +1,18 @@
 use crate::converters::poetry::sources;
+use crate::converters::poetry::version::PoetryPep440;
 use crate::converters::{DependencyGroupsAndDefaultGroups, DependencyGroupsStrategy};
 use crate::errors::{add_recoverable_error, add_unrecoverable_error};
 use crate::schema;
-use crate::schema::poetry::DependencySpecification;
+use crate::schema::poetry::{DependencySpecification, Poetry};
 use crate::schema::pyproject::DependencyGroupSpecification;
 use crate::schema::utils::SingleOrVec;
 use crate::schema::uv::{SourceContainer, SourceIndex};
 use crate::utils::normalize_dependency_name;
 use indexmap::IndexMap;
 use owo_colors::OwoColorize;
+use pep440_rs::VersionSpecifiers;
 use std::collections::HashSet;
+use std::str::FromStr;
 
 pub fn get(
     poetry_dependencies: Option<&IndexMap<String, DependencySpecification>>,
@@ -97,8 +100,8 @@ pub fn get_optional(
     poetry_dependencies: &mut Option<IndexMap<String, DependencySpecification>>,
     extras: Option<IndexMap<String, Vec<String>>>,
 ) -> Option<IndexMap<String, Vec<String>>> {
-    let extras = extras?;
     let poetry_dependencies = poetry_dependencies.as_mut()?;
+    let extras = extras.unwrap_or_default();
     let normalized_poetry_dependencies = get_normalized_dependencies(poetry_dependencies.clone());
 
     let mut dependencies_to_remove: HashSet<&str> = HashSet::new();
@@ -138,17 +141,95 @@ pub fn get_optional(
         })
         .collect();
 
-    if optional_dependencies.is_empty() {
-        return None;
-    }
+    // Poetry never installs optional dependencies that are not referenced by any extra, so they
+    // cannot be migrated to either dependencies or optional dependencies.
+    let unreferenced_optional_dependencies: Vec<String> = poetry_dependencies
+        .iter()
+        .filter(|(name, specification)| {
+            specification.is_optional() && !dependencies_to_remove.contains(name.as_str())
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
 
     for dep in dependencies_to_remove {
         let _ = &mut poetry_dependencies.shift_remove(dep);
     }
 
+    for dep in unreferenced_optional_dependencies {
+        add_unrecoverable_error(format!(
+            "Dependency \"{}\" is marked as optional, but is not referenced by any extra in \"{}\". Either reference it in an extra, or remove it.",
+            dep.bold(),
+            "[tool.poetry.extras]".bold(),
+        ));
+        poetry_dependencies.shift_remove(&dep);
+    }
+
+    if optional_dependencies.is_empty() {
+        return None;
+    }
+
     Some(optional_dependencies)
 }
 
+/// Warn about dependencies that allow pre-releases without a version specifier that explicitly
+/// includes a pre-release (e.g. `>=1.0.0b1`), as uv only allows pre-releases of such dependencies
+/// with its default strategy. uv has no per-package setting, and allowing pre-releases for all
+/// dependencies could change the resolution of every other package, so it is left to the user.
+pub fn check_prereleases(poetry: &Poetry) {
+    let names: Vec<&String> = poetry
+        .dependencies
+        .iter()
+        .chain(poetry.dev_dependencies.iter())
+        .chain(
+            poetry
+                .group
+                .iter()
+                .flat_map(|groups| groups.values().map(|group| &group.dependencies)),
+        )
+        .flatten()
+        .filter(|(_, specification)| {
+            specification.allows_prereleases() && !has_prerelease_specifier(specification)
+        })
+        .map(|(name, _)| name)
+        .collect();
+
+    if names.is_empty() {
+        return;
+    }
+
+    add_recoverable_error(format!(
+        "Pre-releases are allowed for {}, but uv only allows pre-releases for dependencies whose version specifiers include a pre-release (e.g. \"{}\"). Consider using such a specifier, or setting \"{}\" under \"{}\" to allow pre-releases for all dependencies.",
+        names
+            .iter()
+            .map(|name| format!("\"{}\"", name.bold()))
+            .collect::<Vec<String>>()
+            .join(", "),
+        ">=1.0.0b1".bold(),
+        "prerelease = \"allow\"".bold(),
+        "[tool.uv]".bold(),
+    ));
+}
+
+/// Whether a version specifier of the dependency explicitly includes a pre-release.
+fn has_prerelease_specifier(specification: &DependencySpecification) -> bool {
+    match specification {
+        DependencySpecification::String(version)
+        | DependencySpecification::Map {
+            version: Some(version),
+            ..
+        } => PoetryPep440::from_str(version)
+            .ok()
+            .and_then(|version| VersionSpecifiers::from_str(&version.to_string()).ok())
+            .is_some_and(|specifiers| {
+                specifiers
+                    .iter()
+                    .any(|specifier| specifier.version().any_prerelease())
+            }),
+        DependencySpecification::Map { .. } => false,
+        DependencySpecification::Vec(specs) => specs.iter().any(has_prerelease_specifier),
+    }
+}
+
 /// Get a mapping of normalized dependency names to their original specifications.
 fn get_normalized_dependencies(
     dependencies: IndexMap<String, DependencySpecification>,
@@ -0,0 +1,114 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::hatch::Version;
+use crate::schema::poetry::PoetryDynamicVersioning;
+use owo_colors::OwoColorize;
+
+/// Construct Hatch version metadata using `hatch-vcs` (<https://github.com/ofek/hatch-vcs>) from
+/// `poetry-dynamic-versioning` configuration
+/// (<https://github.com/mtkennerly/poetry-dynamic-versioning#configuration>), reporting the
+/// settings that cannot be expressed with `hatch-vcs`.
+pub fn get_hatch_version(dynamic_versioning: &PoetryDynamicVersioning) -> Version {
+    let mut unsupported_settings: Vec<&str> = Vec::new();
+
+    // `hatch-vcs` relies on `setuptools-scm`, which only supports Git and Mercurial.
+    if let Some(vcs) = &dynamic_versioning.vcs
+        && !matches!(vcs.as_str(), "any" | "git" | "mercurial")
+    {
+        unsupported_settings.push("vcs");
+    }
+
+    // `setuptools-scm` always generates PEP 440 versions.
+    if let Some(style) = &dynamic_versioning.style
+        && style != "pep440"
+    {
+        unsupported_settings.push("style");
+    }
+
+    let tag_pattern = match dynamic_versioning.pattern.as_deref() {
+        // Default patterns match tags like "v1.2.3" or "1.2.3", which `setuptools-scm` also
+        // matches by default.
+        None | Some("default" | "default-unprefixed") => None,
+        // Custom patterns capture the version in a group named "base", while `setuptools-scm`
+        // expects it to be named "version". Pre-release stage and revision can also be captured
+        // in separate groups, which `setuptools-scm` has no equivalent for.
+        Some(pattern)
+            if pattern.contains("(?P<base>")
+                && !pattern.contains("(?P<stage>")
+                && !pattern.contains("(?P<revision>") =>
+        {
+            Some(pattern.replace("(?P<base>", "(?P<version>"))
+        }
+        Some(_) => {
+            unsupported_settings.push("pattern");
+            None
+        }
+    };
+
+    unsupported_settings.extend(
+        dynamic_versioning
+            .remaining_fields
+            .keys()
+            .map(String::as_str),
+    );
+
+    if !unsupported_settings.is_empty() {
+        add_recoverable_error(format!(
+            "The following settings from \"{}\" have no equivalent in hatch-vcs, and were not migrated: {}.",
+            "[tool.poetry-dynamic-versioning]".bold(),
+            unsupported_settings
+                .iter()
+                .map(|setting| format!("\"{}\"", setting.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+    }
+
+    Version {
+        source: Some("vcs".to_string()),
+        tag_pattern,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+    use rstest::rstest;
+
+    #[test]
+    fn test_get_hatch_version() {
+        let dynamic_versioning = PoetryDynamicVersioning {
+            enable: Some(true),
+            vcs: Some("git".to_string()),
+            style: Some("pep440".to_string()),
+            pattern: Some(r"(?x)^release-(?P<base>\d+\.\d+\.\d+)$".to_string()),
+            remaining_fields: IndexMap::new(),
+        };
+
+        assert_eq!(
+            get_hatch_version(&dynamic_versioning),
+            Version {
+                source: Some("vcs".to_string()),
+                tag_pattern: Some(r"(?x)^release-(?P<version>\d+\.\d+\.\d+)$".to_string()),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(r"^v(?P<base>\d+\.\d+\.\d+)(-?((?P<stage>[a-zA-Z]+)\.?(?P<revision>\d+)?))?$")]
+    #[case(r"^v(?P<base>\d+\.\d+\.\d+)(-(?P<stage>rc))?$")]
+    fn test_get_hatch_version_unsupported_pattern(#[case] pattern: &str) {
+        let dynamic_versioning = PoetryDynamicVersioning {
+            pattern: Some(pattern.to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_hatch_version(&dynamic_versioning),
+            Version {
+                source: Some("vcs".to_string()),
+                tag_pattern: None,
+            }
+        );
+    }
+}
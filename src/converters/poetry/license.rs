@@ -0,0 +1,243 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::pep_621::License;
+use owo_colors::OwoColorize;
+use spdx::identifiers::{EXCEPTIONS, LICENSES};
+use std::fs;
+use std::path::Path;
+
+/// Free-form license names commonly used in Poetry projects, with their SPDX expression. Poetry
+/// documents "Proprietary" as a valid license (<https://python-poetry.org/docs/pyproject/#license>),
+/// which has no SPDX identifier, so it uses a custom license reference, as recommended by PEP 639
+/// (<https://peps.python.org/pep-0639/#spdx-license-expression-syntax>).
+const LICENSE_ALIASES: [(&str, &str); 22] = [
+    ("Apache", "Apache-2.0"),
+    ("Apache 2", "Apache-2.0"),
+    ("Apache 2.0", "Apache-2.0"),
+    ("Apache License 2.0", "Apache-2.0"),
+    ("Apache License, Version 2.0", "Apache-2.0"),
+    ("Apache Software License 2.0", "Apache-2.0"),
+    ("Apache2", "Apache-2.0"),
+    ("BSD 2-Clause", "BSD-2-Clause"),
+    ("BSD 3-Clause", "BSD-3-Clause"),
+    ("GPLv2", "GPL-2.0-only"),
+    ("GPLv2+", "GPL-2.0-or-later"),
+    ("GPLv3", "GPL-3.0-only"),
+    ("GPLv3+", "GPL-3.0-or-later"),
+    ("LGPLv3", "LGPL-3.0-only"),
+    ("LGPLv3+", "LGPL-3.0-or-later"),
+    ("MIT License", "MIT"),
+    ("Mozilla Public License 2.0", "MPL-2.0"),
+    ("MPL 2.0", "MPL-2.0"),
+    ("New BSD", "BSD-3-Clause"),
+    ("Proprietary", "LicenseRef-Proprietary"),
+    ("Simplified BSD", "BSD-2-Clause"),
+    ("The MIT License", "MIT"),
+];
+
+/// Prefixes of license files that Poetry automatically adds to distributions
+/// (<https://python-poetry.org/docs/pyproject/#license>).
+const LICENSE_FILE_PREFIXES: [&str; 3] = ["LICENSE", "LICENCE", "COPYING"];
+
+/// Convert Poetry `license` to a PEP 639 license expression
+/// (<https://packaging.python.org/en/latest/specifications/pyproject-toml/#license>).
+///
+/// Licenses that are valid SPDX expressions are normalized (e.g. "mit" to "MIT"), and common
+/// free-form names and deprecated identifiers are converted to their SPDX equivalent. If the
+/// license cannot be converted to an SPDX expression, it is kept as a legacy `text`.
+pub fn get_license(license: String) -> License {
+    if let Some(expression) = get_spdx_expression(&license) {
+        if expression != license {
+            add_recoverable_error(format!(
+                "License \"{}\" from \"{}\" was converted to SPDX license expression \"{}\".",
+                license.bold(),
+                "poetry.license".bold(),
+                expression.bold(),
+            ));
+        }
+
+        return License::String(expression);
+    }
+
+    add_recoverable_error(format!(
+        "License \"{}\" from \"{}\" is not a valid SPDX license expression, so it was migrated to \"{}\", which is deprecated. Consider replacing it with an SPDX license expression (<https://spdx.org/licenses/>).",
+        license.bold(),
+        "poetry.license".bold(),
+        "project.license.text".bold(),
+    ));
+
+    License::Map {
+        text: Some(license),
+        file: None,
+    }
+}
+
+/// Get license files located at the root of the project, that Poetry automatically adds to
+/// distributions.
+pub fn get_license_files(project_path: &Path) -> Option<Vec<String>> {
+    let mut license_files: Vec<String> = fs::read_dir(project_path)
+        .ok()?
+        .flatten()
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| {
+            LICENSE_FILE_PREFIXES
+                .iter()
+                .any(|prefix| name.to_uppercase().starts_with(prefix))
+        })
+        .collect();
+    license_files.sort();
+
+    if license_files.is_empty() {
+        None
+    } else {
+        Some(license_files)
+    }
+}
+
+/// Get the SPDX license expression (<https://spdx.github.io/spdx-spec/v2.3/SPDX-license-expressions/>)
+/// matching a license, if any.
+fn get_spdx_expression(license: &str) -> Option<String> {
+    let license = license.trim();
+
+    if let Some((_, expression)) = LICENSE_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(license))
+    {
+        return Some((*expression).to_string());
+    }
+
+    let mut tokens: Vec<String> = Vec::new();
+    // Whether the next token is expected to be a license (or an opening parenthesis), instead of an
+    // operator (or a closing parenthesis).
+    let mut expects_license = true;
+    let mut expects_exception = false;
+    let mut depth: usize = 0;
+
+    for token in license
+        .replace('(', " ( ")
+        .replace(')', " ) ")
+        .split_whitespace()
+    {
+        let token = match (token, expects_license) {
+            ("(", true) => {
+                depth += 1;
+                "(".to_string()
+            }
+            (")", false) if depth > 0 => {
+                depth -= 1;
+                ")".to_string()
+            }
+            (operator, false)
+                if ["AND", "OR", "WITH"].contains(&operator.to_uppercase().as_str()) =>
+            {
+                expects_license = true;
+                expects_exception = operator.eq_ignore_ascii_case("WITH");
+                operator.to_uppercase()
+            }
+            (identifier, true) => {
+                expects_license = false;
+
+                if expects_exception {
+                    expects_exception = false;
+                    get_spdx_exception(identifier)?
+                } else {
+                    get_spdx_license(identifier)?
+                }
+            }
+            _ => return None,
+        };
+
+        tokens.push(token);
+    }
+
+    if expects_license || depth > 0 {
+        return None;
+    }
+
+    Some(tokens.join(" ").replace("( ", "(").replace(" )", ")"))
+}
+
+/// Get the SPDX license identifier matching an identifier from the SPDX license list, ignoring the
+/// case. Custom license references, as well as the "or later" `+` suffix, are supported.
+///
+/// Deprecated GNU identifiers (e.g. "GPL-3.0" or "GPL-3.0+") are replaced by their "only" or "or
+/// later" variants (e.g. "GPL-3.0-only" or "GPL-3.0-or-later"), as GNU licenses do not support the
+/// `+` suffix (<https://spdx.org/licenses/GPL-3.0+.html>).
+fn get_spdx_license(identifier: &str) -> Option<String> {
+    if identifier.starts_with("LicenseRef-") || identifier.starts_with("DocumentRef-") {
+        return Some(identifier.to_string());
+    }
+
+    let (identifier, or_later) = match identifier.strip_suffix('+') {
+        Some(identifier) => (identifier, true),
+        None => (identifier, false),
+    };
+    let license = LICENSES
+        .iter()
+        .find(|(name, _, _)| name.eq_ignore_ascii_case(identifier))
+        .and_then(|(name, _, _)| spdx::license_id(name))?;
+
+    if license.is_gnu() && license.is_deprecated() {
+        let suffix = if or_later { "-or-later" } else { "-only" };
+
+        if let Some(replacement) = spdx::license_id(&format!("{}{suffix}", license.name)) {
+            return Some(replacement.name.to_string());
+        }
+    }
+
+    if or_later {
+        Some(format!("{}+", license.name))
+    } else {
+        Some(license.name.to_string())
+    }
+}
+
+/// Get the SPDX license exception matching an identifier from the SPDX exception list, ignoring
+/// the case.
+fn get_spdx_exception(identifier: &str) -> Option<String> {
+    EXCEPTIONS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(identifier))
+        .map(|(name, _)| (*name).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("MIT", Some("MIT"))]
+    #[case("mit", Some("MIT"))]
+    #[case("MIT License", Some("MIT"))]
+    #[case("Proprietary", Some("LicenseRef-Proprietary"))]
+    #[case("GPL-3.0", Some("GPL-3.0-only"))]
+    #[case("GPL-3.0+", Some("GPL-3.0-or-later"))]
+    #[case("Apache-2.0+", Some("Apache-2.0+"))]
+    #[case("mit or apache-2.0", Some("MIT OR Apache-2.0"))]
+    #[case(
+        "(MIT OR Apache-2.0) AND BSD-3-Clause",
+        Some("(MIT OR Apache-2.0) AND BSD-3-Clause")
+    )]
+    #[case(
+        "GPL-2.0-or-later WITH Classpath-exception-2.0",
+        Some("GPL-2.0-or-later WITH Classpath-exception-2.0")
+    )]
+    #[case("LicenseRef-My-License", Some("LicenseRef-My-License"))]
+    #[case("MPL-1.0", Some("MPL-1.0"))]
+    #[case("apache-1.0", Some("Apache-1.0"))]
+    #[case("Artistic-1.0-Perl", Some("Artistic-1.0-Perl"))]
+    #[case("LGPL-2.0", Some("LGPL-2.0-only"))]
+    #[case("LGPL-2.0+", Some("LGPL-2.0-or-later"))]
+    #[case("MIT WITH Swift-exception", Some("MIT WITH Swift-exception"))]
+    #[case("My Own License", None)]
+    #[case("MIT OR", None)]
+    #[case("(MIT", None)]
+    #[case("MIT WITH Apache-2.0", None)]
+    fn test_get_spdx_expression(#[case] license: &str, #[case] expected: Option<&str>) {
+        assert_eq!(
+            get_spdx_expression(license),
+            expected.map(ToString::to_string)
+        );
+    }
+}
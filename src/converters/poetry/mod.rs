@@ -1,27 +1,31 @@
 mod build_backend;
+mod credentials;
 mod dependencies;
+mod dynamic_versioning;
+mod license;
 mod project;
+mod settings;
 mod sources;
 pub mod version;
 
-use crate::converters::Converter;
 use crate::converters::ConverterOptions;
 use crate::converters::poetry::build_backend::{BuildBackendObject, get_build_backend};
 use crate::converters::poetry::project::get_classifiers;
 use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::converters::{Converter, add_generated_file};
 use crate::errors::{
     MIGRATION_ERRORS, MigrationError, add_recoverable_error, add_unrecoverable_error,
 };
 use crate::schema::pep_621::{License, Project};
-use crate::schema::poetry::PoetryLock;
+use crate::schema::poetry::{PoetryDynamicVersioning, PoetryLock};
 use crate::schema::pyproject::PyProject;
 use crate::schema::uv::{SourceContainer, Uv};
 use crate::toml::PyprojectPrettyFormatter;
 use indexmap::IndexMap;
 use owo_colors::OwoColorize;
 use std::fs;
-use toml_edit::DocumentMut;
 use toml_edit::visit_mut::VisitMut;
+use toml_edit::{DocumentMut, Item, Table};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Poetry {
@@ -34,24 +38,58 @@ impl Converter for Poetry {
             fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
         let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
 
-        let poetry = pyproject
-            .tool
-            .unwrap_or_default()
-            .poetry
-            .unwrap_or_default();
+        let tool = pyproject.tool.unwrap_or_default();
+        let poetry = tool.poetry.unwrap_or_default();
+        let dynamic_versioning = tool
+            .poetry_dynamic_versioning
+            .filter(PoetryDynamicVersioning::is_enabled);
 
+        let build_script = poetry.get_build_script().cloned();
+        let build_generates_setup_file = poetry.build_generates_setup_file();
         let build_backend = get_build_backend(
             &self.converter_options,
             pyproject.build_system.as_ref(),
             &poetry,
+            dynamic_versioning.as_ref(),
         );
+        // File scripts can only be expressed with uv and Hatch build backends.
+        if let Some(
+            build_backend @ (BuildBackendObject::Setuptools(_)
+            | BuildBackendObject::Flit(_)
+            | BuildBackendObject::PdmBackend(_)),
+        ) = &build_backend
+        {
+            for file_script in poetry.get_file_scripts() {
+                add_recoverable_error(format!(
+                    "File script \"{}\" from \"{}\" could not be migrated, as file scripts cannot be expressed with {build_backend}.",
+                    file_script.bold(),
+                    "poetry.scripts".bold(),
+                ));
+            }
+        }
+
+        let distribution_differences = build_backend.as_ref().and_then(|build_backend| {
+            build_backend::distribution::get_differences(
+                &self.get_project_path(),
+                &poetry,
+                build_backend,
+            )
+        });
         let build_system = build_backend::get_new_build_system(
             pyproject.build_system,
             self.converter_options.keep_current_build_backend,
             build_backend.as_ref(),
             self.converter_options.build_backend,
+            dynamic_versioning.is_some(),
+            build_script.is_some(),
         );
 
+        // With `poetry-dynamic-versioning`, the version from Poetry metadata is only a placeholder,
+        // so the version is set as dynamic, as long as the build backend is able to compute it.
+        let dynamic_version = dynamic_versioning.is_some()
+            && (self.keep_current_build_backend()
+                || matches!(build_backend, Some(BuildBackendObject::Hatch(_))));
+
         let mut uv_source_index: IndexMap<String, SourceContainer> = IndexMap::new();
         let (dependency_groups, uv_default_groups) =
             dependencies::get_dependency_groups_and_default_groups(
@@ -59,6 +97,7 @@ impl Converter for Poetry {
                 &mut uv_source_index,
                 self.get_dependency_groups_strategy(),
             );
+        dependencies::check_prereleases(&poetry);
         let mut poetry_dependencies = poetry.dependencies;
 
         let python_specification = poetry_dependencies
@@ -84,6 +123,22 @@ impl Converter for Poetry {
             .as_mut()
             .and_then(|plugins| plugins.shift_remove("gui_scripts"));
 
+        let license = poetry.license.map(license::get_license);
+        let has_license_expression = matches!(license, Some(License::String(_)));
+
+        // License files can only be set alongside a license expression, and are only useful if the
+        // license from Poetry is the one that ends up in the project.
+        let license_files = if has_license_expression
+            && pyproject
+                .project
+                .as_ref()
+                .is_none_or(|p| p.license.is_none())
+        {
+            license::get_license_files(&self.get_project_path())
+        } else {
+            None
+        };
+
         let project = Project {
             // "name" is required by uv.
             name: Some(poetry.name.unwrap_or_default()),
@@ -91,7 +146,8 @@ impl Converter for Poetry {
             authors: project::get_authors(poetry.authors),
             requires_python: requires_python.clone(),
             readme: project::get_readme(poetry.readme),
-            license: poetry.license.map(License::String),
+            license,
+            license_files,
             maintainers: project::get_authors(poetry.maintainers),
             keywords: poetry.keywords,
             classifiers: get_classifiers(
@@ -99,6 +155,7 @@ impl Converter for Poetry {
                 build_system.as_ref(),
                 requires_python,
                 pyproject.project.as_ref(),
+                has_license_expression,
             ),
             dependencies: dependencies::get(poetry_dependencies.as_ref(), &mut uv_source_index),
             optional_dependencies,
@@ -114,9 +171,12 @@ impl Converter for Poetry {
             ..Default::default()
         };
 
-        let uv = Uv {
+        let mut indexes = sources::get_indexes(poetry.source);
+        credentials::set_authentication(&self.get_project_path(), indexes.as_mut());
+
+        let mut uv = Uv {
             package: poetry.package_mode,
-            index: sources::get_indexes(poetry.source),
+            index: indexes,
             sources: if uv_source_index.is_empty() {
                 None
             } else {
@@ -129,7 +189,9 @@ impl Converter for Poetry {
             } else {
                 None
             },
+            ..Uv::default()
         };
+        settings::migrate(&self.get_project_path(), &mut uv);
 
         let mut updated_pyproject = pyproject_toml_content.parse::<DocumentMut>().unwrap();
         let mut pyproject_updater = PyprojectUpdater {
@@ -137,21 +199,88 @@ impl Converter for Poetry {
         };
 
         pyproject_updater.insert_build_system(build_system.as_ref());
-        pyproject_updater.insert_pep_621(&self.build_project(
+        let mut project = self.build_project(
             pyproject.project,
             project,
             poetry.version.unwrap_or_else(|| "0.0.1".to_string()),
-        ));
+        );
+        if dynamic_version {
+            project.version = None;
+
+            let dynamic = project.dynamic.get_or_insert_default();
+            if !dynamic.contains(&"version".to_string()) {
+                dynamic.push("version".to_string());
+            }
+        }
+
+        pyproject_updater.insert_pep_621(&project);
         pyproject_updater.insert_dependency_groups(dependency_groups.as_ref());
         pyproject_updater.insert_uv(&uv);
 
         if let Some(BuildBackendObject::Hatch(ref hatch)) = build_backend {
             pyproject_updater.insert_hatch(Some(hatch));
+
+            // An existing build hook is never overwritten, which is already reported as an error.
+            if let Some(build_script) = &build_script
+                && add_generated_file(
+                    &self.get_project_path(),
+                    build_backend::hatch::BUILD_HOOK_PATH,
+                    build_backend::hatch::get_build_hook(build_script, build_generates_setup_file),
+                )
+            {
+                if build_generates_setup_file {
+                    add_recoverable_error(format!(
+                        "Build script \"{}\" is now run by a Hatch build hook generated in \"{}\", that calls its \"{}\" function and builds extension modules with setuptools. Make sure that \"{}\" is part of build requirements, and that built files are included in wheels.",
+                        build_script.bold(),
+                        build_backend::hatch::BUILD_HOOK_PATH.bold(),
+                        "build".bold(),
+                        "setuptools".bold(),
+                    ));
+                } else {
+                    add_recoverable_error(format!(
+                        "Build script \"{}\" is now run by a Hatch build hook generated in \"{}\". Make sure that the script builds extension modules when run directly, and that built files are included in wheels.",
+                        build_script.bold(),
+                        build_backend::hatch::BUILD_HOOK_PATH.bold(),
+                    ));
+                }
+            }
+        }
+
+        match build_backend {
+            Some(BuildBackendObject::Setuptools(ref setuptools)) => {
+                pyproject_updater.insert_setuptools(setuptools);
+            }
+            Some(BuildBackendObject::Flit(ref flit)) => pyproject_updater.insert_flit(flit),
+            Some(BuildBackendObject::PdmBackend(ref build)) => {
+                pyproject_updater.insert_pdm_build(build);
+            }
+            _ => (),
+        }
+
+        // setuptools can only add files to the source distribution from a manifest. An existing
+        // manifest is never overwritten, which is already reported as an error.
+        if let Some(BuildBackendObject::Setuptools(ref setuptools)) = build_backend
+            && let Some(manifest) = build_backend::setuptools::get_manifest(
+                &self.get_project_path(),
+                setuptools,
+                poetry.include.as_ref(),
+                poetry.exclude.as_ref(),
+            )
+        {
+            add_generated_file(
+                &self.get_project_path(),
+                build_backend::setuptools::MANIFEST_PATH,
+                manifest,
+            );
         }
 
-        self.remove_pyproject_poetry_section(&mut updated_pyproject);
+        self.remove_pyproject_poetry_section(&mut updated_pyproject, dynamic_version);
 
-        if let Some(build_backend) = build_backend {
+        if let Some(distribution_differences) = distribution_differences {
+            for difference in distribution_differences {
+                add_recoverable_error(difference);
+            }
+        } else if let Some(build_backend) = build_backend {
             add_recoverable_error(format!(
                 "Build backend was migrated to {build_backend}. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration."
             ));
@@ -215,8 +344,10 @@ impl Poetry {
     /// Remove `[tool.poetry]` section from `pyproject.toml`, unless user has explicitly asked for
     /// the old metadata to be kept.
     /// If the current build backend should be kept, instead of removing `[tool.poetry]` section, we
-    /// only remove the keys that are not related to the build backend.
-    fn remove_pyproject_poetry_section(&self, pyproject: &mut DocumentMut) {
+    /// only remove the keys that are not related to the build backend. If the version is set
+    /// dynamically, `poetry-dynamic-versioning` also needs the placeholder version and its own
+    /// section to be kept.
+    fn remove_pyproject_poetry_section(&self, pyproject: &mut DocumentMut, dynamic_version: bool) {
         if self.keep_old_metadata() {
             return;
         }
@@ -228,11 +359,14 @@ impl Poetry {
                 if let Some(poetry) = tool_table.get_mut("poetry")
                     && let Some(poetry_table) = poetry.as_table_mut()
                 {
-                    let keys_to_keep = ["packages", "include", "exclude"];
+                    let keys_to_keep = ["packages", "include", "exclude", "build"];
                     let mut found_keys_to_keep = false;
 
                     for (key, _) in &poetry_table.clone() {
-                        if keys_to_keep.contains(&key) {
+                        if keys_to_keep.contains(&key)
+                            || (dynamic_version && key == "version")
+                            || (key == "scripts" && Self::keep_file_scripts(poetry_table))
+                        {
                             found_keys_to_keep = true;
                         } else {
                             poetry_table.remove(key);
@@ -246,9 +380,34 @@ impl Poetry {
                 }
             } else {
                 tool_table.remove("poetry");
+                tool_table.remove("poetry-dynamic-versioning");
             }
         }
     }
+
+    /// File scripts cannot be set in `[project.scripts]`, so Poetry build backend still reads them
+    /// from `[tool.poetry.scripts]` (<https://python-poetry.org/docs/pyproject/#scripts>). Remove
+    /// all other scripts, and return whether file scripts were found.
+    fn keep_file_scripts(poetry_table: &mut Table) -> bool {
+        let Some(scripts) = poetry_table
+            .get_mut("scripts")
+            .and_then(Item::as_table_like_mut)
+        else {
+            return false;
+        };
+
+        let scripts_to_remove: Vec<String> = scripts
+            .iter()
+            .filter(|(_, script)| script.get("type").and_then(Item::as_str) != Some("file"))
+            .map(|(name, _)| name.to_string())
+            .collect();
+
+        for name in scripts_to_remove {
+            scripts.remove(&name);
+        }
+
+        !scripts.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use crate::errors::add_unrecoverable_error;
+use crate::errors::{add_recoverable_error, add_unrecoverable_error};
 use crate::schema::pep_621::{AuthorOrMaintainer, Project};
 use crate::schema::poetry::Script;
 use crate::schema::pyproject::BuildSystem;
@@ -13,6 +13,7 @@ use std::sync::LazyLock;
 const EARLIEST_SUPPORTED_PYTHON_3_MINOR_VERSION: u8 = 4;
 const LATEST_SUPPORTED_PYTHON_3_MINOR_VERSION: u8 = 14;
 const PYTHON_CLASSIFIER_PREFIX: &str = "Programming Language :: Python";
+const LICENSE_CLASSIFIER_PREFIX: &str = "License ::";
 
 static AUTHOR_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^(?<name>[^<>]+)(?: <(?<email>.+?)>)?$").unwrap());
@@ -90,6 +91,12 @@ pub fn get_urls(
     Some(urls)
 }
 
+/// Build scripts to set under `scripts` from Poetry console scripts
+/// (<https://python-poetry.org/docs/pyproject/#scripts>) and `console_scripts` plugins. Extras
+/// required by a script are kept using the entry point syntax
+/// (<https://packaging.python.org/en/latest/specifications/entry-points/#data-model>), as Poetry
+/// does when building distributions. File scripts are not entry points, and are instead handled by
+/// the build backend.
 pub fn get_scripts(
     poetry_scripts: Option<IndexMap<String, Script>>,
     scripts_from_plugins: Option<IndexMap<String, String>>,
@@ -102,11 +109,31 @@ pub fn get_scripts(
                 Script::String(script) => {
                     scripts.insert(name, script);
                 }
-                Script::Map { callable } => {
-                    if let Some(callable) = callable {
-                        scripts.insert(name, callable);
+                Script::Map {
+                    reference,
+                    callable,
+                    type_,
+                    extras,
+                } => match type_.as_deref() {
+                    None | Some("console") => {
+                        if let Some(reference) = reference.or(callable) {
+                            let script = match extras {
+                                Some(extras) if !extras.is_empty() => {
+                                    format!("{reference} [{}]", extras.join(", "))
+                                }
+                                _ => reference,
+                            };
+                            scripts.insert(name, script);
+                        }
                     }
-                }
+                    Some("file") => (),
+                    Some(type_) => add_recoverable_error(format!(
+                        "Script \"{}\" from \"{}\" could not be migrated, as type \"{}\" is not supported.",
+                        name.bold(),
+                        "poetry.scripts".bold(),
+                        type_.bold(),
+                    )),
+                },
             }
         }
     }
@@ -135,11 +162,19 @@ pub fn get_classifiers(
     build_system: Option<&BuildSystem>,
     requires_python: Option<String>,
     project: Option<&Project>,
+    has_license_expression: bool,
 ) -> Option<Vec<String>> {
     // Using an IndexSet ensures that we keep the previous order, while also remove duplicate, in
     // case for instance Python classifiers are also manually set.
     let mut classifiers: IndexSet<String> = IndexSet::from_iter(classifiers.unwrap_or_default());
 
+    // License classifiers are deprecated in favor of license expressions, and build backends
+    // reject them when a license expression is set
+    // (<https://packaging.python.org/en/latest/specifications/core-metadata/#license-expression>).
+    if has_license_expression {
+        classifiers.retain(|classifier| !classifier.starts_with(LICENSE_CLASSIFIER_PREFIX));
+    }
+
     let has_pep_621_classifiers = project.is_some_and(|p| p.classifiers.is_some());
 
     // If we did not find Poetry build system, we're likely not migrating a package, or the package
@@ -0,0 +1,182 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::uv::{Index, Uv};
+use owo_colors::OwoColorize;
+use std::fs;
+use std::path::Path;
+use toml::{Table, Value};
+
+/// Migrate settings from `poetry.toml` (<https://python-poetry.org/docs/configuration/>) that have
+/// an equivalent in uv to `[tool.uv]`, reporting the ones that do not.
+pub fn migrate(project_path: &Path, uv: &mut Uv) {
+    let Ok(content) = fs::read_to_string(project_path.join("poetry.toml")) else {
+        return;
+    };
+    let Ok(settings) = content.parse::<Table>() else {
+        return;
+    };
+
+    let mut unsupported_settings: Vec<String> = Vec::new();
+
+    for (key, value) in flatten(&settings, "") {
+        if let Some(repository) = key
+            .strip_prefix("repositories.")
+            .and_then(|key| key.strip_suffix(".url"))
+            && let Value::String(url) = &value
+        {
+            set_publish_url(uv, repository, url);
+            continue;
+        }
+
+        match (key.as_str(), &value) {
+            // uv always creates the virtual environment in `.venv` of the project.
+            ("virtualenvs.in-project", Value::Boolean(true)) => (),
+            ("virtualenvs.prefer-active-python", Value::Boolean(prefer_active_python)) => {
+                if *prefer_active_python {
+                    uv.python_preference = Some("system".to_string());
+                }
+            }
+            ("installer.max-workers", Value::Integer(max_workers)) => {
+                uv.concurrent_installs = Some(*max_workers);
+            }
+            ("installer.parallel", Value::Boolean(parallel)) => {
+                if !parallel {
+                    uv.concurrent_installs = Some(1);
+                }
+            }
+            ("installer.no-binary", Value::String(no_binary)) => match no_binary.as_str() {
+                ":all:" => uv.no_binary = Some(true),
+                ":none:" => (),
+                packages => {
+                    uv.no_binary_package = Some(
+                        packages
+                            .split(',')
+                            .map(|package| package.trim().to_string())
+                            .filter(|package| !package.is_empty())
+                            .collect(),
+                    );
+                }
+            },
+            ("cache-dir", Value::String(cache_dir)) => {
+                uv.cache_dir = Some(cache_dir.clone());
+            }
+            // Credentials are handled when converting sources to indexes.
+            (key, _) if key.starts_with("http-basic.") => (),
+            (key, _) => unsupported_settings.push(key.to_string()),
+        }
+    }
+
+    if !unsupported_settings.is_empty() {
+        add_recoverable_error(format!(
+            "The following settings from \"{}\" have no equivalent in uv, and were not migrated: {}.",
+            "poetry.toml".bold(),
+            unsupported_settings
+                .iter()
+                .map(|setting| format!("\"{}\"", setting.bold()))
+                .collect::<Vec<String>>()
+                .join(", "),
+        ));
+    }
+}
+
+/// Set the URL of a repository that Poetry publishes to as the publish URL of the index with the
+/// same name, or add an explicit index for it, so that it can be used with
+/// `uv publish --index <name>`.
+fn set_publish_url(uv: &mut Uv, name: &str, url: &str) {
+    let indexes = uv.index.get_or_insert_default();
+
+    if let Some(index) = indexes
+        .iter_mut()
+        .find(|index| index.name.eq_ignore_ascii_case(name))
+    {
+        index.publish_url = Some(url.to_string());
+        return;
+    }
+
+    // Indexes require a URL to install packages from. For PyPI-like repositories (e.g. TestPyPI),
+    // the upload endpoint is `/legacy/`, next to the `/simple/` one.
+    let index_url = match url.trim_end_matches('/').strip_suffix("/legacy") {
+        Some(base_url) => format!("{base_url}/simple/"),
+        None => url.to_string(),
+    };
+
+    indexes.push(Index {
+        name: name.to_string(),
+        url: Some(index_url),
+        explicit: Some(true),
+        publish_url: Some(url.to_string()),
+        ..Default::default()
+    });
+}
+
+/// Flatten nested settings into dotted keys (e.g. `virtualenvs.in-project`), as Poetry names
+/// them.
+fn flatten(table: &Table, prefix: &str) -> Vec<(String, Value)> {
+    table
+        .iter()
+        .flat_map(|(key, value)| {
+            let key = format!("{prefix}{key}");
+
+            match value {
+                Value::Table(table) => flatten(table, &format!("{key}.")),
+                _ => vec![(key, value.clone())],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_migrate() {
+        let tmp_dir = tempdir().unwrap();
+        let mut file = File::create(tmp_dir.path().join("poetry.toml")).unwrap();
+        file.write_all(
+            br#"cache-dir = "/tmp/cache"
+
+[virtualenvs]
+in-project = true
+prefer-active-python = true
+
+[installer]
+max-workers = 4
+no-binary = "foo, bar"
+
+[repositories.private]
+url = "https://private.example.com/legacy/"
+"#,
+        )
+        .unwrap();
+
+        let mut uv = Uv {
+            index: Some(vec![Index {
+                name: "private".to_string(),
+                url: Some("https://private.example.com/simple/".to_string()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        migrate(tmp_dir.path(), &mut uv);
+
+        assert_eq!(uv.python_preference, Some("system".to_string()));
+        assert_eq!(uv.concurrent_installs, Some(4));
+        assert_eq!(
+            uv.no_binary_package,
+            Some(vec!["foo".to_string(), "bar".to_string()])
+        );
+        assert_eq!(uv.cache_dir, Some("/tmp/cache".to_string()));
+        assert_eq!(
+            uv.index,
+            Some(vec![Index {
+                name: "private".to_string(),
+                url: Some("https://private.example.com/simple/".to_string()),
+                publish_url: Some("https://private.example.com/legacy/".to_string()),
+                ..Default::default()
+            }])
+        );
+    }
+}
@@ -1,7 +1,12 @@
 use owo_colors::OwoColorize;
 use pep440_rs::{Operator, Version, VersionSpecifiers};
+use std::cmp::Ordering;
 use std::str::FromStr;
 
+/// Operators supported by Poetry, ordered so that operators that are prefixes of others (e.g.,
+/// "=" for "==") come last.
+const OPERATORS: [&str; 11] = ["===", "==", "!=", "~=", "<=", ">=", "<", ">", "=", "^", "~"];
+
 pub enum PoetryPep440 {
     String(String),
     Compatible(Version),
@@ -34,6 +39,42 @@ impl PoetryPep440 {
             .join(" and ")
     }
 
+    /// Convert a union of version specifiers (e.g. `^1.2 || ^2.0`) to a single range (e.g.
+    /// `>=1.2,<3`), if the ranges of the specifiers overlap or are adjacent. Returns `None` if the
+    /// union is disjoint, or if a specifier cannot be expressed as a range (e.g. `!=1.2.3`).
+    fn from_union(s: &str) -> Option<Self> {
+        let mut ranges = Vec::new();
+
+        for specifier in s.split("||").flat_map(|s| s.split('|')) {
+            let specifiers =
+                VersionSpecifiers::from_str(&Self::from_str(specifier).ok()?.to_string()).ok()?;
+            let range = Range::from_specifiers(&specifiers)?;
+
+            if !range.is_empty() {
+                ranges.push(range);
+            }
+        }
+
+        ranges.sort_by(|a, b| Range::compare_lower_bounds(a.lower.as_ref(), b.lower.as_ref()));
+
+        let mut ranges = ranges.into_iter();
+        let mut merged_range = ranges.next()?;
+
+        for range in ranges {
+            if !merged_range.is_contiguous_with(&range) {
+                return None;
+            }
+
+            if Range::compare_upper_bounds(merged_range.upper.as_ref(), range.upper.as_ref())
+                == Ordering::Less
+            {
+                merged_range.upper = range.upper;
+            }
+        }
+
+        Some(Self::String(merged_range.to_string()))
+    }
+
     /// <https://python-poetry.org/docs/dependency-specification/#caret-requirements>
     fn from_caret(s: &str) -> Result<Self, ParseVersionError> {
         if let Ok(version) = Version::from_str(s) {
@@ -65,13 +106,211 @@ impl PoetryPep440 {
             s.to_string(),
         ))
     }
+
+    /// Split a version into pairs of operator and version (e.g., ">= 1.2 < 2" into `(">=", "1.2")`
+    /// and `("<", "2")`), where specifiers can either be delimited by commas or whitespaces, and
+    /// the operator can be separated from the version by whitespaces. Specifiers without operator
+    /// (e.g., "1.2.3") get an empty one.
+    fn tokenize(s: &str) -> Result<Vec<(&str, &str)>, ParseVersionError> {
+        let is_delimiter = |c: char| c.is_whitespace() || c == ',';
+
+        let mut specifiers = Vec::new();
+        let mut rest = s.trim_start_matches(is_delimiter);
+
+        while !rest.is_empty() {
+            let operator = OPERATORS
+                .iter()
+                .find(|operator| rest.starts_with(**operator))
+                .map_or("", |operator| *operator);
+
+            let remaining = rest[operator.len()..].trim_start();
+            let version_end = remaining
+                .find(|c: char| is_delimiter(c) || "<>=!~^".contains(c))
+                .unwrap_or(remaining.len());
+
+            // An operator must always be followed by a version, e.g. ">= <2" is ambiguous.
+            if version_end == 0 {
+                return Err(ParseVersionError::new(
+                    ParseVersionErrorKind::Other,
+                    s.to_string(),
+                ));
+            }
+
+            specifiers.push((operator, &remaining[..version_end]));
+            rest = remaining[version_end..].trim_start_matches(is_delimiter);
+        }
+
+        Ok(specifiers)
+    }
+
+    /// Convert a single specifier to PEP 440, returning `None` if it has no PEP 440 equivalent.
+    fn from_specifier(operator: &str, version: &str) -> Option<Self> {
+        // Poetry allows using "x" and "X" as wildcards, in addition to "*" (e.g., "1.2.x").
+        let wildcard_prefix = [".*", ".x", ".X"]
+            .iter()
+            .find_map(|wildcard| version.strip_suffix(wildcard));
+
+        match (operator, wildcard_prefix) {
+            ("" | "=" | "==", _) if version == "*" => Some(Self::String(String::new())),
+            // A wildcard does not change the range covered by caret and tilde requirements, e.g.
+            // "~1.2.*" is equivalent to "~1.2".
+            ("^", prefix) => Self::from_caret(prefix.unwrap_or(version)).ok(),
+            ("~", prefix) => Self::from_tilde(prefix.unwrap_or(version)).ok(),
+            ("" | "=" | "==", Some(prefix)) => Some(Self::String(format!("=={prefix}.*"))),
+            ("!=", Some(prefix)) => Some(Self::String(format!("!={prefix}.*"))),
+            ("" | "=", None) => Some(Self::String(format!("=={version}"))),
+            // Other operators do not support wildcards, e.g. ">=1.2.*" is ambiguous.
+            (_, Some(_)) => None,
+            (operator, None) => Some(Self::String(format!("{operator}{version}"))),
+        }
+    }
+}
+
+/// Bound of a range of versions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Bound {
+    version: Version,
+    inclusive: bool,
+}
+
+/// Contiguous range of versions, where a missing bound means that the range is unbounded on that
+/// side.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Range {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+}
+
+impl Range {
+    /// Get the range of versions matched by PEP 440 specifiers, if they can be expressed as one.
+    fn from_specifiers(specifiers: &VersionSpecifiers) -> Option<Self> {
+        let mut range = Self::default();
+
+        for specifier in specifiers.iter() {
+            let version = specifier.version().clone();
+            let (lower, upper) = match specifier.operator() {
+                Operator::GreaterThanEqual => (Some((version, true)), None),
+                Operator::GreaterThan => (Some((version, false)), None),
+                Operator::LessThanEqual => (None, Some((version, true))),
+                Operator::LessThan => (None, Some((version, false))),
+                Operator::Equal | Operator::ExactEqual => {
+                    (Some((version.clone(), true)), Some((version, true)))
+                }
+                // `~=1.2.3` is equivalent to `>=1.2.3,<1.3`.
+                Operator::TildeEqual => {
+                    let release = version.release();
+                    let mut upper_release = release[..release.len() - 1].to_vec();
+                    *upper_release.last_mut()? += 1;
+
+                    (
+                        Some((version, true)),
+                        Some((Version::new(upper_release), false)),
+                    )
+                }
+                _ => return None,
+            };
+
+            if let Some((version, inclusive)) = lower {
+                let bound = Bound { version, inclusive };
+                if Self::compare_lower_bounds(range.lower.as_ref(), Some(&bound)) == Ordering::Less
+                {
+                    range.lower = Some(bound);
+                }
+            }
+            if let Some((version, inclusive)) = upper {
+                let bound = Bound { version, inclusive };
+                if Self::compare_upper_bounds(range.upper.as_ref(), Some(&bound))
+                    == Ordering::Greater
+                {
+                    range.upper = Some(bound);
+                }
+            }
+        }
+
+        Some(range)
+    }
+
+    /// Compare lower bounds, where a missing bound is the lowest one, and an exclusive bound is
+    /// higher than an inclusive one on the same version.
+    fn compare_lower_bounds(a: Option<&Bound>, b: Option<&Bound>) -> Ordering {
+        match (a, b) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) => a
+                .version
+                .cmp(&b.version)
+                .then_with(|| b.inclusive.cmp(&a.inclusive)),
+        }
+    }
+
+    /// Compare upper bounds, where a missing bound is the highest one, and an exclusive bound is
+    /// lower than an inclusive one on the same version.
+    fn compare_upper_bounds(a: Option<&Bound>, b: Option<&Bound>) -> Ordering {
+        match (a, b) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(a), Some(b)) => a
+                .version
+                .cmp(&b.version)
+                .then_with(|| a.inclusive.cmp(&b.inclusive)),
+        }
+    }
+
+    /// Whether the range matches no version at all (e.g. `>=2.0,<1.0`).
+    fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => match lower.version.cmp(&upper.version) {
+                Ordering::Greater => true,
+                Ordering::Equal => !(lower.inclusive && upper.inclusive),
+                Ordering::Less => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Whether a range starting after this one starts can be merged with it, without including
+    /// versions that none of them matches.
+    fn is_contiguous_with(&self, other: &Self) -> bool {
+        match (&self.upper, &other.lower) {
+            (Some(upper), Some(lower)) => match upper.version.cmp(&lower.version) {
+                Ordering::Greater => true,
+                Ordering::Equal => upper.inclusive || lower.inclusive,
+                Ordering::Less => false,
+            },
+            _ => true,
+        }
+    }
+}
+
+impl std::fmt::Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(lower), Some(upper)) = (&self.lower, &self.upper)
+            && lower == upper
+        {
+            return write!(f, "=={}", lower.version);
+        }
+
+        let mut specifiers = Vec::new();
+
+        if let Some(lower) = &self.lower {
+            let operator = if lower.inclusive { ">=" } else { ">" };
+            specifiers.push(format!("{operator}{}", lower.version));
+        }
+        if let Some(upper) = &self.upper {
+            let operator = if upper.inclusive { "<=" } else { "<" };
+            specifiers.push(format!("{operator}{}", upper.version));
+        }
+
+        write!(f, "{}", specifiers.join(","))
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ParseVersionErrorKind {
     OrOperator(String),
     Other,
-    PythonMarkerOrOperator(String),
     PythonMarker,
 }
 
@@ -96,14 +335,6 @@ impl ParseVersionError {
                     operator.bold(),
                 )
             }
-            ParseVersionErrorKind::PythonMarkerOrOperator(operator) => {
-                format!(
-                    "\"{}\" dependency with python marker \"{}\" contains \"{}\", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.",
-                    dependency.bold(),
-                    self.version.bold(),
-                    operator.bold(),
-                )
-            }
             ParseVersionErrorKind::PythonMarker => {
                 format!(
                     "\"{}\" dependency with python marker \"{}\" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.",
@@ -134,61 +365,44 @@ impl FromStr for PoetryPep440 {
         }
 
         // Poetry supports an `||` operator (or `|`, which is equivalent), as in
-        // "^1.0 || ^2.0 || ^3.0", but there is no PEP 440 equivalent, so it cannot be converted.
+        // "^1.0 || ^2.0 || ^3.0". There is no PEP 440 equivalent, but if the union describes one
+        // contiguous range, it can be expressed as a single range.
         for operator in ["||", "|"] {
             if s.contains(operator) {
-                return Err(ParseVersionError::new(
-                    ParseVersionErrorKind::OrOperator(operator.to_string()),
-                    s.to_string(),
-                ));
+                return Self::from_union(s).ok_or_else(|| {
+                    ParseVersionError::new(
+                        ParseVersionErrorKind::OrOperator(operator.to_string()),
+                        s.to_string(),
+                    )
+                });
             }
         }
 
-        let mut pep_440_specifier = Vec::new();
-
         // Even when using Poetry-specific version specifiers, it is still possible to define
         // additional PEP 440 specifiers (e.g., "^1.0,!=1.1.0") or even define multiple Poetry
-        // specifiers (e.g., "^1.0,^1.1"), so we need to split over "," and treat each group
-        // separately, knowing that each group can either be a Poetry-specific specifier, or a PEP
-        // 440 one.
-        for specifier in s.trim().split(',') {
-            let specifier = specifier.trim();
-
-            // If the subgroup is a valid PEP 440 specifier, we can directly use it without any
-            // transformation.
-            if VersionSpecifiers::from_str(specifier).is_ok() {
-                pep_440_specifier.push(Self::String(specifier.to_string()));
-            } else {
-                let mut chars = specifier.chars();
-
-                match (chars.next(), chars.as_str()) {
-                    (Some('*'), "") => pep_440_specifier.push(Self::String(String::new())),
-                    (Some('^'), version) => match Self::from_caret(version.trim()) {
-                        Ok(v) => pep_440_specifier.push(v),
-                        Err(e) => return Err(e),
-                    },
-                    (Some('~'), version) => match Self::from_tilde(version.trim()) {
-                        Ok(v) => pep_440_specifier.push(v),
-                        Err(e) => return Err(e),
-                    },
-                    (Some('='), version) => {
-                        pep_440_specifier.push(Self::String(format!("=={version}")));
-                    }
-                    (Some('0'..='9'), _) => pep_440_specifier.push(Self::String(format!("=={s}"))),
-                    _ => {
-                        return Err(ParseVersionError::new(
-                            ParseVersionErrorKind::Other,
-                            s.to_string(),
-                        ));
-                    }
+        // specifiers (e.g., "^1.0,^1.1"). Poetry also allows delimiting specifiers with whitespaces
+        // instead of commas (e.g., ">= 1.2 < 2"), so we split the version into pairs of operator
+        // and version, and treat each pair separately.
+        let mut pep_440_specifier = Vec::new();
+
+        for (operator, version) in Self::tokenize(s)? {
+            match Self::from_specifier(operator, version) {
+                Some(v) => pep_440_specifier.push(v),
+                None => {
+                    return Err(ParseVersionError::new(
+                        ParseVersionErrorKind::Other,
+                        s.to_string(),
+                    ));
                 }
             }
         }
 
-        // Concatenate the different specifiers that were split over "," into the final version.
+        // Concatenate the different specifiers into the final version, skipping the ones that
+        // match any version (e.g., "*").
         let version = pep_440_specifier
             .iter()
             .map(ToString::to_string)
+            .filter(|specifier| !specifier.is_empty())
             .collect::<Vec<String>>()
             .join(",");
 
@@ -215,3 +429,76 @@ impl std::fmt::Display for PoetryPep440 {
         write!(f, "{str}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(">=7.0 <7.1", ">=7.0,<7.1")]
+    #[case(">= 1.2 < 2", ">=1.2,<2")]
+    #[case("^7.0  ^7.1", ">=7.0,<8,>=7.1,<8")]
+    #[case("^8.4 !=8.4.2", ">=8.4,<9,!=8.4.2")]
+    #[case("^1.0, != 1.1.*", ">=1.0,<2,!=1.1.*")]
+    #[case("1.2.*", "==1.2.*")]
+    #[case("1.2.x", "==1.2.*")]
+    #[case("~1.2.*", ">=1.2,<1.3")]
+    #[case("^1.* <1.5", ">=1,<2,<1.5")]
+    #[case("= 1.2.3", "==1.2.3")]
+    #[case("* >=1.0", ">=1.0")]
+    fn test_from_str_whitespace_and_wildcard(#[case] version: &str, #[case] expected: &str) {
+        assert_eq!(
+            PoetryPep440::from_str(version).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(">= <2")]
+    #[case("^1.0 >=")]
+    #[case(">=1.2.*")]
+    #[case("^foo")]
+    fn test_from_str_ambiguous(#[case] version: &str) {
+        assert_eq!(
+            PoetryPep440::from_str(version).err(),
+            Some(ParseVersionError::new(
+                ParseVersionErrorKind::Other,
+                version.to_string(),
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case("^1.2 || ^2.0", ">=1.2,<3")]
+    #[case("^1.2|^2.0|^3.0", ">=1.2,<4")]
+    #[case(">=3.8,<3.10 || >=3.10,<4", ">=3.8,<4")]
+    #[case("^2.0 || ^1.2", ">=1.2,<3")]
+    #[case("~1.2 || ~1.3", ">=1.2,<1.4")]
+    #[case(">=1.0,<=2.0 || >2.0", ">=1.0")]
+    #[case("<2.0 || >=1.5", "")]
+    #[case("~=1.2.3 || ^1.3", ">=1.2.3,<2")]
+    #[case("1.2.3 || >1.2.3,<2", ">=1.2.3,<2")]
+    #[case("*||^1.0", "")]
+    fn test_from_str_contiguous_union(#[case] version: &str, #[case] expected: &str) {
+        assert_eq!(
+            PoetryPep440::from_str(version).unwrap().to_string(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("^1.0 || ^3.0", "||")]
+    #[case("<2.0 || >2.0", "||")]
+    #[case("~1.2|~1.4", "|")]
+    #[case("^1.0,!=1.5.0 || ^2.0", "||")]
+    fn test_from_str_disjoint_union(#[case] version: &str, #[case] operator: &str) {
+        assert_eq!(
+            PoetryPep440::from_str(version).err(),
+            Some(ParseVersionError::new(
+                ParseVersionErrorKind::OrOperator(operator.to_string()),
+                version.to_string(),
+            ))
+        );
+    }
+}
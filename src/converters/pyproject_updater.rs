@@ -1,9 +1,12 @@
+use crate::schema::flit::Flit;
 use crate::schema::hatch::Hatch;
+use crate::schema::pdm;
 use crate::schema::pep_621::Project;
 use crate::schema::pyproject::{BuildSystem, DependencyGroupSpecification};
+use crate::schema::setuptools::Setuptools;
 use crate::schema::uv::Uv;
 use indexmap::IndexMap;
-use toml_edit::{DocumentMut, table, value};
+use toml_edit::{DocumentMut, InlineTable, table, value};
 
 /// Updates a `pyproject.toml` document.
 pub struct PyprojectUpdater<'a> {
@@ -74,15 +77,88 @@ impl PyprojectUpdater<'_> {
         );
     }
 
-    /// Remove `constraint-dependencies` under `[tool.uv]`, which is only needed to lock
-    /// dependencies to specific versions in the generated lock file.
-    pub fn remove_constraint_dependencies(&mut self) -> Option<&DocumentMut> {
-        self.pyproject
+    /// Adds or replaces setuptools-specific data in TOML document, keeping existing keys that are
+    /// not set.
+    pub fn insert_setuptools(&mut self, setuptools: &Setuptools) {
+        if setuptools == &Setuptools::default() {
+            return;
+        }
+
+        if !self.pyproject.contains_key("tool") {
+            self.pyproject["tool"] = table();
+        }
+
+        if !self.pyproject["tool"]
+            .as_table_like()
+            .is_some_and(|tool| tool.contains_key("setuptools"))
+        {
+            self.pyproject["tool"]["setuptools"] = value(InlineTable::new());
+        }
+
+        let serialized_setuptools =
+            serde::Serialize::serialize(&setuptools, toml_edit::ser::ValueSerializer::new())
+                .unwrap();
+
+        if let Some(serialized_setuptools) = serialized_setuptools.as_inline_table() {
+            for (key, setuptools_value) in serialized_setuptools {
+                self.pyproject["tool"]["setuptools"][key] = value(setuptools_value.clone());
+            }
+        }
+    }
+
+    /// Adds or replaces Flit-specific data in TOML document.
+    pub fn insert_flit(&mut self, flit: &Flit) {
+        if !self.pyproject.contains_key("tool") {
+            self.pyproject["tool"] = table();
+        }
+
+        self.pyproject["tool"]["flit"] = value(
+            serde::Serialize::serialize(&flit, toml_edit::ser::ValueSerializer::new()).unwrap(),
+        );
+    }
+
+    /// Adds or replaces pdm-backend build data under `[tool.pdm]` in TOML document.
+    pub fn insert_pdm_build(&mut self, build: &pdm::Build) {
+        if !self.pyproject.contains_key("tool") {
+            self.pyproject["tool"] = table();
+        }
+
+        if !self.pyproject["tool"]
+            .as_table_like()
+            .is_some_and(|tool| tool.contains_key("pdm"))
+        {
+            self.pyproject["tool"]["pdm"] = table();
+        }
+
+        self.pyproject["tool"]["pdm"]["build"] = value(
+            serde::Serialize::serialize(&build, toml_edit::ser::ValueSerializer::new()).unwrap(),
+        );
+    }
+
+    /// Remove constraints from `constraint-dependencies` under `[tool.uv]` that are only needed to
+    /// lock dependencies to specific versions in the generated lock file. Other constraints, that
+    /// were explicitly defined with the previous package manager, are kept.
+    pub fn remove_constraint_dependencies(
+        &mut self,
+        locked_constraint_dependencies: &[String],
+    ) -> Option<&DocumentMut> {
+        let uv = self
+            .pyproject
             .get_mut("tool")?
             .as_table_mut()?
             .get_mut("uv")?
-            .as_table_mut()?
-            .remove("constraint-dependencies")?;
+            .as_table_mut()?;
+
+        let constraint_dependencies = uv.get_mut("constraint-dependencies")?.as_array_mut()?;
+        constraint_dependencies.retain(|constraint| {
+            constraint.as_str().is_none_or(|constraint| {
+                !locked_constraint_dependencies.contains(&constraint.to_string())
+            })
+        });
+
+        if constraint_dependencies.is_empty() {
+            uv.remove("constraint-dependencies")?;
+        }
 
         // If `constraint-dependencies` was the only item in `[tool.uv]`, remove `[tool.uv]`.
         if self
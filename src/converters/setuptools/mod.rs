@@ -0,0 +1,339 @@
+mod project;
+pub mod setup_cfg;
+mod setup_py;
+
+use crate::converters::Converter;
+use crate::converters::ConverterOptions;
+use crate::converters::pyproject_updater::PyprojectUpdater;
+use crate::converters::setuptools::project::SetupArguments;
+use crate::errors::{add_recoverable_error, add_unrecoverable_error};
+use crate::schema::pep_621::{License, Project};
+use crate::schema::pyproject::{BuildSystem, PyProject};
+use crate::toml::PyprojectPrettyFormatter;
+use owo_colors::OwoColorize;
+use std::fs;
+use std::fs::{File, remove_file};
+use std::io::Write;
+use toml_edit::DocumentMut;
+use toml_edit::visit_mut::VisitMut;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Setuptools {
+    pub converter_options: ConverterOptions,
+}
+
+impl Converter for Setuptools {
+    fn build_uv_pyproject(&self) -> String {
+        let pyproject_toml_content =
+            fs::read_to_string(self.get_project_path().join("pyproject.toml")).unwrap_or_default();
+        let pyproject: PyProject = toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+        let (setup_arguments, errors) = self.get_setup_arguments();
+
+        for error in errors {
+            add_unrecoverable_error(error);
+        }
+
+        let (scripts, gui_scripts, entry_points) =
+            project::get_scripts_and_entry_points(setup_arguments.entry_points);
+
+        let project = Project {
+            // "name" is required by uv.
+            name: Some(setup_arguments.name.unwrap_or_default()),
+            description: setup_arguments.description,
+            authors: project::get_authors(setup_arguments.author, setup_arguments.author_email),
+            requires_python: setup_arguments.python_requires,
+            readme: setup_arguments.readme,
+            license: setup_arguments.license.map(License::String),
+            maintainers: project::get_authors(
+                setup_arguments.maintainer,
+                setup_arguments.maintainer_email,
+            ),
+            keywords: setup_arguments.keywords,
+            classifiers: setup_arguments.classifiers,
+            dependencies: project::get_dependencies(
+                setup_arguments.install_requires,
+                "install_requires",
+            ),
+            optional_dependencies: project::get_optional_dependencies(
+                setup_arguments.extras_require,
+            ),
+            urls: project::get_urls(
+                setup_arguments.url,
+                setup_arguments.download_url,
+                setup_arguments.project_urls,
+            ),
+            scripts,
+            gui_scripts,
+            entry_points,
+            ..Default::default()
+        };
+
+        let build_system = if pyproject.build_system.is_some() {
+            if let Some(setup_requires) = &setup_arguments.setup_requires {
+                add_recoverable_error(format!(
+                    "\"{}\" ({}) was not migrated, as \"{}\" is already defined in \"{}\".",
+                    "setup_requires".bold(),
+                    setup_requires
+                        .iter()
+                        .map(|s| format!("\"{}\"", s.bold()))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    "[build-system]".bold(),
+                    "pyproject.toml".bold(),
+                ));
+            }
+            None
+        } else {
+            // Version 61.0.0 is the first one supporting PEP 621 metadata.
+            let mut requires = vec!["setuptools>=61".to_string()];
+            requires.extend(setup_arguments.setup_requires.unwrap_or_default());
+
+            Some(BuildSystem {
+                requires,
+                build_backend: Some("setuptools.build_meta".to_string()),
+            })
+        };
+
+        let mut updated_pyproject = pyproject_toml_content.parse::<DocumentMut>().unwrap();
+        let mut pyproject_updater = PyprojectUpdater {
+            pyproject: &mut updated_pyproject,
+        };
+
+        pyproject_updater.insert_build_system(build_system.as_ref());
+        pyproject_updater.insert_pep_621(
+            &self.build_project(
+                pyproject.project,
+                project,
+                // Version used by `setuptools` when no version is set.
+                setup_arguments
+                    .version
+                    .unwrap_or_else(|| "0.0.0".to_string()),
+            ),
+        );
+        pyproject_updater.insert_setuptools(&setup_arguments.setuptools);
+
+        let mut visitor = PyprojectPrettyFormatter::default();
+        visitor.visit_document_mut(&mut updated_pyproject);
+
+        updated_pyproject.to_string()
+    }
+
+    fn get_package_manager_name(&self) -> String {
+        "setuptools".to_string()
+    }
+
+    fn get_converter_options(&self) -> &ConverterOptions {
+        &self.converter_options
+    }
+
+    fn respect_locked_versions(&self) -> bool {
+        // There are no locked dependencies for setuptools.
+        false
+    }
+
+    fn get_migrated_files_to_delete(&self) -> Vec<String> {
+        let setup_py_path = self.get_project_path().join("setup.py");
+
+        // `setup.py` is only deleted if all its arguments could be migrated, as it might contain
+        // logic that needs to be manually migrated otherwise.
+        if let Ok(setup_py_content) = fs::read_to_string(setup_py_path)
+            && setup_py::get_setup_arguments(&setup_py_content)
+                .1
+                .is_empty()
+        {
+            return vec!["setup.py".to_string()];
+        }
+
+        Vec::new()
+    }
+
+    /// Delete `setup.py`, and remove migrated sections from `setup.cfg`, which might also hold
+    /// configuration for other tools. If there is nothing left in `setup.cfg` once migrated
+    /// sections are removed, the file is deleted.
+    fn delete_migrated_files(&self) -> std::io::Result<()> {
+        if self.keep_old_metadata() {
+            return Ok(());
+        }
+
+        for file in self.get_migrated_files_to_delete() {
+            let path = self.get_project_path().join(file);
+
+            if path.exists() {
+                remove_file(path)?;
+            }
+        }
+
+        let setup_cfg_path = self.get_project_path().join("setup.cfg");
+
+        if let Ok(setup_cfg_content) = fs::read_to_string(&setup_cfg_path) {
+            let remaining_content = setup_cfg::remove_migrated_sections(&setup_cfg_content);
+
+            if remaining_content.is_empty() {
+                remove_file(setup_cfg_path)?;
+            } else {
+                File::create(setup_cfg_path)?.write_all(remaining_content.as_bytes())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_constraint_dependencies(&self) -> Option<Vec<String>> {
+        None
+    }
+}
+
+impl Setuptools {
+    /// Get `setup()` arguments from both `setup.cfg` and `setup.py`, with the latter taking
+    /// precedence if an argument is defined in both files, along with the errors that occurred
+    /// when extracting the arguments.
+    fn get_setup_arguments(&self) -> (SetupArguments, Vec<String>) {
+        let mut setup_arguments = SetupArguments::default();
+        let mut errors: Vec<String> = Vec::new();
+
+        if let Ok(setup_cfg_content) = fs::read_to_string(self.get_project_path().join("setup.cfg"))
+        {
+            let (setup_cfg_arguments, setup_cfg_errors) =
+                setup_cfg::get_setup_arguments(&self.get_project_path(), &setup_cfg_content);
+
+            setup_arguments = setup_arguments.merge(setup_cfg_arguments);
+            errors.extend(setup_cfg_errors);
+        }
+
+        if let Ok(setup_py_content) = fs::read_to_string(self.get_project_path().join("setup.py")) {
+            let (setup_py_arguments, setup_py_errors) =
+                setup_py::get_setup_arguments(&setup_py_content);
+
+            setup_arguments = setup_arguments.merge(setup_py_arguments);
+            errors.extend(setup_py_errors);
+        }
+
+        (setup_arguments, errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_setup_cfg_and_setup_py() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        fs::write(
+            project_path.join("setup.cfg"),
+            r"
+[metadata]
+name = foo
+version = 1.2.3
+description = A description
+
+[options]
+install_requires =
+    arrow>=1.2.3
+",
+        )
+        .unwrap();
+
+        fs::write(
+            project_path.join("setup.py"),
+            r#"
+from setuptools import setup
+
+setup(version="2.0.0")
+"#,
+        )
+        .unwrap();
+
+        let setuptools = Setuptools {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(setuptools.build_uv_pyproject(), @r#"
+        [build-system]
+        requires = ["setuptools>=61"]
+        build-backend = "setuptools.build_meta"
+
+        [project]
+        name = "foo"
+        version = "2.0.0"
+        description = "A description"
+        dependencies = ["arrow>=1.2.3"]
+        "#);
+    }
+
+    #[test]
+    fn test_keep_existing_pyproject() {
+        let tmp_dir = tempdir().unwrap();
+        let project_path = tmp_dir.path();
+
+        fs::write(
+            project_path.join("pyproject.toml"),
+            r#"
+[build-system]
+requires = ["setuptools>=64", "setuptools-scm>=8"]
+build-backend = "setuptools.build_meta"
+
+[tool.setuptools]
+zip-safe = false
+"#,
+        )
+        .unwrap();
+
+        fs::write(
+            project_path.join("setup.cfg"),
+            r"
+[metadata]
+name = foo
+version = 1.0.0
+
+[options]
+setup_requires = setuptools-scm
+package_dir =
+    =src
+packages = find:
+
+[options.packages.find]
+where = src
+",
+        )
+        .unwrap();
+
+        let setuptools = Setuptools {
+            converter_options: ConverterOptions {
+                project_path: PathBuf::from(project_path),
+                dry_run: true,
+                skip_lock: true,
+                ..Default::default()
+            },
+        };
+
+        insta::assert_snapshot!(setuptools.build_uv_pyproject(), @r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+
+
+        [build-system]
+        requires = ["setuptools>=64", "setuptools-scm>=8"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.setuptools]
+        zip-safe = false
+        package-dir = { "" = "src" }
+
+        [tool.setuptools.packages.find]
+        where = ["src"]
+        namespaces = false
+        "#);
+    }
+}
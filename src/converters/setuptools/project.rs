@@ -0,0 +1,239 @@
+use crate::errors::add_recoverable_error;
+use crate::schema::pep_621::AuthorOrMaintainer;
+use crate::schema::setuptools::Setuptools;
+use indexmap::IndexMap;
+use owo_colors::OwoColorize;
+use pep508_rs::Requirement;
+use std::str::FromStr;
+use url::Url;
+
+type ScriptsAndEntryPoints = (
+    Option<IndexMap<String, String>>,
+    Option<IndexMap<String, String>>,
+    Option<IndexMap<String, IndexMap<String, String>>>,
+);
+
+/// Arguments passed to `setup()`, either declaratively in `setup.cfg`, or as literal values in
+/// `setup.py` (<https://setuptools.pypa.io/en/latest/references/keywords.html>).
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct SetupArguments {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    pub readme: Option<String>,
+    pub author: Option<String>,
+    pub author_email: Option<String>,
+    pub maintainer: Option<String>,
+    pub maintainer_email: Option<String>,
+    pub license: Option<String>,
+    pub url: Option<String>,
+    pub download_url: Option<String>,
+    pub project_urls: Option<IndexMap<String, String>>,
+    pub keywords: Option<Vec<String>>,
+    pub classifiers: Option<Vec<String>>,
+    pub python_requires: Option<String>,
+    pub install_requires: Option<Vec<String>>,
+    pub extras_require: Option<IndexMap<String, Vec<String>>>,
+    pub entry_points: Option<IndexMap<String, Vec<String>>>,
+    pub setup_requires: Option<Vec<String>>,
+    pub setuptools: Setuptools,
+}
+
+impl SetupArguments {
+    /// Merge arguments with the ones from `other`, which take precedence, as `setuptools` does
+    /// when arguments are both set in `setup.cfg` and `setup.py`.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            name: other.name.or(self.name),
+            version: other.version.or(self.version),
+            description: other.description.or(self.description),
+            readme: other.readme.or(self.readme),
+            author: other.author.or(self.author),
+            author_email: other.author_email.or(self.author_email),
+            maintainer: other.maintainer.or(self.maintainer),
+            maintainer_email: other.maintainer_email.or(self.maintainer_email),
+            license: other.license.or(self.license),
+            url: other.url.or(self.url),
+            download_url: other.download_url.or(self.download_url),
+            project_urls: other.project_urls.or(self.project_urls),
+            keywords: other.keywords.or(self.keywords),
+            classifiers: other.classifiers.or(self.classifiers),
+            python_requires: other.python_requires.or(self.python_requires),
+            install_requires: other.install_requires.or(self.install_requires),
+            extras_require: other.extras_require.or(self.extras_require),
+            entry_points: other.entry_points.or(self.entry_points),
+            setup_requires: other.setup_requires.or(self.setup_requires),
+            setuptools: Setuptools {
+                py_modules: other.setuptools.py_modules.or(self.setuptools.py_modules),
+                packages: other.setuptools.packages.or(self.setuptools.packages),
+                package_dir: other.setuptools.package_dir.or(self.setuptools.package_dir),
+                package_data: other
+                    .setuptools
+                    .package_data
+                    .or(self.setuptools.package_data),
+                include_package_data: other
+                    .setuptools
+                    .include_package_data
+                    .or(self.setuptools.include_package_data),
+                license_files: other
+                    .setuptools
+                    .license_files
+                    .or(self.setuptools.license_files),
+            },
+        }
+    }
+}
+
+pub fn get_authors(name: Option<String>, email: Option<String>) -> Option<Vec<AuthorOrMaintainer>> {
+    if name.is_none() && email.is_none() {
+        return None;
+    }
+    Some(vec![AuthorOrMaintainer { name, email }])
+}
+
+pub fn get_urls(
+    url: Option<String>,
+    download_url: Option<String>,
+    project_urls: Option<IndexMap<String, String>>,
+) -> Option<IndexMap<String, String>> {
+    let mut urls: IndexMap<String, String> = IndexMap::new();
+
+    if let Some(url) = url {
+        urls.insert("Homepage".to_string(), url);
+    }
+    if let Some(download_url) = download_url {
+        urls.insert("Download".to_string(), download_url);
+    }
+    if let Some(project_urls) = project_urls {
+        urls.extend(project_urls);
+    }
+
+    if urls.is_empty() {
+        return None;
+    }
+    Some(urls)
+}
+
+pub fn get_dependencies(dependencies: Option<Vec<String>>, source: &str) -> Option<Vec<String>> {
+    let mut pep_508_dependencies: Vec<String> = Vec::new();
+
+    for dependency in dependencies? {
+        if let Ok(requirement) = Requirement::<Url>::from_str(&dependency) {
+            pep_508_dependencies.push(requirement.to_string());
+        } else {
+            add_recoverable_error(format!(
+                "\"{}\" from \"{}\" is not a valid PEP 508 dependency, so it was not migrated.",
+                dependency.bold(),
+                source.bold(),
+            ));
+        }
+    }
+
+    if pep_508_dependencies.is_empty() {
+        return None;
+    }
+    Some(pep_508_dependencies)
+}
+
+pub fn get_optional_dependencies(
+    extras_require: Option<IndexMap<String, Vec<String>>>,
+) -> Option<IndexMap<String, Vec<String>>> {
+    let optional_dependencies: IndexMap<String, Vec<String>> = extras_require?
+        .into_iter()
+        .map(|(extra, dependencies)| {
+            let source = format!("extras_require.{extra}");
+            (
+                extra,
+                get_dependencies(Some(dependencies), &source).unwrap_or_default(),
+            )
+        })
+        .collect();
+
+    if optional_dependencies.is_empty() {
+        return None;
+    }
+    Some(optional_dependencies)
+}
+
+/// Split entry points defined as `name = value` strings, grouped by entry point group, into
+/// `console_scripts`, `gui_scripts` and the remaining entry point groups, as PEP 621 defines
+/// dedicated fields for the first two.
+pub fn get_scripts_and_entry_points(
+    entry_points: Option<IndexMap<String, Vec<String>>>,
+) -> ScriptsAndEntryPoints {
+    let Some(entry_points) = entry_points else {
+        return (None, None, None);
+    };
+
+    let mut groups: IndexMap<String, IndexMap<String, String>> = IndexMap::new();
+
+    for (group, definitions) in entry_points {
+        let group_entry_points = groups.entry(group).or_default();
+
+        for definition in definitions {
+            if let Some((name, value)) = definition.split_once('=') {
+                group_entry_points.insert(name.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    let scripts = groups.shift_remove("console_scripts");
+    let gui_scripts = groups.shift_remove("gui_scripts");
+
+    (
+        scripts,
+        gui_scripts,
+        if groups.is_empty() {
+            None
+        } else {
+            Some(groups)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_scripts_and_entry_points() {
+        let entry_points = IndexMap::from([
+            (
+                "console_scripts".to_string(),
+                vec!["foo = foo.cli:main".to_string(), "bar=bar:run".to_string()],
+            ),
+            (
+                "gui_scripts".to_string(),
+                vec!["foo-gui = foo.gui:main".to_string()],
+            ),
+            (
+                "pytest11".to_string(),
+                vec!["foo = foo.pytest_plugin".to_string()],
+            ),
+        ]);
+
+        let (scripts, gui_scripts, entry_points) = get_scripts_and_entry_points(Some(entry_points));
+
+        assert_eq!(
+            scripts,
+            Some(IndexMap::from([
+                ("foo".to_string(), "foo.cli:main".to_string()),
+                ("bar".to_string(), "bar:run".to_string()),
+            ]))
+        );
+        assert_eq!(
+            gui_scripts,
+            Some(IndexMap::from([(
+                "foo-gui".to_string(),
+                "foo.gui:main".to_string()
+            )]))
+        );
+        assert_eq!(
+            entry_points,
+            Some(IndexMap::from([(
+                "pytest11".to_string(),
+                IndexMap::from([("foo".to_string(), "foo.pytest_plugin".to_string())])
+            )]))
+        );
+    }
+}
@@ -0,0 +1,515 @@
+use crate::converters::setuptools::project::SetupArguments;
+use crate::errors::add_recoverable_error;
+use crate::schema::setuptools::{Find, Packages};
+use indexmap::IndexMap;
+use owo_colors::OwoColorize;
+use std::fs;
+use std::path::Path;
+
+type Sections = IndexMap<String, IndexMap<String, String>>;
+
+/// Whether the section holds `setup()` arguments
+/// (<https://setuptools.pypa.io/en/latest/userguide/declarative_config.html>), and is therefore
+/// migrated to `pyproject.toml`.
+pub fn is_migrated_section(section: &str) -> bool {
+    section == "metadata" || section == "options" || section.starts_with("options.")
+}
+
+/// Parse `setup.cfg` file into sections, following `configparser` rules used by `setuptools`,
+/// i.e. values can either be delimited by `=` or `:`, and can span over multiple lines, as long
+/// as the following lines are indented.
+pub fn parse_sections(content: &str) -> Sections {
+    let mut sections: Sections = IndexMap::new();
+    let mut current_section: Option<String> = None;
+    let mut current_key: Option<String> = None;
+
+    for line in content.lines() {
+        let trimmed_line = line.trim();
+
+        if trimmed_line.is_empty() || trimmed_line.starts_with(['#', ';']) {
+            continue;
+        }
+
+        if line.starts_with(char::is_whitespace) {
+            if let Some(section) = &current_section
+                && let Some(key) = &current_key
+                && let Some(value) = sections
+                    .get_mut(section)
+                    .and_then(|options| options.get_mut(key))
+            {
+                value.push('\n');
+                value.push_str(trimmed_line);
+            }
+            continue;
+        }
+
+        if trimmed_line.starts_with('[') && trimmed_line.ends_with(']') {
+            let section = trimmed_line[1..trimmed_line.len() - 1].trim().to_string();
+            sections.entry(section.clone()).or_default();
+            current_section = Some(section);
+            current_key = None;
+            continue;
+        }
+
+        if let Some(section) = &current_section
+            && let Some(position) = line.find(['=', ':'])
+        {
+            // `setuptools` accepts dashes in option names, but they are deprecated in favor of
+            // underscores. Only names of `setup()` arguments are normalized, as keys of other
+            // sections are user-defined (e.g. extras in `[options.extras_require]`).
+            let key = line[..position].trim();
+            let key = if section == "metadata" || section == "options" {
+                key.to_lowercase().replace('-', "_")
+            } else {
+                key.to_string()
+            };
+            let value = line[position + 1..].trim().to_string();
+
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(key.clone(), value);
+            current_key = Some(key);
+        }
+    }
+
+    for options in sections.values_mut() {
+        for value in options.values_mut() {
+            *value = value.trim().to_string();
+        }
+    }
+
+    sections
+}
+
+/// Remove sections holding `setup()` arguments from `setup.cfg`, keeping other sections (e.g.
+/// configuration of other tools) untouched.
+pub fn remove_migrated_sections(content: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut in_migrated_section = false;
+
+    for line in content.lines() {
+        let trimmed_line = line.trim();
+
+        if !line.starts_with(char::is_whitespace)
+            && trimmed_line.starts_with('[')
+            && trimmed_line.ends_with(']')
+        {
+            in_migrated_section =
+                is_migrated_section(trimmed_line[1..trimmed_line.len() - 1].trim());
+        }
+
+        if !in_migrated_section {
+            lines.push(line);
+        }
+    }
+
+    let remaining_content = lines.join("\n").trim().to_string();
+
+    if remaining_content.is_empty() {
+        return remaining_content;
+    }
+    format!("{remaining_content}\n")
+}
+
+pub fn get_setup_arguments(project_path: &Path, content: &str) -> (SetupArguments, Vec<String>) {
+    let sections = parse_sections(content);
+    let mut arguments = SetupArguments::default();
+    let mut errors: Vec<String> = Vec::new();
+
+    let mut packages_find: Option<Find> = None;
+
+    for (section, options) in &sections {
+        if !is_migrated_section(section) {
+            continue;
+        }
+
+        for (key, value) in options {
+            // `long_description` refers to a file, which is the only thing we can express in PEP
+            // 621 `readme`, so it needs to be handled before reading the file content.
+            if section == "metadata" && key == "long_description" {
+                if let Some(files) = value.strip_prefix("file:") {
+                    let files: Vec<&str> = files
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|file| !file.is_empty())
+                        .collect();
+
+                    if let [readme, dropped_files @ ..] = files.as_slice()
+                        && !dropped_files.is_empty()
+                    {
+                        errors.push(format!(
+                            "\"{}\" from \"{}\" in \"{}\" lists several files, that setuptools concatenates, while \"{}\" only accepts a single file, so only \"{}\" was migrated, and {} {} dropped.",
+                            key.bold(),
+                            format!("[{section}]").bold(),
+                            "setup.cfg".bold(),
+                            "readme".bold(),
+                            readme.bold(),
+                            dropped_files
+                                .iter()
+                                .map(|file| format!("\"{}\"", file.bold()))
+                                .collect::<Vec<String>>()
+                                .join(", "),
+                            if dropped_files.len() == 1 { "was" } else { "were" },
+                        ));
+                    }
+
+                    arguments.readme = files.first().map(ToString::to_string);
+                } else {
+                    errors.push(format_error(
+                        section,
+                        key,
+                        "is not defined with a \"file:\" directive",
+                    ));
+                }
+                continue;
+            }
+
+            let Some(value) = resolve_value(project_path, section, key, value, &mut errors) else {
+                continue;
+            };
+
+            match (section.as_str(), key.as_str()) {
+                ("metadata", "name") => arguments.name = Some(value),
+                ("metadata", "version") => arguments.version = Some(value),
+                ("metadata", "description" | "summary") => arguments.description = Some(value),
+                ("metadata", "author") => arguments.author = Some(value),
+                ("metadata", "author_email") => arguments.author_email = Some(value),
+                ("metadata", "maintainer") => arguments.maintainer = Some(value),
+                ("metadata", "maintainer_email") => arguments.maintainer_email = Some(value),
+                ("metadata", "license") => arguments.license = Some(value),
+                ("metadata", "license_file" | "license_files") => {
+                    arguments.setuptools.license_files = Some(parse_list(&value, ','));
+                }
+                ("metadata", "url" | "home_page") => arguments.url = Some(value),
+                ("metadata", "download_url") => arguments.download_url = Some(value),
+                ("metadata", "project_urls") => arguments.project_urls = Some(parse_dict(&value)),
+                ("metadata", "keywords") => arguments.keywords = Some(parse_list(&value, ',')),
+                ("metadata", "classifiers" | "classifier") => {
+                    arguments.classifiers = Some(parse_list(&value, ','));
+                }
+                ("metadata", "platforms" | "platform") => {
+                    add_recoverable_error(format!(
+                        "\"{}\" from \"{}\" in \"{}\" has no equivalent in PEP 621, so it was not migrated.",
+                        key.bold(),
+                        format!("[{section}]").bold(),
+                        "setup.cfg".bold(),
+                    ));
+                }
+                // `zip_safe` is obsolete, and content type of the long description is inferred
+                // from the extension of the readme file.
+                ("metadata", "long_description_content_type") | ("options", "zip_safe") => (),
+                ("options", "python_requires") => arguments.python_requires = Some(value),
+                ("options", "install_requires") => {
+                    arguments.install_requires = Some(parse_list(&value, ';'));
+                }
+                ("options", "setup_requires") => {
+                    arguments.setup_requires = Some(parse_list(&value, ';'));
+                }
+                ("options", "packages") => match value.as_str() {
+                    "find:" => {
+                        packages_find.get_or_insert_default().namespaces = Some(false);
+                    }
+                    "find_namespace:" => {
+                        packages_find.get_or_insert_default();
+                    }
+                    _ => {
+                        arguments.setuptools.packages =
+                            Some(Packages::Vec(parse_list(&value, ',')));
+                    }
+                },
+                ("options", "package_dir") => {
+                    arguments.setuptools.package_dir = Some(parse_dict(&value));
+                }
+                ("options", "py_modules") => {
+                    arguments.setuptools.py_modules = Some(parse_list(&value, ','));
+                }
+                ("options", "include_package_data") => {
+                    arguments.setuptools.include_package_data = Some(parse_bool(&value));
+                }
+                ("options.extras_require", extra) => {
+                    arguments
+                        .extras_require
+                        .get_or_insert_default()
+                        .insert(extra.to_string(), parse_list(&value, ';'));
+                }
+                ("options.entry_points", group) => {
+                    arguments
+                        .entry_points
+                        .get_or_insert_default()
+                        .insert(group.to_string(), parse_list(&value, ';'));
+                }
+                ("options.package_data", package) => {
+                    arguments
+                        .setuptools
+                        .package_data
+                        .get_or_insert_default()
+                        .insert(package.to_string(), parse_list(&value, ','));
+                }
+                ("options.packages.find", "where") => {
+                    packages_find.get_or_insert_default().where_ = Some(parse_list(&value, ','));
+                }
+                ("options.packages.find", "include") => {
+                    packages_find.get_or_insert_default().include = Some(parse_list(&value, ','));
+                }
+                ("options.packages.find", "exclude") => {
+                    packages_find.get_or_insert_default().exclude = Some(parse_list(&value, ','));
+                }
+                _ => errors.push(format_error(section, key, "is not supported")),
+            }
+        }
+    }
+
+    if let Some(find) = packages_find {
+        arguments.setuptools.packages = Some(Packages::Find { find });
+    }
+
+    (arguments, errors)
+}
+
+/// Resolve `file:` and `attr:` directives
+/// (<https://setuptools.pypa.io/en/latest/userguide/declarative_config.html#specifying-values>).
+/// `attr:` requires importing the project, so it cannot be statically resolved.
+fn resolve_value(
+    project_path: &Path,
+    section: &str,
+    key: &str,
+    value: &str,
+    errors: &mut Vec<String>,
+) -> Option<String> {
+    if value.starts_with("attr:") {
+        errors.push(format_error(
+            section,
+            key,
+            "uses an \"attr:\" directive, which cannot be statically resolved",
+        ));
+        return None;
+    }
+
+    let Some(files) = value.strip_prefix("file:") else {
+        return Some(value.to_string());
+    };
+
+    let mut content = String::new();
+
+    for file in files.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+        let Ok(file_content) = fs::read_to_string(project_path.join(file)) else {
+            errors.push(format_error(
+                section,
+                key,
+                &format!(
+                    "refers to \"{}\" file, which could not be read",
+                    file.bold()
+                ),
+            ));
+            return None;
+        };
+        content.push_str(&file_content);
+    }
+
+    Some(content.trim().to_string())
+}
+
+fn format_error(section: &str, key: &str, reason: &str) -> String {
+    format!(
+        "\"{}\" from \"{}\" in \"{}\" {reason}, so it could not be migrated.",
+        key.bold(),
+        format!("[{section}]").bold(),
+        "setup.cfg".bold(),
+    )
+}
+
+/// Parse a list, which can either be defined on multiple lines, or on a single line using a
+/// separator.
+fn parse_list(value: &str, separator: char) -> Vec<String> {
+    let items: Vec<&str> = if value.contains('\n') {
+        value.lines().collect()
+    } else {
+        value.split(separator).collect()
+    };
+
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn parse_dict(value: &str) -> IndexMap<String, String> {
+    parse_list(value, ',')
+        .iter()
+        .filter_map(|item| item.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
+
+fn parse_bool(value: &str) -> bool {
+    matches!(value.to_lowercase().as_str(), "1" | "yes" | "true" | "on")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sections() {
+        let content = r"
+[metadata]
+name = foo
+Description: A description
+# A comment
+keywords = foo, bar
+
+[options]
+install_requires =
+    arrow>=1.2.3
+    ; Another comment
+    requests[security]>=2.31 ; python_version >= '3.10'
+package_dir =
+    =src
+";
+
+        assert_eq!(
+            parse_sections(content),
+            IndexMap::from([
+                (
+                    "metadata".to_string(),
+                    IndexMap::from([
+                        ("name".to_string(), "foo".to_string()),
+                        ("description".to_string(), "A description".to_string()),
+                        ("keywords".to_string(), "foo, bar".to_string()),
+                    ])
+                ),
+                (
+                    "options".to_string(),
+                    IndexMap::from([
+                        (
+                            "install_requires".to_string(),
+                            "arrow>=1.2.3\nrequests[security]>=2.31 ; python_version >= '3.10'"
+                                .to_string()
+                        ),
+                        ("package_dir".to_string(), "=src".to_string()),
+                    ])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_remove_migrated_sections() {
+        let content = r"[metadata]
+name = foo
+
+[options]
+install_requires =
+    arrow>=1.2.3
+
+[options.packages.find]
+where = src
+
+[flake8]
+max-line-length = 120
+
+[tool:pytest]
+addopts = -v
+";
+
+        insta::assert_snapshot!(remove_migrated_sections(content), @r"
+        [flake8]
+        max-line-length = 120
+
+        [tool:pytest]
+        addopts = -v
+        ");
+    }
+
+    #[test]
+    fn test_remove_migrated_sections_only() {
+        let content = r"[metadata]
+name = foo
+
+[options]
+install_requires =
+    arrow>=1.2.3
+";
+
+        assert_eq!(remove_migrated_sections(content), String::new());
+    }
+
+    #[test]
+    fn test_get_setup_arguments_long_description_multiple_files() {
+        let content = r"[metadata]
+name = foo
+long_description = file: README.md, CHANGELOG.md,
+    AUTHORS.md
+";
+
+        let (arguments, errors) = get_setup_arguments(Path::new("."), content);
+
+        assert_eq!(arguments.readme, Some("README.md".to_string()));
+        assert_eq!(
+            errors,
+            vec![format!(
+                "\"{}\" from \"{}\" in \"{}\" lists several files, that setuptools concatenates, while \"{}\" only accepts a single file, so only \"{}\" was migrated, and \"{}\", \"{}\" were dropped.",
+                "long_description".bold(),
+                "[metadata]".bold(),
+                "setup.cfg".bold(),
+                "readme".bold(),
+                "README.md".bold(),
+                "CHANGELOG.md".bold(),
+                "AUTHORS.md".bold(),
+            )]
+        );
+    }
+
+    #[test]
+    fn test_get_setup_arguments_keeps_user_defined_keys() {
+        let content = r"[metadata]
+Name = foo
+long-description = file: README.md
+
+[options.extras_require]
+dev-tools = pytest>=8
+
+[options.package_data]
+Foo-Bar = py.typed
+";
+
+        let (arguments, errors) = get_setup_arguments(Path::new("."), content);
+
+        assert_eq!(arguments.name, Some("foo".to_string()));
+        assert_eq!(arguments.readme, Some("README.md".to_string()));
+        assert_eq!(
+            arguments.extras_require,
+            Some(IndexMap::from([(
+                "dev-tools".to_string(),
+                vec!["pytest>=8".to_string()]
+            )]))
+        );
+        assert_eq!(
+            arguments
+                .setuptools
+                .package_data
+                .as_ref()
+                .map(|package_data| package_data.keys().collect::<Vec<&String>>()),
+            Some(vec![&"Foo-Bar".to_string()])
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            parse_list("foo, bar,baz", ','),
+            vec!["foo".to_string(), "bar".to_string(), "baz".to_string()]
+        );
+        assert_eq!(
+            parse_list("\nfoo>=1 ; python_version < '3.11'\nbar", ';'),
+            vec![
+                "foo>=1 ; python_version < '3.11'".to_string(),
+                "bar".to_string()
+            ]
+        );
+    }
+}
@@ -0,0 +1,790 @@
+use crate::converters::setuptools::project::SetupArguments;
+use crate::schema::setuptools::{Find, Packages};
+use indexmap::IndexMap;
+use owo_colors::OwoColorize;
+
+/// Python literal values that can be statically extracted from `setup.py`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Literal {
+    String(String),
+    Bool(bool),
+    None,
+    List(Vec<Literal>),
+    Dict(Vec<(Literal, Literal)>),
+    /// Call to a function with literal arguments, only used to support package discovery
+    /// functions (e.g. `find_packages(where="src")`).
+    Call {
+        name: String,
+        args: Vec<Literal>,
+        kwargs: IndexMap<String, Literal>,
+    },
+}
+
+/// Extract arguments from the `setup()` call in `setup.py`, as long as they are defined with
+/// literal values. Any argument that cannot be statically extracted (e.g. a value computed when
+/// running `setup.py`) is reported as an error.
+pub fn get_setup_arguments(content: &str) -> (SetupArguments, Vec<String>) {
+    let mut arguments = SetupArguments::default();
+    let mut errors: Vec<String> = Vec::new();
+
+    let mut parser = Parser::new(content);
+
+    if !parser.find_setup_call() {
+        errors.push(format!(
+            "Could not find a call to \"{}\" in \"{}\".",
+            "setup()".bold(),
+            "setup.py".bold(),
+        ));
+        return (arguments, errors);
+    }
+
+    let (keyword_arguments, has_non_keyword_arguments) = parser.parse_call_arguments();
+
+    if has_non_keyword_arguments {
+        errors.push(format!(
+            "\"{}\" in \"{}\" is called with positional or unpacked arguments, which cannot be statically extracted, so they could not be migrated.",
+            "setup()".bold(),
+            "setup.py".bold(),
+        ));
+    }
+
+    for (key, value) in keyword_arguments {
+        let Some(value) = value else {
+            errors.push(format_error(
+                &key,
+                "is not a literal value, so it could not be statically extracted",
+            ));
+            continue;
+        };
+
+        if set_argument(&mut arguments, &key, value).is_err() {
+            errors.push(format_error(
+                &key,
+                "is not supported, so it could not be migrated",
+            ));
+        }
+    }
+
+    (arguments, errors)
+}
+
+fn format_error(key: &str, reason: &str) -> String {
+    format!(
+        "\"{}\" argument of \"{}\" in \"{}\" {reason}.",
+        key.bold(),
+        "setup()".bold(),
+        "setup.py".bold(),
+    )
+}
+
+fn set_argument(arguments: &mut SetupArguments, key: &str, value: Literal) -> Result<(), ()> {
+    match key {
+        "name" => arguments.name = Some(to_string(value)?),
+        "version" => arguments.version = Some(to_string(value)?),
+        "description" => arguments.description = Some(to_string(value)?),
+        "author" => arguments.author = Some(to_string(value)?),
+        "author_email" => arguments.author_email = Some(to_string(value)?),
+        "maintainer" => arguments.maintainer = Some(to_string(value)?),
+        "maintainer_email" => arguments.maintainer_email = Some(to_string(value)?),
+        "license" => arguments.license = Some(to_string(value)?),
+        "license_files" => arguments.setuptools.license_files = Some(to_list(value)?),
+        "url" => arguments.url = Some(to_string(value)?),
+        "download_url" => arguments.download_url = Some(to_string(value)?),
+        "project_urls" => {
+            arguments.project_urls = Some(
+                to_dict(value)?
+                    .into_iter()
+                    .map(|(name, url)| Ok((name, to_string(url)?)))
+                    .collect::<Result<_, ()>>()?,
+            );
+        }
+        "keywords" => {
+            arguments.keywords = Some(match value {
+                Literal::String(keywords) => keywords
+                    .split([',', ' '])
+                    .filter(|keyword| !keyword.is_empty())
+                    .map(ToString::to_string)
+                    .collect(),
+                _ => to_list(value)?,
+            });
+        }
+        "classifiers" => arguments.classifiers = Some(to_list(value)?),
+        "python_requires" => arguments.python_requires = Some(to_string(value)?),
+        "install_requires" => arguments.install_requires = Some(to_list(value)?),
+        "setup_requires" => arguments.setup_requires = Some(to_list(value)?),
+        "extras_require" => {
+            arguments.extras_require = Some(
+                to_dict(value)?
+                    .into_iter()
+                    .map(|(extra, dependencies)| Ok((extra, to_list(dependencies)?)))
+                    .collect::<Result<_, ()>>()?,
+            );
+        }
+        "entry_points" => {
+            arguments.entry_points = Some(
+                to_dict(value)?
+                    .into_iter()
+                    .map(|(group, entry_points)| Ok((group, to_list(entry_points)?)))
+                    .collect::<Result<_, ()>>()?,
+            );
+        }
+        "packages" => {
+            arguments.setuptools.packages = Some(match value {
+                Literal::Call { name, args, kwargs } => to_packages_find(&name, args, kwargs)?,
+                _ => Packages::Vec(to_list(value)?),
+            });
+        }
+        "package_dir" => {
+            arguments.setuptools.package_dir = Some(
+                to_dict(value)?
+                    .into_iter()
+                    .map(|(package, directory)| Ok((package, to_string(directory)?)))
+                    .collect::<Result<_, ()>>()?,
+            );
+        }
+        "package_data" => {
+            arguments.setuptools.package_data = Some(
+                to_dict(value)?
+                    .into_iter()
+                    .map(|(package, patterns)| Ok((package, to_list(patterns)?)))
+                    .collect::<Result<_, ()>>()?,
+            );
+        }
+        "py_modules" => arguments.setuptools.py_modules = Some(to_list(value)?),
+        "include_package_data" => {
+            let Literal::Bool(include_package_data) = value else {
+                return Err(());
+            };
+            arguments.setuptools.include_package_data = Some(include_package_data);
+        }
+        // `zip_safe` is obsolete, and content type of the long description is inferred from the
+        // extension of the readme file.
+        "zip_safe" | "long_description_content_type" => (),
+        _ => return Err(()),
+    }
+
+    Ok(())
+}
+
+fn to_string(value: Literal) -> Result<String, ()> {
+    match value {
+        Literal::String(value) => Ok(value),
+        _ => Err(()),
+    }
+}
+
+/// Convert a list of strings, or a single string (as `setuptools` accepts a string with one
+/// item per line for arguments expecting a list).
+fn to_list(value: Literal) -> Result<Vec<String>, ()> {
+    match value {
+        Literal::String(value) => Ok(value
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(ToString::to_string)
+            .collect()),
+        Literal::List(items) => items.into_iter().map(to_string).collect(),
+        _ => Err(()),
+    }
+}
+
+fn to_dict(value: Literal) -> Result<IndexMap<String, Literal>, ()> {
+    let Literal::Dict(items) = value else {
+        return Err(());
+    };
+
+    items
+        .into_iter()
+        .map(|(key, value)| Ok((to_string(key)?, value)))
+        .collect()
+}
+
+fn to_packages_find(
+    name: &str,
+    args: Vec<Literal>,
+    mut kwargs: IndexMap<String, Literal>,
+) -> Result<Packages, ()> {
+    let namespaces = match name.rsplit('.').next() {
+        Some("find_packages") => Some(false),
+        Some("find_namespace_packages") => None,
+        _ => return Err(()),
+    };
+
+    // Arguments of `find_packages` are `where`, `exclude` and `include`, in that order.
+    for (arg, key) in args.into_iter().zip(["where", "exclude", "include"]) {
+        kwargs.insert(key.to_string(), arg);
+    }
+
+    let mut find = Find {
+        namespaces,
+        ..Default::default()
+    };
+
+    for (key, value) in kwargs {
+        match key.as_str() {
+            "where" => find.where_ = Some(vec![to_string(value)?]),
+            "exclude" => find.exclude = Some(to_list(value)?),
+            "include" => find.include = Some(to_list(value)?),
+            _ => return Err(()),
+        }
+    }
+
+    Ok(Packages::Find { find })
+}
+
+/// Minimal parser for Python source code, that only understands what is needed to extract
+/// literal arguments passed to `setup()`.
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn new(content: &str) -> Self {
+        Self {
+            chars: content.chars().collect(),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    /// Skip whitespaces, line continuations and comments.
+    fn skip_whitespaces(&mut self) {
+        while let Some(char) = self.peek() {
+            if char.is_whitespace() || char == '\\' {
+                self.position += 1;
+            } else if char == '#' {
+                while let Some(char) = self.peek()
+                    && char != '\n'
+                {
+                    self.position += 1;
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn is_string_start(&self) -> bool {
+        let mut offset = 0;
+
+        while let Some(char) = self.peek_at(offset)
+            && offset < 2
+            && "rRbBuUfF".contains(char)
+        {
+            offset += 1;
+        }
+
+        matches!(self.peek_at(offset), Some('"' | '\''))
+    }
+
+    fn parse_identifier(&mut self) -> Option<String> {
+        let start = self.position;
+
+        while let Some(char) = self.peek()
+            && (char.is_alphanumeric() || char == '_')
+        {
+            self.position += 1;
+        }
+
+        if start == self.position {
+            return None;
+        }
+        Some(self.chars[start..self.position].iter().collect())
+    }
+
+    /// Parse a dotted name, e.g. `setuptools.setup`.
+    fn parse_name(&mut self) -> Option<String> {
+        let mut name = self.parse_identifier()?;
+
+        while self.peek() == Some('.') {
+            self.position += 1;
+            name.push('.');
+            name.push_str(&self.parse_identifier()?);
+        }
+
+        Some(name)
+    }
+
+    /// Parse a single string literal, returning `None` for f-strings, which cannot be
+    /// statically evaluated.
+    fn parse_string(&mut self) -> Option<String> {
+        let mut prefix = String::new();
+
+        while let Some(char) = self.peek()
+            && char != '"'
+            && char != '\''
+        {
+            prefix.push(char.to_ascii_lowercase());
+            self.position += 1;
+        }
+
+        let quote = self.peek()?;
+        let is_triple_quoted = self.peek_at(1) == Some(quote) && self.peek_at(2) == Some(quote);
+        let is_raw = prefix.contains('r');
+        let is_formatted = prefix.contains('f');
+
+        self.position += if is_triple_quoted { 3 } else { 1 };
+
+        let mut value = String::new();
+
+        loop {
+            let char = self.peek()?;
+
+            if char == quote
+                && (!is_triple_quoted
+                    || (self.peek_at(1) == Some(quote) && self.peek_at(2) == Some(quote)))
+            {
+                self.position += if is_triple_quoted { 3 } else { 1 };
+                break;
+            }
+
+            if char == '\\' {
+                let escaped = self.peek_at(1)?;
+                self.position += 2;
+
+                if is_raw {
+                    value.push(char);
+                    value.push(escaped);
+                    continue;
+                }
+
+                match escaped {
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    '\n' => (),
+                    '\\' | '\'' | '"' => value.push(escaped),
+                    _ => {
+                        value.push(char);
+                        value.push(escaped);
+                    }
+                }
+                continue;
+            }
+
+            value.push(char);
+            self.position += 1;
+        }
+
+        if is_formatted {
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Skip a string literal, without caring about its value.
+    fn skip_string(&mut self) {
+        let start = self.position;
+
+        if self.parse_string().is_none() && self.position == start {
+            self.position += 1;
+        }
+    }
+
+    /// Find the call to `setup()`, and move right after its opening parenthesis.
+    fn find_setup_call(&mut self) -> bool {
+        let mut previous_identifier: Option<String> = None;
+
+        while let Some(char) = self.peek() {
+            if char == '#' || char.is_whitespace() {
+                self.skip_whitespaces();
+            } else if self.is_string_start() {
+                self.skip_string();
+                previous_identifier = None;
+            } else if char.is_alphabetic() || char == '_' {
+                let Some(name) = self.parse_name() else {
+                    self.position += 1;
+                    continue;
+                };
+                let is_function_definition = previous_identifier.as_deref() == Some("def");
+
+                self.skip_whitespaces();
+
+                if !is_function_definition
+                    && name.rsplit('.').next() == Some("setup")
+                    && self.peek() == Some('(')
+                {
+                    self.position += 1;
+                    return true;
+                }
+
+                previous_identifier = Some(name);
+            } else {
+                self.position += 1;
+                previous_identifier = None;
+            }
+        }
+
+        false
+    }
+
+    /// Parse arguments of a call, from right after the opening parenthesis to right after the
+    /// closing one. Keyword arguments that do not hold a literal value are returned with a `None`
+    /// value. Also returns whether positional or unpacked arguments were found.
+    fn parse_call_arguments(&mut self) -> (IndexMap<String, Option<Literal>>, bool) {
+        let mut keyword_arguments: IndexMap<String, Option<Literal>> = IndexMap::new();
+        let mut has_non_keyword_arguments = false;
+
+        loop {
+            self.skip_whitespaces();
+
+            match self.peek() {
+                None => break,
+                Some(')') => {
+                    self.position += 1;
+                    break;
+                }
+                Some(',') => {
+                    self.position += 1;
+                    continue;
+                }
+                _ => (),
+            }
+
+            let start = self.position;
+
+            if let Some(key) = self.parse_identifier() {
+                self.skip_whitespaces();
+
+                if self.peek() == Some('=') && self.peek_at(1) != Some('=') {
+                    self.position += 1;
+                    keyword_arguments.insert(key, self.parse_argument_value());
+                    continue;
+                }
+            }
+
+            self.position = start;
+            has_non_keyword_arguments = true;
+            self.skip_expression();
+        }
+
+        (keyword_arguments, has_non_keyword_arguments)
+    }
+
+    /// Parse the value of an argument, which is only considered literal if the whole expression
+    /// (up to the next argument) is a literal.
+    fn parse_argument_value(&mut self) -> Option<Literal> {
+        let start = self.position;
+
+        if let Some(value) = self.parse_literal() {
+            self.skip_whitespaces();
+
+            if matches!(self.peek(), Some(',' | ')')) {
+                return Some(value);
+            }
+        }
+
+        self.position = start;
+        self.skip_expression();
+        None
+    }
+
+    /// Skip an expression, up to the next `,` or `)` that is not nested in another expression.
+    fn skip_expression(&mut self) {
+        let mut depth: usize = 0;
+
+        while let Some(char) = self.peek() {
+            if self.is_string_start() {
+                self.skip_string();
+                continue;
+            }
+
+            match char {
+                '#' => {
+                    self.skip_whitespaces();
+                    continue;
+                }
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth > 0 => depth -= 1,
+                ',' | ')' if depth == 0 => return,
+                _ => (),
+            }
+            self.position += 1;
+        }
+    }
+
+    fn parse_literal(&mut self) -> Option<Literal> {
+        self.skip_whitespaces();
+
+        if self.is_string_start() {
+            let mut value = self.parse_string()?;
+
+            // Adjacent strings are implicitly concatenated.
+            loop {
+                let position = self.position;
+                self.skip_whitespaces();
+
+                if !self.is_string_start() {
+                    self.position = position;
+                    break;
+                }
+                value.push_str(&self.parse_string()?);
+            }
+
+            return Some(Literal::String(value));
+        }
+
+        match self.peek()? {
+            '[' => {
+                self.position += 1;
+                let (items, _) = self.parse_sequence(']')?;
+                Some(Literal::List(items))
+            }
+            '(' => {
+                self.position += 1;
+                let (mut items, has_trailing_comma) = self.parse_sequence(')')?;
+
+                // Parentheses around a single value without a trailing comma only group an
+                // expression, and do not define a tuple.
+                if items.len() == 1 && !has_trailing_comma {
+                    return items.pop();
+                }
+                Some(Literal::List(items))
+            }
+            '{' => {
+                self.position += 1;
+                self.parse_dict()
+            }
+            char if char.is_alphabetic() || char == '_' => {
+                let name = self.parse_name()?;
+
+                match name.as_str() {
+                    "True" => Some(Literal::Bool(true)),
+                    "False" => Some(Literal::Bool(false)),
+                    "None" => Some(Literal::None),
+                    _ => {
+                        self.skip_whitespaces();
+
+                        if self.peek() != Some('(') {
+                            return None;
+                        }
+                        self.position += 1;
+
+                        let mut args: Vec<Literal> = Vec::new();
+                        let mut kwargs: IndexMap<String, Literal> = IndexMap::new();
+
+                        loop {
+                            self.skip_whitespaces();
+
+                            match self.peek()? {
+                                ')' => {
+                                    self.position += 1;
+                                    break;
+                                }
+                                ',' => {
+                                    self.position += 1;
+                                    continue;
+                                }
+                                _ => (),
+                            }
+
+                            let start = self.position;
+
+                            if let Some(key) = self.parse_identifier() {
+                                self.skip_whitespaces();
+
+                                if self.peek() == Some('=') && self.peek_at(1) != Some('=') {
+                                    self.position += 1;
+                                    kwargs.insert(key, self.parse_literal()?);
+                                    continue;
+                                }
+                            }
+
+                            self.position = start;
+                            args.push(self.parse_literal()?);
+                        }
+
+                        Some(Literal::Call { name, args, kwargs })
+                    }
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Parse items of a list or tuple, from right after the opening bracket to right after the
+    /// closing one. Also returns whether the last item is followed by a trailing comma.
+    fn parse_sequence(&mut self, closing_char: char) -> Option<(Vec<Literal>, bool)> {
+        let mut items: Vec<Literal> = Vec::new();
+        let mut has_trailing_comma = false;
+
+        loop {
+            self.skip_whitespaces();
+
+            if self.peek()? == closing_char {
+                self.position += 1;
+                return Some((items, has_trailing_comma));
+            }
+
+            items.push(self.parse_literal()?);
+            self.skip_whitespaces();
+
+            has_trailing_comma = self.peek()? == ',';
+            if has_trailing_comma {
+                self.position += 1;
+            } else if self.peek()? != closing_char {
+                return None;
+            }
+        }
+    }
+
+    /// Parse items of a dictionary, from right after the opening brace to right after the
+    /// closing one.
+    fn parse_dict(&mut self) -> Option<Literal> {
+        let mut items: Vec<(Literal, Literal)> = Vec::new();
+
+        loop {
+            self.skip_whitespaces();
+
+            if self.peek()? == '}' {
+                self.position += 1;
+                return Some(Literal::Dict(items));
+            }
+
+            let key = self.parse_literal()?;
+            self.skip_whitespaces();
+
+            if self.peek()? != ':' {
+                return None;
+            }
+            self.position += 1;
+
+            items.push((key, self.parse_literal()?));
+            self.skip_whitespaces();
+
+            if self.peek()? == ',' {
+                self.position += 1;
+            } else if self.peek()? != '}' {
+                return None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::setuptools::Setuptools;
+
+    #[test]
+    fn test_get_setup_arguments() {
+        let content = r#"
+import setuptools
+from setuptools import find_packages
+
+# setup(name="commented")
+
+setuptools.setup(
+    name="foo",
+    version='1.2.3',
+    description=(
+        "A long "
+        "description"
+    ),
+    author="John Doe",
+    keywords="foo bar",
+    python_requires=">=3.9",
+    install_requires=[
+        "arrow>=1.2.3",  # A comment
+        'requests[security]>=2.31 ; python_version >= "3.10"',
+    ],
+    extras_require={"test": ["pytest>=8"], "docs": ("mkdocs",)},
+    entry_points={
+        "console_scripts": ["foo = foo.cli:main"],
+    },
+    packages=find_packages("src", exclude=["tests"]),
+    package_dir={"": "src"},
+    include_package_data=True,
+    zip_safe=False,
+)
+"#;
+
+        assert_eq!(
+            get_setup_arguments(content),
+            (
+                SetupArguments {
+                    name: Some("foo".to_string()),
+                    version: Some("1.2.3".to_string()),
+                    description: Some("A long description".to_string()),
+                    author: Some("John Doe".to_string()),
+                    keywords: Some(vec!["foo".to_string(), "bar".to_string()]),
+                    python_requires: Some(">=3.9".to_string()),
+                    install_requires: Some(vec![
+                        "arrow>=1.2.3".to_string(),
+                        "requests[security]>=2.31 ; python_version >= \"3.10\"".to_string(),
+                    ]),
+                    extras_require: Some(IndexMap::from([
+                        ("test".to_string(), vec!["pytest>=8".to_string()]),
+                        ("docs".to_string(), vec!["mkdocs".to_string()]),
+                    ])),
+                    entry_points: Some(IndexMap::from([(
+                        "console_scripts".to_string(),
+                        vec!["foo = foo.cli:main".to_string()],
+                    )])),
+                    setuptools: Setuptools {
+                        packages: Some(Packages::Find {
+                            find: Find {
+                                where_: Some(vec!["src".to_string()]),
+                                exclude: Some(vec!["tests".to_string()]),
+                                namespaces: Some(false),
+                                ..Default::default()
+                            }
+                        }),
+                        package_dir: Some(IndexMap::from([(String::new(), "src".to_string())])),
+                        include_package_data: Some(true),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Vec::new()
+            )
+        );
+    }
+
+    #[test]
+    fn test_get_setup_arguments_non_literal() {
+        let content = r#"
+from setuptools import setup
+
+with open("README.md") as f:
+    long_description = f.read()
+
+def get_version():
+    return "1.2.3"
+
+setup(
+    name="foo",
+    version=get_version(),
+    long_description=long_description,
+    install_requires=["arrow"] + EXTRA_REQUIREMENTS,
+    cmdclass={"build_py": BuildPy},
+    **kwargs
+)
+"#;
+
+        let (arguments, errors) = get_setup_arguments(content);
+
+        assert_eq!(
+            arguments,
+            SetupArguments {
+                name: Some("foo".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(errors.len(), 5);
+    }
+
+    #[test]
+    fn test_get_setup_arguments_no_setup_call() {
+        let (_, errors) = get_setup_arguments("def setup():\n    pass\n");
+
+        assert_eq!(errors.len(), 1);
+    }
+}
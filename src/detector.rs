@@ -1,6 +1,7 @@
 use crate::converters;
 use crate::converters::{Converter, ConverterOptions};
 use crate::schema::pyproject::PyProject;
+use indexmap::IndexMap;
 use log::debug;
 use owo_colors::OwoColorize;
 use std::fmt::Display;
@@ -10,10 +11,14 @@ use std::path::Path;
 /// Lists the package managers supported for the migration.
 #[derive(clap::ValueEnum, Clone, Debug, Eq, PartialEq)]
 pub enum PackageManager {
+    Conda,
+    Hatch,
+    Pdm,
     Pip,
     PipTools,
     Pipenv,
     Poetry,
+    Setuptools,
 }
 
 /// Check if the projects already uses uv, and return the reason for it if we find that it does.
@@ -59,6 +64,7 @@ impl PackageManager {
         converter_options: &ConverterOptions,
         requirements_files: Vec<String>,
         dev_requirements_files: Vec<String>,
+        requirements_groups: Vec<(String, String)>,
     ) -> Result<Box<dyn Converter>, String> {
         let project_path = &converter_options.project_path;
 
@@ -97,6 +103,88 @@ impl PackageManager {
                     converter_options: converter_options.clone(),
                 }))
             }
+            Self::Pdm => {
+                let project_file = "pyproject.toml";
+
+                let pyproject_toml_path = project_path.join(project_file);
+
+                if !pyproject_toml_path.exists() {
+                    return Err(format!(
+                        "Directory does not contain a {} file.",
+                        project_file.bold()
+                    ));
+                }
+
+                let pyproject_toml_content = fs::read_to_string(pyproject_toml_path).unwrap();
+                let pyproject_toml: PyProject =
+                    toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+                if pyproject_toml.tool.is_none_or(|tool| tool.pdm.is_none())
+                    && !project_path.join("pdm.lock").exists()
+                {
+                    return Err(format!(
+                        "{} does not contain a {} section nor a {} file.",
+                        project_file.bold(),
+                        "[tool.pdm]".bold(),
+                        "pdm.lock".bold(),
+                    ));
+                }
+
+                debug!("{self} detected as a package manager.");
+                Ok(Box::new(converters::pdm::Pdm {
+                    converter_options: converter_options.clone(),
+                }))
+            }
+            Self::Hatch => {
+                let project_file = "pyproject.toml";
+
+                let pyproject_toml_path = project_path.join(project_file);
+
+                if !pyproject_toml_path.exists() {
+                    return Err(format!(
+                        "Directory does not contain a {} file.",
+                        project_file.bold()
+                    ));
+                }
+
+                let pyproject_toml_content = fs::read_to_string(pyproject_toml_path).unwrap();
+                let pyproject_toml: PyProject =
+                    toml::from_str(pyproject_toml_content.as_str()).unwrap();
+
+                if pyproject_toml
+                    .tool
+                    .and_then(|tool| tool.hatch)
+                    .is_none_or(|hatch| hatch.envs.is_none())
+                {
+                    return Err(format!(
+                        "{} does not contain a {} section.",
+                        project_file.bold(),
+                        "[tool.hatch.envs]".bold(),
+                    ));
+                }
+
+                debug!("{self} detected as a package manager.");
+                Ok(Box::new(converters::hatch::Hatch {
+                    converter_options: converter_options.clone(),
+                }))
+            }
+            Self::Conda => {
+                let Some(environment_file) = ["environment.yml", "environment.yaml"]
+                    .into_iter()
+                    .find(|file| project_path.join(file).exists())
+                else {
+                    return Err(format!(
+                        "Directory does not contain an {} file.",
+                        "environment.yml".bold()
+                    ));
+                };
+
+                debug!("{self} detected as a package manager.");
+                Ok(Box::new(converters::conda::Conda {
+                    converter_options: converter_options.clone(),
+                    environment_file: environment_file.to_string(),
+                }))
+            }
             Self::Pipenv => {
                 let project_file = "Pipfile";
 
@@ -128,7 +216,19 @@ impl PackageManager {
                     }
                 }
 
-                if found_requirements_files.is_empty() && found_dev_requirements_files.is_empty() {
+                let found_requirements_groups = get_requirements_groups(
+                    project_path,
+                    requirements_groups
+                        .into_iter()
+                        .map(|(group, file)| (group, file.replace(".txt", ".in"))),
+                    "in",
+                    &[&found_requirements_files, &found_dev_requirements_files],
+                );
+
+                if found_requirements_files.is_empty()
+                    && found_dev_requirements_files.is_empty()
+                    && found_requirements_groups.is_empty()
+                {
                     return Err(
                         "Directory does not contain any pip-tools requirements file.".to_string(),
                     );
@@ -139,6 +239,7 @@ impl PackageManager {
                     converter_options: converter_options.clone(),
                     requirements_files: found_requirements_files,
                     dev_requirements_files: found_dev_requirements_files,
+                    requirements_groups: found_requirements_groups,
                     is_pip_tools: true,
                 }))
             }
@@ -158,7 +259,17 @@ impl PackageManager {
                     }
                 }
 
-                if found_requirements_files.is_empty() && found_dev_requirements_files.is_empty() {
+                let found_requirements_groups = get_requirements_groups(
+                    project_path,
+                    requirements_groups.into_iter(),
+                    "txt",
+                    &[&found_requirements_files, &found_dev_requirements_files],
+                );
+
+                if found_requirements_files.is_empty()
+                    && found_dev_requirements_files.is_empty()
+                    && found_requirements_groups.is_empty()
+                {
                     return Err("Directory does not contain any pip requirements file.".to_string());
                 }
 
@@ -167,9 +278,36 @@ impl PackageManager {
                     converter_options: converter_options.clone(),
                     requirements_files: found_requirements_files,
                     dev_requirements_files: found_dev_requirements_files,
+                    requirements_groups: found_requirements_groups,
                     is_pip_tools: false,
                 }))
             }
+            Self::Setuptools => {
+                let has_setup_py = project_path.join("setup.py").exists();
+                let has_setup_cfg_arguments = fs::read_to_string(project_path.join("setup.cfg"))
+                    .is_ok_and(|content| {
+                        converters::setuptools::setup_cfg::parse_sections(&content)
+                            .keys()
+                            .any(|section| {
+                                converters::setuptools::setup_cfg::is_migrated_section(section)
+                            })
+                    });
+
+                if !has_setup_py && !has_setup_cfg_arguments {
+                    return Err(format!(
+                        "Directory does not contain a {} file nor a {} file with {} or {} sections.",
+                        "setup.py".bold(),
+                        "setup.cfg".bold(),
+                        "[metadata]".bold(),
+                        "[options]".bold(),
+                    ));
+                }
+
+                debug!("{self} detected as a package manager.");
+                Ok(Box::new(converters::setuptools::Setuptools {
+                    converter_options: converter_options.clone(),
+                }))
+            }
         }
     }
 }
@@ -177,20 +315,124 @@ impl PackageManager {
 impl Display for PackageManager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Self::Conda => write!(f, "conda"),
+            Self::Hatch => write!(f, "Hatch"),
+            Self::Pdm => write!(f, "PDM"),
             Self::Pip => write!(f, "pip"),
             Self::PipTools => write!(f, "pip-tools"),
             Self::Pipenv => write!(f, "Pipenv"),
             Self::Poetry => write!(f, "Poetry"),
+            Self::Setuptools => write!(f, "setuptools"),
         }
     }
 }
 
+/// Get requirements files to migrate to dependency groups, from the ones explicitly mapped to a
+/// group, followed by the ones discovered from `requirements-<group>.<extension>` and
+/// `requirements/<group>.<extension>` files. Files that do not exist, that are already used for
+/// production or development requirements, or that are already mapped to a group are skipped.
+///
+/// Discovered files that are referenced from other requirements files (e.g. `-r base.txt` or
+/// `-c constraints.txt`) are also skipped, as they are either already migrated through the files
+/// referencing them, or hold constraints rather than dependencies.
+fn get_requirements_groups(
+    project_path: &Path,
+    requirements_groups: impl Iterator<Item = (String, String)>,
+    extension: &str,
+    excluded_files: &[&Vec<String>],
+) -> IndexMap<String, Vec<String>> {
+    let requirements_groups: Vec<(String, String)> = requirements_groups.collect();
+    let discovered_requirements_groups = discover_requirements_groups(project_path, extension);
+
+    let referenced_files = converters::pip::get_referenced_files(
+        project_path,
+        &excluded_files
+            .iter()
+            .copied()
+            .flatten()
+            .chain(requirements_groups.iter().map(|(_, file)| file))
+            .chain(discovered_requirements_groups.iter().map(|(_, file)| file))
+            .collect::<Vec<&String>>(),
+    );
+
+    let mut found_requirements_groups: IndexMap<String, Vec<String>> = IndexMap::new();
+
+    for (group, file) in requirements_groups.into_iter().chain(
+        discovered_requirements_groups
+            .into_iter()
+            .filter(|(_, file)| !referenced_files.contains(Path::new(file))),
+    ) {
+        if !project_path.join(&file).is_file()
+            || excluded_files.iter().any(|files| files.contains(&file))
+            || found_requirements_groups
+                .values()
+                .any(|files| files.contains(&file))
+        {
+            continue;
+        }
+
+        found_requirements_groups
+            .entry(group)
+            .or_default()
+            .push(file);
+    }
+
+    found_requirements_groups
+}
+
+/// Discover requirements files following `requirements-<group>.<extension>` naming at the root of
+/// the project, or `<group>.<extension>` naming in `requirements` directory.
+fn discover_requirements_groups(project_path: &Path, extension: &str) -> Vec<(String, String)> {
+    let mut requirements_groups: Vec<(String, String)> = Vec::new();
+
+    for directory in ["", "requirements"] {
+        let Ok(entries) = fs::read_dir(project_path.join(directory)) else {
+            continue;
+        };
+
+        // Directory entries are returned in an arbitrary order, so they are sorted to get
+        // deterministic groups.
+        let mut files: Vec<String> = entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .collect();
+        files.sort();
+
+        for file in files {
+            let Some(stem) = file.strip_suffix(&format!(".{extension}")) else {
+                continue;
+            };
+
+            let group = if directory.is_empty() {
+                stem.strip_prefix("requirements-")
+            } else {
+                Some(stem)
+            };
+
+            if let Some(group) = group
+                && !group.is_empty()
+            {
+                requirements_groups.push((
+                    group.to_string(),
+                    if directory.is_empty() {
+                        file.clone()
+                    } else {
+                        format!("{directory}/{file}")
+                    },
+                ));
+            }
+        }
+    }
+
+    requirements_groups
+}
+
 /// Auto-detects converter to use based on files (and their content) present in the project, or
 /// explicitly select the one associated to the package manager that could be enforced in the CLI.
 pub fn get_converter(
     converter_options: &ConverterOptions,
     requirements_files: Vec<String>,
     dev_requirements_files: Vec<String>,
+    requirements_groups: Vec<(String, String)>,
     enforced_package_manager: Option<PackageManager>,
 ) -> Result<Box<dyn Converter>, String> {
     let project_path = &converter_options.project_path;
@@ -217,6 +459,7 @@ pub fn get_converter(
             converter_options,
             requirements_files,
             dev_requirements_files,
+            requirements_groups,
         ) {
             Ok(converter) => return Ok(converter),
             Err(e) => Err(e),
@@ -225,14 +468,19 @@ pub fn get_converter(
 
     for package_manager in [
         PackageManager::Poetry,
+        PackageManager::Pdm,
+        PackageManager::Hatch,
         PackageManager::Pipenv,
+        PackageManager::Conda,
         PackageManager::PipTools,
         PackageManager::Pip,
+        PackageManager::Setuptools,
     ] {
         match package_manager.detected(
             converter_options,
             requirements_files.clone(),
             dev_requirements_files.clone(),
+            requirements_groups.clone(),
         ) {
             Ok(converter) => return Ok(converter),
             Err(err) => debug!("{err}"),
@@ -271,6 +519,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         )
         .unwrap();
@@ -282,6 +531,50 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("tests/fixtures/pdm/full")]
+    #[case("tests/fixtures/pdm/minimal")]
+    fn test_auto_detect_pdm_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::pdm::Pdm>()
+                .unwrap(),
+            &converters::pdm::Pdm { converter_options }
+        );
+    }
+
+    #[rstest]
+    #[case("tests/fixtures/hatch/full")]
+    #[case("tests/fixtures/hatch/minimal")]
+    fn test_auto_detect_hatch_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::hatch::Hatch>()
+                .unwrap(),
+            &converters::hatch::Hatch { converter_options }
+        );
+    }
+
     #[rstest]
     #[case("tests/fixtures/pipenv/full")]
     #[case("tests/fixtures/pipenv/minimal")]
@@ -292,6 +585,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         )
         .unwrap();
@@ -303,6 +597,31 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("tests/fixtures/conda/full", "environment.yml")]
+    #[case("tests/fixtures/conda/minimal", "environment.yaml")]
+    fn test_auto_detect_conda_ok(#[case] project_path: &str, #[case] environment_file: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::conda::Conda>()
+                .unwrap(),
+            &converters::conda::Conda {
+                converter_options,
+                environment_file: environment_file.to_string(),
+            }
+        );
+    }
+
     #[test]
     fn test_auto_detect_pip_tools_ok() {
         let converter_options =
@@ -312,6 +631,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         )
         .unwrap();
@@ -323,6 +643,10 @@ mod tests {
                 converter_options,
                 requirements_files: vec!["requirements.in".to_string()],
                 dev_requirements_files: vec!["requirements-dev.in".to_string()],
+                requirements_groups: IndexMap::from([(
+                    "typing".to_string(),
+                    vec!["requirements-typing.in".to_string()]
+                )]),
                 is_pip_tools: true,
             }
         );
@@ -337,6 +661,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         )
         .unwrap();
@@ -348,11 +673,37 @@ mod tests {
                 converter_options,
                 requirements_files: vec!["requirements.in".to_string()],
                 dev_requirements_files: vec!["requirements-dev.in".to_string()],
+                requirements_groups: IndexMap::from([(
+                    "typing".to_string(),
+                    vec!["requirements-typing.in".to_string()]
+                )]),
                 is_pip_tools: true,
             }
         );
     }
 
+    #[rstest]
+    #[case("tests/fixtures/setuptools/setup_cfg")]
+    #[case("tests/fixtures/setuptools/setup_py")]
+    fn test_auto_detect_setuptools_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::setuptools::Setuptools>()
+                .unwrap(),
+            &converters::setuptools::Setuptools { converter_options }
+        );
+    }
+
     #[rstest]
     #[case(
         "tests/fixtures/non_existing_path",
@@ -373,6 +724,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         );
         assert_eq!(converter.unwrap_err(), error);
@@ -388,6 +740,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             Some(PackageManager::Poetry),
         )
         .unwrap();
@@ -409,11 +762,88 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             Some(PackageManager::Poetry),
         );
         assert_eq!(converter.unwrap_err(), error);
     }
 
+    #[rstest]
+    #[case("tests/fixtures/pdm/full")]
+    #[case("tests/fixtures/pdm/minimal")]
+    fn test_pdm_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Pdm),
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::pdm::Pdm>()
+                .unwrap(),
+            &converters::pdm::Pdm { converter_options }
+        );
+    }
+
+    #[rstest]
+    #[case("tests/fixtures/pdm", format!("Directory does not contain a {} file.", "pyproject.toml".bold()))]
+    #[case("tests/fixtures/poetry/full", format!("{} does not contain a {} section nor a {} file.", "pyproject.toml".bold(), "[tool.pdm]".bold(), "pdm.lock".bold()))]
+    fn test_pdm_err(#[case] project_path: &str, #[case] error: String) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Pdm),
+        );
+        assert_eq!(converter.unwrap_err(), error);
+    }
+
+    #[rstest]
+    #[case("tests/fixtures/hatch/full")]
+    #[case("tests/fixtures/hatch/minimal")]
+    fn test_hatch_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Hatch),
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::hatch::Hatch>()
+                .unwrap(),
+            &converters::hatch::Hatch { converter_options }
+        );
+    }
+
+    #[rstest]
+    #[case("tests/fixtures/hatch", format!("Directory does not contain a {} file.", "pyproject.toml".bold()))]
+    #[case("tests/fixtures/pdm/full", format!("{} does not contain a {} section.", "pyproject.toml".bold(), "[tool.hatch.envs]".bold()))]
+    fn test_hatch_err(#[case] project_path: &str, #[case] error: String) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Hatch),
+        );
+        assert_eq!(converter.unwrap_err(), error);
+    }
+
     #[rstest]
     #[case("tests/fixtures/pipenv/full")]
     #[case("tests/fixtures/pipenv/minimal")]
@@ -424,6 +854,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             Some(PackageManager::Pipenv),
         )
         .unwrap();
@@ -443,6 +874,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             Some(PackageManager::Pipenv),
         );
         assert_eq!(
@@ -451,6 +883,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_conda_ok() {
+        let converter_options = get_converter_options(PathBuf::from("tests/fixtures/conda/full"));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Conda),
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::conda::Conda>()
+                .unwrap(),
+            &converters::conda::Conda {
+                converter_options,
+                environment_file: "environment.yml".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_conda_err() {
+        let converter_options = get_converter_options(PathBuf::from("tests/fixtures/conda"));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Conda),
+        );
+        assert_eq!(
+            converter.unwrap_err(),
+            format!(
+                "Directory does not contain an {} file.",
+                "environment.yml".bold()
+            )
+        );
+    }
+
     #[test]
     fn test_pip_tools_ok() {
         let converter_options =
@@ -463,6 +938,7 @@ mod tests {
                 "requirements-dev.in".to_string(),
                 "requirements-typing.in".to_string(),
             ],
+            Vec::new(),
             Some(PackageManager::PipTools),
         )
         .unwrap();
@@ -477,6 +953,7 @@ mod tests {
                     "requirements-dev.in".to_string(),
                     "requirements-typing.in".to_string()
                 ],
+                requirements_groups: IndexMap::new(),
                 is_pip_tools: true,
             }
         );
@@ -493,6 +970,7 @@ mod tests {
                 "requirements-dev.in".to_string(),
                 "requirements-typing.in".to_string(),
             ],
+            Vec::new(),
             Some(PackageManager::PipTools),
         );
         assert_eq!(
@@ -512,6 +990,7 @@ mod tests {
                 "requirements-dev.txt".to_string(),
                 "requirements-typing.txt".to_string(),
             ],
+            Vec::new(),
             Some(PackageManager::Pip),
         )
         .unwrap();
@@ -526,6 +1005,41 @@ mod tests {
                     "requirements-dev.txt".to_string(),
                     "requirements-typing.txt".to_string()
                 ],
+                requirements_groups: IndexMap::new(),
+                is_pip_tools: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_pip_requirements_groups_ok() {
+        let converter_options = get_converter_options(PathBuf::from("tests/fixtures/pip/full"));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            Vec::new(),
+            vec![
+                ("lint".to_string(), "requirements-dev.txt".to_string()),
+                ("lint".to_string(), "unknown.txt".to_string()),
+            ],
+            Some(PackageManager::Pip),
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::pip::Pip>()
+                .unwrap(),
+            &converters::pip::Pip {
+                converter_options,
+                requirements_files: vec!["requirements.txt".to_string()],
+                dev_requirements_files: Vec::new(),
+                // `requirements-typing.txt` is referenced from `requirements-dev.txt`, so it is not
+                // discovered as a separate group.
+                requirements_groups: IndexMap::from([(
+                    "lint".to_string(),
+                    vec!["requirements-dev.txt".to_string()]
+                )]),
                 is_pip_tools: false,
             }
         );
@@ -542,6 +1056,7 @@ mod tests {
                 "requirements-dev.txt".to_string(),
                 "requirements-typing.txt".to_string(),
             ],
+            Vec::new(),
             Some(PackageManager::Pip),
         );
         assert_eq!(
@@ -550,6 +1065,51 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("tests/fixtures/setuptools/setup_cfg")]
+    #[case("tests/fixtures/setuptools/setup_py")]
+    fn test_setuptools_ok(#[case] project_path: &str) {
+        let converter_options = get_converter_options(PathBuf::from(project_path));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Setuptools),
+        )
+        .unwrap();
+        assert_eq!(
+            (converter.as_ref() as &dyn Any)
+                .downcast_ref::<converters::setuptools::Setuptools>()
+                .unwrap(),
+            &converters::setuptools::Setuptools { converter_options }
+        );
+    }
+
+    #[test]
+    fn test_setuptools_err() {
+        let converter_options = get_converter_options(PathBuf::from("tests/fixtures/poetry/full"));
+
+        let converter = get_converter(
+            &converter_options,
+            vec!["requirements.txt".to_string()],
+            vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
+            Some(PackageManager::Setuptools),
+        );
+        assert_eq!(
+            converter.unwrap_err(),
+            format!(
+                "Directory does not contain a {} file nor a {} file with {} or {} sections.",
+                "setup.py".bold(),
+                "setup.cfg".bold(),
+                "[metadata]".bold(),
+                "[options]".bold(),
+            )
+        );
+    }
+
     #[test]
     fn test_auto_detect_already_using_uv() {
         let converter_options = get_converter_options(PathBuf::from("tests/fixtures/uv/minimal"));
@@ -558,6 +1118,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         );
         assert_eq!(
@@ -580,6 +1141,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         );
         assert_eq!(
@@ -597,6 +1159,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         );
         assert_eq!(
@@ -617,6 +1180,7 @@ mod tests {
             &converter_options,
             vec!["requirements.txt".to_string()],
             vec!["requirements-dev.txt".to_string()],
+            Vec::new(),
             None,
         );
         assert_eq!(
@@ -20,9 +20,15 @@ pub fn add_unrecoverable_error(error: String) {
         .push(MigrationError::new(error, false));
 }
 
+/// Add an error that does not prevent the migration. Since some files can be parsed more than
+/// once, errors that were already added are ignored.
 pub fn add_recoverable_error(error: String) {
-    MIGRATION_ERRORS
-        .lock()
-        .unwrap()
-        .push(MigrationError::new(error, true));
+    let mut migration_errors = MIGRATION_ERRORS.lock().unwrap();
+
+    if !migration_errors
+        .iter()
+        .any(|migration_error| migration_error.recoverable && migration_error.error == error)
+    {
+        migration_errors.push(MigrationError::new(error, true));
+    }
 }
@@ -0,0 +1,16 @@
+/// Representation of a conda environment file
+/// (<https://docs.conda.io/projects/conda/en/latest/user-guide/tasks/manage-environments.html#create-env-file-manually>).
+#[derive(Default, Debug, Eq, PartialEq)]
+pub struct Environment {
+    pub name: Option<String>,
+    pub channels: Option<Vec<String>>,
+    pub dependencies: Option<Vec<Dependency>>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Dependency {
+    /// Conda package, using conda match specification (e.g. `numpy>=1.26` or `python=3.12`).
+    Conda(String),
+    /// Dependencies installed with pip, defined under `pip` key.
+    Pip(Vec<String>),
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// <https://flit.pypa.io/en/stable/pyproject_toml.html>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Flit {
+    pub module: Option<Module>,
+    pub sdist: Option<Sdist>,
+}
+
+/// <https://flit.pypa.io/en/stable/pyproject_toml.html#module-section>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Module {
+    pub name: Option<String>,
+}
+
+/// <https://flit.pypa.io/en/stable/pyproject_toml.html#sdist-section>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Sdist {
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+}
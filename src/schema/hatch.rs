@@ -1,9 +1,26 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-#[derive(Deserialize, Serialize)]
+#[derive(Default, Deserialize, Serialize)]
 pub struct Hatch {
+    // Version configuration is only written when migrating to Hatch build backend.
+    #[serde(skip_deserializing)]
+    pub version: Option<Version>,
+    // Build configuration is only written when migrating to Hatch build backend, and can hold
+    // values we do not model (e.g. `sources` defined as an array), so we do not read it.
+    #[serde(skip_deserializing)]
     pub build: Option<Build>,
+    pub envs: Option<IndexMap<String, Env>>,
+}
+
+/// <https://hatch.pypa.io/latest/version/>
+#[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct Version {
+    pub source: Option<String>,
+    /// <https://github.com/ofek/hatch-vcs#version-source-options>
+    #[serde(rename = "tag-pattern")]
+    pub tag_pattern: Option<String>,
 }
 
 #[derive(Default, Eq, PartialEq, Deserialize, Serialize)]
@@ -18,4 +35,27 @@ pub struct BuildTarget {
     pub force_include: Option<IndexMap<String, String>>,
     pub exclude: Option<Vec<String>>,
     pub sources: Option<IndexMap<String, String>>,
+    #[serde(rename = "shared-scripts")]
+    pub shared_scripts: Option<IndexMap<String, String>>,
+    pub hooks: Option<IndexMap<String, BuildHook>>,
+}
+
+/// <https://hatch.pypa.io/latest/plugins/build-hook/custom/>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BuildHook {
+    pub path: Option<String>,
+}
+
+/// <https://hatch.pypa.io/latest/config/environment/overview/>
+#[derive(Default, Deserialize, Serialize)]
+pub struct Env {
+    pub template: Option<String>,
+    pub detached: Option<bool>,
+    #[serde(rename = "skip-install")]
+    pub skip_install: Option<bool>,
+    pub dependencies: Option<Vec<String>>,
+    #[serde(rename = "extra-dependencies")]
+    pub extra_dependencies: Option<Vec<String>>,
+    pub features: Option<Vec<String>>,
+    pub scripts: Option<IndexMap<String, Value>>,
 }
@@ -1,7 +1,11 @@
+pub mod conda;
+pub mod flit;
 pub mod hatch;
+pub mod pdm;
 pub mod pep_621;
 pub mod pipenv;
 pub mod poetry;
 pub mod pyproject;
+pub mod setuptools;
 pub mod utils;
 pub mod uv;
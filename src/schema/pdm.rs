@@ -0,0 +1,61 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use toml::Value;
+
+#[derive(Deserialize, Serialize, Default)]
+pub struct Pdm {
+    /// <https://pdm-project.org/latest/reference/pep621/#distribution>
+    pub distribution: Option<bool>,
+    /// <https://pdm-project.org/latest/usage/dependency/#add-development-only-dependencies>
+    #[serde(rename = "dev-dependencies")]
+    pub dev_dependencies: Option<IndexMap<String, Vec<String>>>,
+    pub source: Option<Vec<Source>>,
+    pub resolution: Option<Resolution>,
+    pub scripts: Option<IndexMap<String, Value>>,
+}
+
+/// Represents a package source: <https://pdm-project.org/latest/usage/config/#configure-the-package-indexes>.
+#[derive(Deserialize, Serialize, Default)]
+pub struct Source {
+    pub name: String,
+    pub url: String,
+    #[serde(rename = "type")]
+    pub type_: Option<SourceType>,
+    pub include_packages: Option<Vec<String>>,
+    pub exclude_packages: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceType {
+    Index,
+    FindLinks,
+}
+
+/// <https://backend.pdm-project.org/build_config/>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Build {
+    pub includes: Option<Vec<String>>,
+    pub excludes: Option<Vec<String>>,
+    #[serde(rename = "source-includes")]
+    pub source_includes: Option<Vec<String>>,
+    #[serde(rename = "package-dir")]
+    pub package_dir: Option<String>,
+}
+
+/// <https://pdm-project.org/latest/usage/config/#override-the-resolved-package-versions>
+#[derive(Deserialize, Serialize, Default)]
+pub struct Resolution {
+    pub overrides: Option<IndexMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+pub struct PdmLock {
+    pub package: Option<Vec<LockedPackage>>,
+}
+
+#[derive(Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+}
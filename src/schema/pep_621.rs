@@ -1,4 +1,6 @@
-use crate::schema::poetry::Poetry;
+use crate::schema::hatch::Hatch;
+use crate::schema::pdm::Pdm;
+use crate::schema::poetry::{Poetry, PoetryDynamicVersioning};
 use crate::schema::uv::Uv;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,8 @@ pub struct Project {
     pub requires_python: Option<String>,
     pub readme: Option<String>,
     pub license: Option<License>,
+    #[serde(rename = "license-files")]
+    pub license_files: Option<Vec<String>>,
     pub maintainers: Option<Vec<AuthorOrMaintainer>>,
     pub keywords: Option<Vec<String>>,
     pub classifiers: Option<Vec<String>>,
@@ -51,6 +55,10 @@ pub enum License {
 
 #[derive(Deserialize, Serialize, Default)]
 pub struct Tool {
+    pub hatch: Option<Hatch>,
+    pub pdm: Option<Pdm>,
     pub poetry: Option<Poetry>,
+    #[serde(rename = "poetry-dynamic-versioning")]
+    pub poetry_dynamic_versioning: Option<PoetryDynamicVersioning>,
     pub uv: Option<Uv>,
 }
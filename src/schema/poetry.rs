@@ -32,6 +32,41 @@ pub struct Poetry {
     pub packages: Option<Vec<Package>>,
     pub include: Option<Vec<Include>>,
     pub exclude: Option<Vec<String>>,
+    pub build: Option<Build>,
+}
+
+impl Poetry {
+    pub fn get_build_script(&self) -> Option<&String> {
+        match self.build.as_ref()? {
+            Build::String(script) => Some(script),
+            Build::Map { script, .. } => script.as_ref(),
+        }
+    }
+
+    /// Whether Poetry builds extension modules by calling `build(setup_kwargs)` from the build
+    /// script, and running setuptools with the updated arguments, instead of only executing the
+    /// script. This is the case for the legacy definition, or when `generate-setup-file` is set.
+    pub fn build_generates_setup_file(&self) -> bool {
+        match self.build.as_ref() {
+            Some(Build::String(_)) => true,
+            Some(Build::Map {
+                generate_setup_file,
+                ..
+            }) => generate_setup_file.unwrap_or(false),
+            None => false,
+        }
+    }
+
+    /// Paths of the file scripts (<https://python-poetry.org/docs/pyproject/#scripts>), that are
+    /// installed as is in the environment.
+    pub fn get_file_scripts(&self) -> Vec<String> {
+        self.scripts
+            .iter()
+            .flatten()
+            .filter_map(|(_, script)| script.get_file())
+            .map(|path| path.trim_start_matches("./").to_string())
+            .collect()
+    }
 }
 
 #[derive(Deserialize, Serialize)]
@@ -74,10 +109,28 @@ pub enum SourcePriority {
 #[serde(untagged)]
 pub enum Script {
     String(String),
-    // Although not documented, a script can be set as a map, where `callable` is the script to run.
-    // An `extra` field also exists, but it doesn't seem to actually do
-    // anything (https://github.com/python-poetry/poetry/issues/6892).
-    Map { callable: Option<String> },
+    /// <https://python-poetry.org/docs/pyproject/#scripts>. `reference` is either the object to
+    /// run for `console` scripts (the default type), or the path of the script for `file` scripts.
+    /// Before Poetry 2, the object to run was set with `callable`.
+    Map {
+        reference: Option<String>,
+        callable: Option<String>,
+        #[serde(rename = "type")]
+        type_: Option<String>,
+        extras: Option<Vec<String>>,
+    },
+}
+
+impl Script {
+    /// Path of the script, if the script is a file script.
+    pub fn get_file(&self) -> Option<&String> {
+        match self {
+            Self::Map {
+                reference, type_, ..
+            } if type_.as_deref() == Some("file") => reference.as_ref(),
+            _ => None,
+        }
+    }
 }
 
 /// Represents the different ways dependencies can be defined in Poetry.
@@ -105,6 +158,9 @@ pub enum DependencySpecification {
         path: Option<String>,
         develop: Option<bool>,
         url: Option<String>,
+        #[serde(rename = "allow-prereleases")]
+        allow_prereleases: Option<bool>,
+        optional: Option<bool>,
     },
     /// Multiple constraints dependencies: <https://python-poetry.org/docs/dependency-specification/#multiple-constraints-dependencies>.
     Vec(Vec<Self>),
@@ -139,6 +195,28 @@ impl DependencySpecification {
         }
     }
 
+    /// Whether the dependency is only installed when an extra referencing it is requested
+    /// (<https://python-poetry.org/docs/pyproject/#extras>).
+    pub fn is_optional(&self) -> bool {
+        match self {
+            Self::String(_) => false,
+            Self::Map { optional, .. } => *optional == Some(true),
+            Self::Vec(specs) => specs.iter().any(Self::is_optional),
+        }
+    }
+
+    /// Whether pre-releases of the dependency can be installed
+    /// (<https://python-poetry.org/docs/dependency-specification/#version-constraints>).
+    pub fn allows_prereleases(&self) -> bool {
+        match self {
+            Self::String(_) => false,
+            Self::Map {
+                allow_prereleases, ..
+            } => *allow_prereleases == Some(true),
+            Self::Vec(specs) => specs.iter().any(Self::allows_prereleases),
+        }
+    }
+
     pub fn get_marker(&self) -> Result<Option<String>, ParseVersionError> {
         let mut combined_markers: Vec<String> = Vec::new();
 
@@ -152,15 +230,12 @@ impl DependencySpecification {
             if let Some(python) = python {
                 match PoetryPep440::from_str(python) {
                     Ok(version) => combined_markers.push(version.to_python_marker()),
+                    // While PEP 440 has no "or" operator, markers do, so disjoint ranges can still be
+                    // expressed.
                     Err(ParseVersionError {
-                        kind: ParseVersionErrorKind::OrOperator(operator),
-                        version,
-                    }) => {
-                        return Err(ParseVersionError::new(
-                            ParseVersionErrorKind::PythonMarkerOrOperator(operator),
-                            version,
-                        ));
-                    }
+                        kind: ParseVersionErrorKind::OrOperator(_),
+                        ..
+                    }) => combined_markers.push(Self::get_python_or_marker(python)?),
                     Err(ParseVersionError {
                         kind: ParseVersionErrorKind::Other,
                         version,
@@ -207,8 +282,45 @@ impl DependencySpecification {
         if combined_markers.is_empty() {
             return Ok(None);
         }
+
+        // Since "and" takes precedence over "or" in markers, markers using "or" need to be
+        // parenthesized when combined with other ones.
+        if combined_markers.len() > 1 {
+            for marker in &mut combined_markers {
+                if marker.contains(" or ") {
+                    *marker = format!("({marker})");
+                }
+            }
+        }
+
         Ok(Some(combined_markers.join(" and ")))
     }
+
+    /// Convert a Python version using Poetry "or" operator (e.g. `2.7 || ^3.6`) to markers
+    /// combined with "or" (e.g. `python_full_version == '2.7.*' or (python_full_version >= '3.6'
+    /// and python_full_version < '4')`).
+    fn get_python_or_marker(python: &str) -> Result<String, ParseVersionError> {
+        python
+            .split("||")
+            .flat_map(|s| s.split('|'))
+            .map(|version| match PoetryPep440::from_str(version) {
+                Ok(version) => {
+                    let marker = version.to_python_marker();
+
+                    if marker.contains(" and ") {
+                        Ok(format!("({marker})"))
+                    } else {
+                        Ok(marker)
+                    }
+                }
+                Err(_) => Err(ParseVersionError::new(
+                    ParseVersionErrorKind::PythonMarker,
+                    python.to_string(),
+                )),
+            })
+            .collect::<Result<Vec<String>, ParseVersionError>>()
+            .map(|markers| markers.join(" or "))
+    }
 }
 
 /// Package distribution definition <https://python-poetry.org/docs/pyproject/#packages>.
@@ -231,6 +343,20 @@ pub enum Include {
     },
 }
 
+/// Build script used to build extension modules
+/// <https://python-poetry.org/docs/building-extension-modules/>.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Build {
+    /// Legacy definition, e.g. `build = "build.py"`.
+    String(String),
+    Map {
+        script: Option<String>,
+        #[serde(rename = "generate-setup-file")]
+        generate_setup_file: Option<bool>,
+    },
+}
+
 #[derive(Deserialize, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Format {
@@ -238,6 +364,25 @@ pub enum Format {
     Wheel,
 }
 
+/// Configuration of `poetry-dynamic-versioning` plugin
+/// <https://github.com/mtkennerly/poetry-dynamic-versioning#configuration>.
+#[derive(Deserialize, Serialize, Default)]
+pub struct PoetryDynamicVersioning {
+    pub enable: Option<bool>,
+    pub vcs: Option<String>,
+    pub style: Option<String>,
+    pub pattern: Option<String>,
+    #[serde(flatten)]
+    pub remaining_fields: IndexMap<String, toml::Value>,
+}
+
+impl PoetryDynamicVersioning {
+    /// The plugin only sets the version dynamically if it is explicitly enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enable == Some(true)
+    }
+}
+
 #[derive(Deserialize)]
 pub struct PoetryLock {
     pub package: Option<Vec<LockedPackage>>,
@@ -248,3 +393,113 @@ pub struct LockedPackage {
     pub name: String,
     pub version: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn get_specification(python: Option<&str>, platform: Option<&str>) -> DependencySpecification {
+        toml::from_str::<IndexMap<String, DependencySpecification>>(&format!(
+            "foo = {{ version = \"1.2.3\"{}{} }}",
+            python.map_or_else(String::new, |python| format!(", python = \"{python}\"")),
+            platform.map_or_else(String::new, |platform| format!(
+                ", platform = \"{platform}\""
+            )),
+        ))
+        .unwrap()
+        .shift_remove("foo")
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(
+        Some("^3.11"),
+        None,
+        Some("python_full_version >= '3.11' and python_full_version < '4'")
+    )]
+    #[case(
+        Some("2.7 || ^3.6"),
+        None,
+        Some(
+            "python_full_version == '2.7.*' or (python_full_version >= '3.6' and python_full_version < '4')"
+        )
+    )]
+    #[case(
+        Some("~3.9 | ~3.11"),
+        None,
+        Some(
+            "(python_full_version >= '3.9' and python_full_version < '3.10') or (python_full_version >= '3.11' and python_full_version < '3.12')"
+        )
+    )]
+    #[case(
+        Some("~3.11 || ~3.12"),
+        None,
+        Some("python_full_version >= '3.11' and python_full_version < '3.13'")
+    )]
+    #[case(
+        Some("2.7 || ^3.6"),
+        Some("linux"),
+        Some(
+            "(python_full_version == '2.7.*' or (python_full_version >= '3.6' and python_full_version < '4')) and sys_platform == 'linux'"
+        )
+    )]
+    #[case(
+        Some("3.11"),
+        Some("darwin|linux"),
+        Some(
+            "python_full_version == '3.11.*' and (sys_platform == 'darwin' or sys_platform == 'linux')"
+        )
+    )]
+    #[case(None, None, None)]
+    fn test_get_marker(
+        #[case] python: Option<&str>,
+        #[case] platform: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            get_specification(python, platform).get_marker(),
+            Ok(expected.map(ToString::to_string))
+        );
+    }
+
+    #[test]
+    fn test_get_marker_invalid_python_or() {
+        assert_eq!(
+            get_specification(Some("~2.7 || foo"), None).get_marker(),
+            Err(ParseVersionError::new(
+                ParseVersionErrorKind::PythonMarker,
+                "~2.7 || foo".to_string()
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(r#"build = "build.py""#, Some("build.py"))]
+    #[case(
+        r#"build = { script = "build.py", generate-setup-file = false }"#,
+        Some("build.py")
+    )]
+    #[case("build = { generate-setup-file = false }", None)]
+    #[case("", None)]
+    fn test_get_build_script(#[case] poetry: &str, #[case] expected: Option<&str>) {
+        let poetry = toml::from_str::<Poetry>(poetry).unwrap();
+
+        assert_eq!(poetry.get_build_script().map(String::as_str), expected);
+    }
+
+    #[rstest]
+    #[case(r#"build = "build.py""#, true)]
+    #[case(r#"build = { script = "build.py", generate-setup-file = true }"#, true)]
+    #[case(
+        r#"build = { script = "build.py", generate-setup-file = false }"#,
+        false
+    )]
+    #[case(r#"build = { script = "build.py" }"#, false)]
+    #[case("", false)]
+    fn test_build_generates_setup_file(#[case] poetry: &str, #[case] expected: bool) {
+        let poetry = toml::from_str::<Poetry>(poetry).unwrap();
+
+        assert_eq!(poetry.build_generates_setup_file(), expected);
+    }
+}
@@ -0,0 +1,35 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// <https://setuptools.pypa.io/en/latest/userguide/pyproject_config.html#setuptools-specific-configuration>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Setuptools {
+    #[serde(rename = "py-modules")]
+    pub py_modules: Option<Vec<String>>,
+    pub packages: Option<Packages>,
+    #[serde(rename = "package-dir")]
+    pub package_dir: Option<IndexMap<String, String>>,
+    #[serde(rename = "package-data")]
+    pub package_data: Option<IndexMap<String, Vec<String>>>,
+    #[serde(rename = "include-package-data")]
+    pub include_package_data: Option<bool>,
+    #[serde(rename = "license-files")]
+    pub license_files: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum Packages {
+    Vec(Vec<String>),
+    Find { find: Find },
+}
+
+/// <https://setuptools.pypa.io/en/latest/userguide/package_discovery.html#finding-simple-packages>
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
+pub struct Find {
+    #[serde(rename = "where")]
+    pub where_: Option<Vec<String>>,
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+    pub namespaces: Option<bool>,
+}
@@ -7,6 +7,26 @@ pub struct Uv {
     pub package: Option<bool>,
     /// <https://docs.astral.sh/uv/configuration/indexes/#defining-an-index>
     pub index: Option<Vec<Index>>,
+    /// <https://docs.astral.sh/uv/reference/settings/#allow-insecure-host>
+    #[serde(rename = "allow-insecure-host")]
+    pub allow_insecure_host: Option<Vec<String>>,
+    /// <https://docs.astral.sh/uv/reference/settings/#prerelease>
+    pub prerelease: Option<String>,
+    /// <https://docs.astral.sh/uv/reference/settings/#python-preference>
+    #[serde(rename = "python-preference")]
+    pub python_preference: Option<String>,
+    /// <https://docs.astral.sh/uv/reference/settings/#no-binary>
+    #[serde(rename = "no-binary")]
+    pub no_binary: Option<bool>,
+    /// <https://docs.astral.sh/uv/reference/settings/#no-binary-package>
+    #[serde(rename = "no-binary-package")]
+    pub no_binary_package: Option<Vec<String>>,
+    /// <https://docs.astral.sh/uv/reference/settings/#concurrent-installs>
+    #[serde(rename = "concurrent-installs")]
+    pub concurrent_installs: Option<i64>,
+    /// <https://docs.astral.sh/uv/reference/settings/#cache-dir>
+    #[serde(rename = "cache-dir")]
+    pub cache_dir: Option<String>,
     /// <https://docs.astral.sh/uv/configuration/indexes/#pinning-a-package-to-an-index>
     pub sources: Option<IndexMap<String, SourceContainer>>,
     /// <https://docs.astral.sh/uv/concepts/projects/dependencies/#default-groups>
@@ -14,19 +34,48 @@ pub struct Uv {
     pub default_groups: Option<SingleOrVec<String>>,
     #[serde(rename = "constraint-dependencies")]
     pub constraint_dependencies: Option<Vec<String>>,
+    /// <https://docs.astral.sh/uv/reference/settings/#override-dependencies>
+    #[serde(rename = "override-dependencies")]
+    pub override_dependencies: Option<Vec<String>>,
     #[serde(rename = "build-backend")]
     pub build_backend: Option<UvBuildBackend>,
 }
 
+/// <https://docs.astral.sh/uv/concepts/projects/layout/#the-lockfile>
+#[derive(Default, Deserialize, Eq, PartialEq, Debug)]
+pub struct UvLock {
+    pub package: Option<Vec<LockedPackage>>,
+}
+
+#[derive(Default, Deserialize, Eq, PartialEq, Debug)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: Option<String>,
+    pub sdist: Option<LockedArtifact>,
+    pub wheels: Option<Vec<LockedArtifact>>,
+}
+
+#[derive(Default, Deserialize, Eq, PartialEq, Debug)]
+pub struct LockedArtifact {
+    pub hash: Option<String>,
+}
+
 #[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
 pub struct Index {
     pub name: String,
     pub url: Option<String>,
+    /// <https://docs.astral.sh/uv/concepts/indexes/#flat-indexes>
+    pub format: Option<String>,
     pub default: Option<bool>,
     pub explicit: Option<bool>,
+    /// <https://docs.astral.sh/uv/reference/settings/#index>
+    pub authenticate: Option<String>,
+    /// <https://docs.astral.sh/uv/guides/package/#publishing-your-package>
+    #[serde(rename = "publish-url")]
+    pub publish_url: Option<String>,
 }
 
-#[derive(Default, Deserialize, Serialize, Eq, PartialEq)]
+#[derive(Default, Deserialize, Serialize, Eq, PartialEq, Debug)]
 pub struct SourceIndex {
     pub index: Option<String>,
     pub path: Option<String>,
@@ -40,7 +89,7 @@ pub struct SourceIndex {
     pub marker: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Eq, PartialEq)]
+#[derive(Deserialize, Serialize, Eq, PartialEq, Debug)]
 #[serde(untagged)]
 pub enum SourceContainer {
     SourceIndex(SourceIndex),
@@ -67,9 +116,9 @@ pub struct UvBuildBackend {
 
 #[derive(Default, Deserialize, Serialize, Clone, Eq, PartialEq)]
 pub struct UvBuildBackendData {
-    data: Option<String>,
-    headers: Option<String>,
-    platlib: Option<String>,
-    purelib: Option<String>,
-    scripts: Option<String>,
+    pub data: Option<String>,
+    pub headers: Option<String>,
+    pub platlib: Option<String>,
+    pub purelib: Option<String>,
+    pub scripts: Option<String>,
 }
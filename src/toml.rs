@@ -54,15 +54,17 @@ impl VisitMut for PyprojectPrettyFormatter {
                             | "entry-points"
                     ]
                     | ["project", "entry-points", _]
-                    | ["tool", "uv"]
+                    | ["tool", "uv" | "setuptools"]
                     | ["tool", "uv", "build-backend" | "sources"]
-                    | ["tool", "hatch", ..]
+                    | ["tool", "hatch" | "flit", ..]
+                    | ["tool", "pdm", "build", ..]
+                    | ["tool", "setuptools", "packages" | "package-data", ..]
             ) {
                 let position = match parent_keys.as_slice() {
                     ["project"] => Some(0),
                     ["dependency-groups"] => Some(1),
                     ["tool", "uv"] => Some(2),
-                    ["tool", "hatch"] => Some(3),
+                    ["tool", "hatch" | "setuptools" | "flit"] | ["tool", "pdm", "build"] => Some(3),
                     _ => None,
                 };
 
@@ -107,7 +109,9 @@ impl VisitMut for PyprojectPrettyFormatter {
         // sections ensures that unrelated sections are left intact.
         if matches!(
             parent_keys.as_slice(),
-            ["project" | "dependency-groups", ..] | ["tool", "uv" | "hatch", ..]
+            ["project" | "dependency-groups", ..]
+                | ["tool", "uv" | "hatch" | "setuptools" | "flit", ..]
+                | ["tool", "pdm", "build", ..]
         ) && node.len() >= 2
         {
             for item in node.iter_mut() {
@@ -0,0 +1,132 @@
+use crate::common::{apply_filters, cli};
+use dircpy::copy_dir;
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+
+const FIXTURES_PATH: &str = "tests/fixtures/conda";
+
+#[test]
+fn test_complete_workflow() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("minimal");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locking dependencies using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Successfully migrated project from conda to uv!
+    "#);
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("environment.yaml").exists());
+
+    // Assert that `uv.lock` file was generated.
+    assert!(project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_skip_lock() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("full");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from conda to uv!
+
+    warning: "-e ./packages/bar" from "environment.yml" could not be automatically migrated, try running "uv add -e ./packages/bar".
+    warning: The following conda packages from "environment.yml" do not have an equivalent on PyPI, so they were not migrated: "cudatoolkit=11.8", "r-base".
+    warning: "environment.yml" was not deleted, as some of its content could not be migrated.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = "==3.12.*"
+    dependencies = [
+        "numpy>=1.26,<3",
+        "pandas==2.2.*",
+        "scikit-learn==1.6.1",
+        "torch>=2.5",
+        "requests>=2.31",
+        "httpx[cli]==0.28.1",
+    ]
+
+    [tool.uv]
+    package = false
+    "#);
+
+    // Assert that the environment file is kept, as some packages could not be migrated.
+    assert!(project_path.join("environment.yml").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("minimal");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11"
+    dependencies = ["arrow"]
+
+    [tool.uv]
+    package = false
+    "#);
+
+    // Assert that `pyproject.toml` was not created.
+    assert!(!project_path.join("pyproject.toml").exists());
+
+    // Assert that previous package manager files have not been removed.
+    assert!(project_path.join("environment.yaml").exists());
+}
+
+#[test]
+fn test_unsupported_syntax() {
+    let project_path = Path::new(FIXTURES_PATH).join("flow_sequences");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "dependencies: [python=3.12, numpy]" from "environment.yml" uses YAML syntax that is not supported, use block sequences instead (one "- <item>" per line).
+    "#);
+
+    // Assert that `pyproject.toml` was not created.
+    assert!(!project_path.join("pyproject.toml").exists());
+}
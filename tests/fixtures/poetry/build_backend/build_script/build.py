@@ -0,0 +1,8 @@
+from setuptools import Distribution, Extension
+from setuptools.command.build_ext import build_ext
+
+distribution = Distribution({"ext_modules": [Extension("foo._speedups", ["foo/_speedups.c"])]})
+command = build_ext(distribution)
+command.inplace = True
+command.ensure_finalized()
+command.run()
@@ -0,0 +1,5 @@
+from setuptools import Extension
+
+
+def build(setup_kwargs):
+    setup_kwargs.update({"ext_modules": [Extension("foo._speedups", ["foo/_speedups.c"])]})
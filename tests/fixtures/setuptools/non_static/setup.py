@@ -0,0 +1,12 @@
+from setuptools import setup
+
+with open("README.md") as f:
+    long_description = f.read()
+
+setup(
+    name="foo",
+    version="1.2.3",
+    long_description=long_description,
+    install_requires=["arrow>=1.2.3"],
+    cmdclass={"build_py": BuildPy},
+)
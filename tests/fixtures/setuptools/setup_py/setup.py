@@ -0,0 +1,23 @@
+from setuptools import find_packages, setup
+
+setup(
+    name="foo",
+    version="1.2.3",
+    description="A fabulous project.",
+    author="John Doe",
+    author_email="john.doe@example.com",
+    url="https://example.com",
+    python_requires=">=3.11",
+    install_requires=[
+        "arrow>=1.2.3",
+        "requests[security]>=2.31 ; python_version >= '3.12'",
+    ],
+    extras_require={
+        "http": ["httpx>=0.28", "certifi"],
+    },
+    entry_points={
+        "console_scripts": ["foo = foo.cli:main"],
+    },
+    packages=find_packages(exclude=["tests", "tests.*"]),
+    setup_requires=["wheel"],
+)
@@ -0,0 +1,177 @@
+use crate::common::{apply_filters, cli};
+use dircpy::copy_dir;
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+
+const FIXTURES_PATH: &str = "tests/fixtures/hatch";
+
+#[test]
+fn test_complete_workflow() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("minimal");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locking dependencies using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Successfully migrated project from Hatch to uv!
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.11"
+
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [dependency-groups]
+    dev = ["pytest>=8"]
+    "#);
+
+    // Assert that `uv.lock` file was generated.
+    assert!(project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_skip_lock() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("full");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from Hatch to uv!
+
+    warning: "invalid-package!" from "dev" group could not be automatically migrated, try running "uv add --group dev invalid-package!".
+    warning: Scripts from "tool.hatch.envs.test.scripts" ("run", "cov") cannot be migrated, as uv does not support defining scripts.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.11"
+    dependencies = ["arrow>=1.2.3"]
+
+    [project.optional-dependencies]
+    cli = ["click>=8"]
+    test = ["pytest>=8"]
+
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [dependency-groups]
+    dev = [
+        "factory-boy>=3.2.1",
+        "foo[cli]",
+    ]
+    test = [
+        { include-group = "dev" },
+        "coverage[toml]>=7",
+        "foo[test]",
+    ]
+    lint = ["ruff>=0.8"]
+    docs = ["mkdocs>=1.6"]
+
+    [tool.uv]
+    default-groups = "all"
+
+    [tool.hatch.build.targets.wheel]
+    sources = ["src"]
+
+    [tool.ruff]
+    fix = true
+    "#);
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_keep_old_metadata() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("minimal");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--keep-current-data"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from Hatch to uv!
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.11"
+
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [dependency-groups]
+    dev = ["pytest>=8"]
+
+    [tool.hatch.envs.default]
+    dependencies = ["pytest>=8"]
+    "#);
+}
+
+#[test]
+fn test_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("minimal");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    requires-python = ">=3.11"
+
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [dependency-groups]
+    dev = ["pytest>=8"]
+    "#);
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
@@ -0,0 +1,283 @@
+use crate::common::{LockedPackage, UvLock, apply_filters, cli};
+use dircpy::copy_dir;
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+
+const FIXTURES_PATH: &str = "tests/fixtures/pdm";
+
+#[test]
+fn test_complete_workflow() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_lock_file");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Locking dependencies again using "uv lock" to remove constraints...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Successfully migrated project from PDM to uv!
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11"
+    dependencies = ["arrow>=1.2.3"]
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1"]
+
+    [tool.uv]
+    package = false
+    "#);
+
+    let uv_lock = toml::from_str::<UvLock>(
+        fs::read_to_string(project_path.join("uv.lock"))
+            .unwrap()
+            .as_str(),
+    )
+    .unwrap();
+
+    // Assert that locked versions in `uv.lock` match what was in `pdm.lock`.
+    let uv_lock_packages = uv_lock.package.unwrap();
+    let expected_locked_packages = Vec::from([
+        LockedPackage {
+            name: "arrow".to_string(),
+            version: "1.2.3".to_string(),
+        },
+        LockedPackage {
+            name: "factory-boy".to_string(),
+            version: "3.2.1".to_string(),
+        },
+        LockedPackage {
+            name: "faker".to_string(),
+            version: "33.1.0".to_string(),
+        },
+        LockedPackage {
+            name: "python-dateutil".to_string(),
+            version: "2.7.0".to_string(),
+        },
+        LockedPackage {
+            name: "six".to_string(),
+            version: "1.15.0".to_string(),
+        },
+        LockedPackage {
+            name: "typing-extensions".to_string(),
+            version: "4.6.0".to_string(),
+        },
+    ]);
+    for package in expected_locked_packages {
+        assert!(uv_lock_packages.contains(&package));
+    }
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("pdm.lock").exists());
+    assert!(!project_path.join("pdm.toml").exists());
+}
+
+#[test]
+fn test_skip_lock() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_lock_file");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from PDM to uv!
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11"
+    dependencies = ["arrow>=1.2.3"]
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1"]
+
+    [tool.uv]
+    package = false
+    "#);
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("pdm.lock").exists());
+    assert!(!project_path.join("pdm.toml").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_skip_lock_full() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("full");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from PDM to uv!
+
+    warning: "-e file:///${PROJECT_ROOT}/packages/foo" from "dev" group could not be automatically migrated, try running "uv add --group dev -e file:///${PROJECT_ROOT}/packages/foo".
+    warning: Scripts from "tool.pdm.scripts" ("start", "lint") cannot be migrated, as uv does not support defining scripts.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.11"
+    license = { text = "MIT" }
+    dependencies = [
+        "arrow>=1.2.3",
+        "httpx[cli]==0.28.1",
+    ]
+
+    [project.optional-dependencies]
+    extra = ["requests>=2.32"]
+
+    [build-system]
+    requires = ["pdm-backend"]
+    build-backend = "pdm.backend"
+
+    [dependency-groups]
+    dev = ["pytest>=8.3.4"]
+    typing = [
+        "mypy>=1.14.1",
+        "types-jsonschema>=4.23.0",
+    ]
+
+    [tool.uv]
+    default-groups = "all"
+    override-dependencies = [
+        "asgiref==3.2.10",
+        "urllib3>=1.26.2",
+        "pytz @ https://example.com/pytz-2020.9-py3-none-any.whl",
+    ]
+
+    [[tool.uv.index]]
+    name = "private"
+    url = "https://private.example.com/simple"
+
+    [[tool.uv.index]]
+    name = "wheels"
+    url = "https://wheels.example.com/"
+    format = "flat"
+
+    [tool.pdm.build]
+    includes = ["foobar"]
+
+    # This comment should be preserved.
+    [tool.ruff]
+    fix = true
+    "#);
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_lock_file");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11"
+    dependencies = ["arrow>=1.2.3"]
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1"]
+
+    [tool.uv]
+    package = false
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that previous package manager files have not been removed.
+    assert!(project_path.join("pdm.lock").exists());
+    assert!(project_path.join("pdm.toml").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_dry_run_minimal() {
+    let project_path = Path::new(FIXTURES_PATH).join("minimal");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    requires-python = ">=3.11"
+
+    [tool.uv]
+    package = false
+
+    [tool.ruff]
+    fix = true
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
@@ -45,8 +45,11 @@ fn test_complete_workflow() {
 
     warning: "file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:bar".
     warning: "file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:./bar".
+    warning: "-e file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:bar".
+    warning: "-e file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:./bar".
     warning: "git+https://github.com/psf/requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests".
-    warning: "git+https://github.com/psf/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests#egg=requests".
+    warning: "hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add hg+https://hg.example.com/requests#egg=requests".
+    warning: "-e hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add --editable hg+https://hg.example.com/requests#egg=requests".
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
@@ -64,14 +67,19 @@ fn test_complete_workflow() {
 
     [dependency-groups]
     dev = [
-        "pytest==8.3.4",
-        "ruff==0.8.4",
         "mypy==1.14.1",
         "types-jsonschema==4.23.0.20241208",
+        "pytest==8.3.4",
+        "ruff==0.8.4",
     ]
 
     [tool.uv]
     package = false
+    constraint-dependencies = [
+        "h11==0.14.0",
+        "httpcore==1.0.7",
+        "zstandard==0.23.0",
+    ]
     "#);
 
     // Assert that previous package manager files are correctly removed.
@@ -117,8 +125,11 @@ fn test_keep_current_data() {
 
     warning: "file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:bar".
     warning: "file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:./bar".
+    warning: "-e file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:bar".
+    warning: "-e file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:./bar".
     warning: "git+https://github.com/psf/requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests".
-    warning: "git+https://github.com/psf/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests#egg=requests".
+    warning: "hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add hg+https://hg.example.com/requests#egg=requests".
+    warning: "-e hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add --editable hg+https://hg.example.com/requests#egg=requests".
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
@@ -136,14 +147,19 @@ fn test_keep_current_data() {
 
     [dependency-groups]
     dev = [
-        "pytest==8.3.4",
-        "ruff==0.8.4",
         "mypy==1.14.1",
         "types-jsonschema==4.23.0.20241208",
+        "pytest==8.3.4",
+        "ruff==0.8.4",
     ]
 
     [tool.uv]
     package = false
+    constraint-dependencies = [
+        "h11==0.14.0",
+        "httpcore==1.0.7",
+        "zstandard==0.23.0",
+    ]
     "#);
 
     // Assert that previous package manager files have not been removed.
@@ -182,8 +198,11 @@ fn test_skip_lock() {
 
     warning: "file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:bar".
     warning: "file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:./bar".
+    warning: "-e file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:bar".
+    warning: "-e file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:./bar".
     warning: "git+https://github.com/psf/requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests".
-    warning: "git+https://github.com/psf/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests#egg=requests".
+    warning: "hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add hg+https://hg.example.com/requests#egg=requests".
+    warning: "-e hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add --editable hg+https://hg.example.com/requests#egg=requests".
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
@@ -201,14 +220,19 @@ fn test_skip_lock() {
 
     [dependency-groups]
     dev = [
-        "pytest==8.3.4",
-        "ruff==0.8.4",
         "mypy==1.14.1",
         "types-jsonschema==4.23.0.20241208",
+        "pytest==8.3.4",
+        "ruff==0.8.4",
     ]
 
     [tool.uv]
     package = false
+    constraint-dependencies = [
+        "h11==0.14.0",
+        "httpcore==1.0.7",
+        "zstandard==0.23.0",
+    ]
     "#);
 
     // Assert that previous package manager files are correctly removed.
@@ -256,19 +280,27 @@ fn test_dry_run() {
 
     [dependency-groups]
     dev = [
-        "pytest==8.3.4",
-        "ruff==0.8.4",
         "mypy==1.14.1",
         "types-jsonschema==4.23.0.20241208",
+        "pytest==8.3.4",
+        "ruff==0.8.4",
     ]
 
     [tool.uv]
     package = false
+    constraint-dependencies = [
+        "h11==0.14.0",
+        "httpcore==1.0.7",
+        "zstandard==0.23.0",
+    ]
 
     warning: "file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:bar".
     warning: "file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add file:./bar".
+    warning: "-e file:bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:bar".
+    warning: "-e file:./bar" from "requirements.txt" could not be automatically migrated, try running "uv add --editable file:./bar".
     warning: "git+https://github.com/psf/requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests".
-    warning: "git+https://github.com/psf/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add git+https://github.com/psf/requests#egg=requests".
+    warning: "hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add hg+https://hg.example.com/requests#egg=requests".
+    warning: "-e hg+https://hg.example.com/requests#egg=requests" from "requirements.txt" could not be automatically migrated, try running "uv add --editable hg+https://hg.example.com/requests#egg=requests".
     "#);
 
     // Assert that previous package manager files have not been removed.
@@ -283,6 +315,206 @@ fn test_dry_run() {
     assert!(!project_path.join("uv.lock").exists());
 }
 
+#[test]
+fn test_dry_run_indexes() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_indexes");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = ["arrow==1.3.0"]
+
+    [dependency-groups]
+    dev = ["pytest==8.3.4"]
+
+    [tool.uv]
+    package = false
+    allow-insecure-host = ["mirror.example.com"]
+
+    [[tool.uv.index]]
+    name = "mirror.example.com"
+    url = "https://mirror.example.com/simple"
+    default = true
+
+    [[tool.uv.index]]
+    name = "foo.example.com"
+    url = "https://foo.example.com/simple/"
+
+    [[tool.uv.index]]
+    name = "wheels"
+    url = "./wheels"
+    format = "flat"
+
+    [[tool.uv.index]]
+    name = "foo.example.com-2"
+    url = "https://foo.example.com/wheels/"
+    format = "flat"
+    "#);
+}
+
+#[test]
+fn test_dry_run_sources() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_sources");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = [
+        "arrow==1.3.0",
+        "core",
+        "qux[cli]",
+        "bar",
+        "baz ; sys_platform == 'linux'",
+        "foo",
+    ]
+
+    [dependency-groups]
+    dev = [
+        "pytest==8.3.4",
+        "lint-rules",
+    ]
+
+    [tool.uv]
+    package = true
+
+    [tool.uv.sources]
+    core = { path = "./libs/core", editable = true }
+    qux = { path = "libs/qux", editable = true }
+    bar = { path = "./wheels/bar-1.0.0-py3-none-any.whl" }
+    baz = { path = "/opt/baz" }
+    foo = { git = "https://github.com/org/foo", rev = "v1.2.0", subdirectory = "packages/foo" }
+    lint-rules = { git = "https://github.com/org/lint-rules" }
+    "#);
+}
+
+#[test]
+fn test_dry_run_env_vars() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_env_vars");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = [
+        "arrow==1.3.0",
+        "foo @ file:///${PROJECT_ROOT}/libs/foo",
+        "bar",
+    ]
+
+    [tool.uv]
+    package = false
+
+    [[tool.uv.index]]
+    name = "pypi.example.com"
+    url = "https://pypi.example.com/simple"
+    default = true
+
+    [[tool.uv.index]]
+    name = "mirror_host"
+    url = "https://${MIRROR_HOST}/simple"
+
+    [tool.uv.sources]
+    bar = { path = "./libs/bar", editable = true }
+
+    warning: "baz @ https://${GITHUB_TOKEN}@github.com/org/baz/archive/v1.0.0.zip" from "requirements.txt" uses environment variables ("${GITHUB_TOKEN}") that uv does not expand in "pyproject.toml", so it could not be automatically migrated.
+    warning: "-r ${REQUIREMENTS_DIR}/base.txt" from "requirements.txt" uses environment variables ("${REQUIREMENTS_DIR}") that uv does not expand in "pyproject.toml", so it could not be automatically migrated.
+    warning: Credentials of index "pypi.example.com" use environment variables ("${PYPI_USERNAME}", "${PYPI_PASSWORD}") that uv does not expand in "pyproject.toml", so they were removed from the index URL. Set "UV_INDEX_PYPI_EXAMPLE_COM_USERNAME" and "UV_INDEX_PYPI_EXAMPLE_COM_PASSWORD" environment variables instead.
+    warning: URL of index "mirror_host" uses environment variables ("${MIRROR_HOST}") that uv does not expand in "pyproject.toml". Replace them with their values in "[[tool.uv.index]]".
+    "#);
+}
+
+#[test]
+fn test_dry_run_requirements_groups() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_groups");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = ["arrow==1.3.0"]
+
+    [dependency-groups]
+    lint = ["ruff==0.8.4"]
+    docs = ["mkdocs==1.6.1"]
+    test = [
+        "coverage==7.6.10",
+        "pytest==8.3.4",
+    ]
+
+    [tool.uv]
+    package = false
+    default-groups = "all"
+    constraint-dependencies = ["jinja2<4"]
+    "#);
+}
+
+#[test]
+fn test_dry_run_requirements_groups_mapping() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_groups");
+
+    assert_cmd_snapshot!(cli()
+        .arg(&project_path)
+        .arg("--dry-run")
+        .arg("--requirements-group")
+        .arg("typing=requirements-lint.txt")
+        .arg("--dependency-groups-strategy")
+        .arg("set-default-groups"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = ["arrow==1.3.0"]
+
+    [dependency-groups]
+    typing = ["ruff==0.8.4"]
+    docs = ["mkdocs==1.6.1"]
+    test = [
+        "coverage==7.6.10",
+        "pytest==8.3.4",
+    ]
+
+    [tool.uv]
+    package = false
+    default-groups = [
+        "typing",
+        "docs",
+        "test",
+    ]
+    constraint-dependencies = ["jinja2<4"]
+    "#);
+}
+
 #[test]
 fn test_preserves_existing_project() {
     let project_path = Path::new(FIXTURES_PATH).join("existing_project");
@@ -385,6 +385,46 @@ fn test_skip_lock_full() {
     assert!(!project_path.join("uv.lock").exists());
 }
 
+#[test]
+fn test_skip_lock_keeps_user_constraints() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_constraints");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from pip-tools to uv!
+    ");
+
+    // Constraints declared by the user are kept, even when they are identical to a locked
+    // version, while other locked versions are removed.
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = [
+        "arrow>=1.2.3",
+        "foo",
+    ]
+
+    [tool.uv]
+    package = false
+    constraint-dependencies = [
+        "python-dateutil==2.9.0.post0",
+    ]
+
+    [tool.uv.sources]
+    foo = { path = "./libs/foo", editable = true }
+    "#);
+}
+
 #[test]
 fn test_dry_run() {
     let project_path = Path::new(FIXTURES_PATH).join("with_lock_file");
@@ -437,6 +477,71 @@ fn test_dry_run() {
     assert!(!project_path.join("uv.lock").exists());
 }
 
+#[test]
+fn test_dry_run_outdated_lock_file() {
+    let project_path = Path::new(FIXTURES_PATH).join("outdated_lock_file");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = ["arrow>=1.2.3"]
+
+    [dependency-groups]
+    dev = ["requests>=2.32.3"]
+
+    [tool.uv]
+    package = false
+
+    warning: The following packages pinned in "requirements.txt" are not required from "requirements.in" anymore: "certifi", "requests", "urllib3". "requirements.txt" is probably outdated, consider running "pip-compile requirements.in" before migrating.
+    "#);
+}
+
+#[test]
+fn test_dry_run_compile_options() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_compile_options");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "1.0.0"
+    requires-python = ">=3.13"
+    dependencies = ["arrow>=1.2.3"]
+
+    [project.optional-dependencies]
+    lint = ["ruff>=0.8.4"]
+
+    [dependency-groups]
+    dev = [
+        "pytest>=8.3.4",
+        "foobar[lint]",
+    ]
+
+    [tool.uv]
+    package = false
+    prerelease = "allow"
+
+    [[tool.uv.index]]
+    name = "mirror.example.com"
+    url = "https://mirror.example.com/simple"
+    default = true
+
+    warning: The following options used to compile "requirements.txt" could not be migrated, as uv handles them differently: "--no-build-isolation".
+    "#);
+}
+
 #[test]
 fn test_preserves_existing_project() {
     let project_path = Path::new(FIXTURES_PATH).join("existing_project");
@@ -441,6 +441,44 @@ fn test_dry_run_minimal() {
     assert!(!project_path.join("uv.lock").exists());
 }
 
+#[test]
+fn test_dry_run_env_vars() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_env_vars");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    dependencies = [
+        "arrow==1.3.0",
+        "foo==1.0.0",
+    ]
+
+    [tool.uv]
+    package = false
+
+    [[tool.uv.index]]
+    name = "pypi"
+    url = "https://pypi.org/simple"
+
+    [[tool.uv.index]]
+    name = "private"
+    url = "https://private.example.com/simple"
+    explicit = true
+
+    [tool.uv.sources]
+    foo = { index = "private" }
+
+    warning: Credentials of index "private" use environment variables ("${PRIVATE_USERNAME}", "${PRIVATE_PASSWORD}") that uv does not expand in "pyproject.toml", so they were removed from the index URL. Set "UV_INDEX_PRIVATE_USERNAME" and "UV_INDEX_PRIVATE_PASSWORD" environment variables instead.
+    "#);
+}
+
 #[test]
 fn test_preserves_existing_project() {
     let project_path = Path::new(FIXTURES_PATH).join("existing_project");
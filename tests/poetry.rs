@@ -466,7 +466,6 @@ fn test_skip_lock_full() {
 
     warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
     warning: Could not find dependency "non-existing-dependency" listed in "extra-with-non-existing-dependencies" extra.
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
@@ -495,7 +494,6 @@ fn test_skip_lock_full() {
         "Development Status :: 3 - Alpha",
         "Environment :: Console",
         "Intended Audience :: Developers",
-        "License :: OSI Approved :: MIT License",
         "Topic :: Software Development :: Libraries :: Python Modules",
         "Operating System :: OS Independent",
         "Programming Language :: Python :: 3",
@@ -519,7 +517,7 @@ fn test_skip_lock_full() {
         "caret-and-pep440>=1.0,<2,<1.3",
         "caret-and-pep440-2>=1.0,<2,>=1.1,<1.3",
         "caret-and-pep440-3>=1.0,<2,>=1.1,>=1.2,!=1.2.2,<1.3",
-        "caret-and-pep440-whitepsaces>=1.0,<2,>=   1.1,>=  1.2,!=   1.2.2,< 1.3",
+        "caret-and-pep440-whitepsaces>=1.0,<2,>=1.1,>=1.2,!=1.2.2,<1.3",
         "caret-and-caret-and-pep440>=1.0,<2,>=1.1,<2,<1.2",
         "tilde~=1.2.3",
         "tilde-2>=1.2,<1.3",
@@ -530,7 +528,7 @@ fn test_skip_lock_full() {
         "tilde-and-pep440>=1.0,<1.1,<1.3",
         "tilde-and-pep440-2>=1.0,<1.1,>=1.0,<1.1",
         "tilde-and-pep440-3>=1.0,<1.1,>=1.0,>=1.0.1,!=1.0.2,<1.3",
-        "tilde-and-pep440-whitepsaces>=1.0,<1.1,>=   1.0,>=  1.0.1,!=   1.0.2,< 1.3",
+        "tilde-and-pep440-whitepsaces>=1.0,<1.1,>=1.0,>=1.0.1,!=1.0.2,<1.3",
         "tilde-and-tilde-and-pep440>=1.0,<1.1,~=1.0.1,<1.2",
         "exact==1.2.3",
         "exact-2==1.2.3",
@@ -546,7 +544,7 @@ fn test_skip_lock_full() {
         "with-pipe-delimited-platform==1.2.3 ; sys_platform == 'darwin' or sys_platform == 'linux'",
         "with-double-pipe-delimited-platform==1.2.3 ; sys_platform == 'darwin' or sys_platform == 'linux' or sys_platform == 'windows'",
         "with-double-pipe-delimited-platform-and-spaces==1.2.3 ; sys_platform == 'darwin' or sys_platform == 'linux' or sys_platform == 'windows'",
-        "with-markers-python-platform==1.2.3 ; python_full_version >= '3.11' and python_full_version < '3.12' and platform_python_implementation == 'CPython' or platform_python_implementation == 'Jython' and sys_platform == 'darwin'",
+        "with-markers-python-platform==1.2.3 ; python_full_version >= '3.11' and python_full_version < '3.12' and (platform_python_implementation == 'CPython' or platform_python_implementation == 'Jython') and sys_platform == 'darwin'",
         "with-source==1.2.3",
         "python-restricted==1.2.3 ; python_full_version >= '3.11' and python_full_version < '4'",
         "python-restricted-2==1.2.3 ; python_full_version >= '3.11' and python_full_version < '3.12'",
@@ -563,9 +561,23 @@ fn test_skip_lock_full() {
         "python-restricted-equal-minor-version==1.2.2 ; python_full_version == '3.11.*'",
         "python-restricted-equal-major-version==1.2.3 ; python_full_version == '3.0.*'",
         "python-restricted-with-source==1.2.3 ; python_full_version > '3.11' and python_full_version < '3.13'",
+        "or-contiguous>=1.2,<3",
+        "or-contiguous-single>=3.8,<4",
+        "python-restricted-or-contiguous==1.2.3 ; python_full_version >= '3.11' and python_full_version < '3.13'",
+        "python-restricted-or-disjoint==1.2.3 ; (python_full_version >= '2.7' and python_full_version < '3') or (python_full_version >= '3.11' and python_full_version < '4')",
+        "python-restricted-or-disjoint-single==1.2.3 ; python_full_version == '2.7.*' or (python_full_version >= '3.11' and python_full_version < '4')",
         "whitespaces>=3.2,<4",
         "whitespaces-2     >   3.11,     <=     3.13    ",
-        "optional-not-in-extra==1.2.3",
+        "whitespace>=7.0,<7.1",
+        "whitespace-multiple>=7.0,<7.1",
+        "whitespace-operator>=7.0,<7.1",
+        "whitespace-caret>=7.0,<8,>=7.1,<8",
+        "whitespace-caret-multiple>=7.0,<8,>=7.1,<8",
+        "wildcard==1.2.*",
+        "wildcard-x==1.2.*",
+        "wildcard-tilde>=1.2,<1.3",
+        "wildcard-exclusion>=1.0,<2,!=1.1.*",
+        "python-whitespace==1.2.3 ; python_full_version >= '3.11' and python_full_version <= '3.14' and python_full_version < '4'",
         "local-package",
         "local-package-2",
         "local-package-editable",
@@ -830,11 +842,14 @@ fn test_dry_run() {
 }
 
 #[test]
-fn test_dry_run_minimal() {
-    let project_path = Path::new(FIXTURES_PATH).join("minimal");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_dry_run_credentials() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_credentials");
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r###"
+    assert_cmd_snapshot!(cli()
+        .arg(&project_path)
+        .arg("--dry-run")
+        .env("POETRY_CONFIG_DIR", project_path.join("config"))
+        .env("POETRY_HTTP_BASIC_BAZ_MIRROR_PASSWORD", "baz-password"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -842,61 +857,51 @@ fn test_dry_run_minimal() {
     ----- stderr -----
     Migrated pyproject.toml:
     [project]
-    name = "foobar"
+    name = ""
     version = "0.0.1"
+    requires-python = ">=3.12,<4"
+    dependencies = [
+        "arrow>=1.3.0,<2",
+        "foo>=1.0.0,<2",
+    ]
 
-    [tool.ruff]
-    fix = true
-
-    [tool.ruff.format]
-    preview = true
-    "###);
-
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
+    [tool.uv]
+    package = false
 
-    // Assert that `uv.lock` file was not generated.
-    assert!(!project_path.join("uv.lock").exists());
-}
+    [[tool.uv.index]]
+    name = "private-bar"
+    url = "https://bar.example.com/simple/"
+    authenticate = "always"
 
-#[test]
-fn test_preserves_existing_project() {
-    let project_path = Path::new(FIXTURES_PATH).join("existing_project");
+    [[tool.uv.index]]
+    name = "baz.mirror"
+    url = "https://baz.example.com/simple/"
+    authenticate = "always"
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
-    success: true
-    exit_code: 0
-    ----- stdout -----
+    [[tool.uv.index]]
+    name = "qux"
+    url = "https://qux.example.com/simple/"
 
-    ----- stderr -----
-    Migrated pyproject.toml:
-    [project]
-    name = "foobar"
-    version = "1.0.0"
-    description = "A description"
-    requires-python = ">=3.13"
-    dependencies = ["arrow>=1.2.3,<2"]
+    [[tool.uv.index]]
+    name = "foo"
+    url = "https://foo.example.com/simple/"
+    explicit = true
+    authenticate = "always"
 
-    [dependency-groups]
-    dev = ["factory-boy>=3.2.1,<4"]
-    typing = ["mypy>=1.13.0,<2"]
+    [tool.uv.sources]
+    foo = { index = "foo" }
 
-    [tool.uv]
-    default-groups = "all"
+    warning: Credentials of index "private-bar" are configured in Poetry, but uv reads them from "UV_INDEX_PRIVATE_BAR_USERNAME" and "UV_INDEX_PRIVATE_BAR_PASSWORD" environment variables, which should be set before running uv.
+    warning: Credentials of index "baz.mirror" are configured in Poetry, but uv reads them from "UV_INDEX_BAZ_MIRROR_USERNAME" and "UV_INDEX_BAZ_MIRROR_PASSWORD" environment variables, which should be set before running uv.
+    warning: Credentials of index "foo" are configured in Poetry, but uv reads them from "UV_INDEX_FOO_USERNAME" and "UV_INDEX_FOO_PASSWORD" environment variables, which should be set before running uv.
     "#);
 }
 
 #[test]
-fn test_replaces_existing_project() {
-    let project_path = Path::new(FIXTURES_PATH).join("existing_project");
+fn test_dry_run_settings() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_settings");
 
-    assert_cmd_snapshot!(cli()
-        .arg(&project_path)
-        .arg("--dry-run")
-        .arg("--replace-project-section"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -904,26 +909,37 @@ fn test_replaces_existing_project() {
     ----- stderr -----
     Migrated pyproject.toml:
     [project]
-    name = "foo"
+    name = ""
     version = "0.0.1"
-    description = "A description"
-    requires-python = ">=3.11,<4"
-    dependencies = ["arrow>=1.2.3,<2"]
-
-    [dependency-groups]
-    dev = ["factory-boy>=3.2.1,<4"]
-    typing = ["mypy>=1.13.0,<2"]
+    requires-python = ">=3.12,<4"
+    dependencies = ["arrow>=1.3.0,<2"]
 
     [tool.uv]
-    default-groups = "all"
+    package = false
+    python-preference = "system"
+    no-binary = true
+    concurrent-installs = 4
+    cache-dir = "/tmp/poetry-cache"
+
+    [[tool.uv.index]]
+    name = "private"
+    url = "https://private.example.com/simple/"
+    publish-url = "https://private.example.com/legacy/"
+
+    [[tool.uv.index]]
+    name = "testpypi"
+    url = "https://test.pypi.org/simple/"
+    explicit = true
+    publish-url = "https://test.pypi.org/legacy/"
+
+    warning: The following settings from "poetry.toml" have no equivalent in uv, and were not migrated: "virtualenvs.create", "virtualenvs.options.system-site-packages".
     "#);
 }
 
 #[test]
-fn test_pep_621() {
-    let project_path = Path::new(FIXTURES_PATH).join("pep_621");
+fn test_dry_run_dynamic_versioning() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_dynamic_versioning");
 
-    apply_filters!();
     assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
     exit_code: 0
@@ -932,401 +948,420 @@ fn test_pep_621() {
     ----- stderr -----
     Migrated pyproject.toml:
     [build-system]
-    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
-    build-backend = "uv_build"
+    requires = ["hatchling", "hatch-vcs"]
+    build-backend = "hatchling.build"
 
     [project]
-    name = "foobar"
-    version = "0.1.0"
+    name = "foo"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
-    requires-python = ">=3.11"
-    readme = "README.md"
-    license = "MIT"
-    maintainers = [{ name = "Dohn Joe", email = "dohn.joe@example.com" }]
-    keywords = ["foo"]
-    classifiers = ["Development Status :: 3 - Alpha"]
-    dependencies = [
-        "arrow==1.2.3",
-        "git-dep",
-        "private-dep==3.4.5",
-    ]
-
-    [dependency-groups]
-    dev = ["factory-boy>=3.2.1,<4"]
-    typing = ["mypy>=1.13.0,<2"]
-    profiling = ["pyinstrument>=5.0.2,<6"]
-
-    [tool.uv]
-    default-groups = [
-        "dev",
-        "typing",
+    requires-python = ">=3.11,<4"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
     ]
+    dependencies = ["arrow>=1.3.0,<2"]
+    dynamic = ["version"]
 
-    [[tool.uv.index]]
-    name = "PyPI"
-    url = "https://pypi.org/simple/"
-
-    [[tool.uv.index]]
-    name = "supplemental"
-    url = "https://supplemental.example.com/simple/"
-
-    [tool.uv.sources]
-    git-dep = { git = "https://example.com/foo/bar", tag = "v1.2.3" }
-    private-dep = { index = "supplemental" }
-
-    [tool.ruff]
-    fix = true
-
-    [tool.ruff.lint]
-    # This comment should be preserved.
-    fixable = ["I", "UP"]
+    [tool.hatch.version]
+    source = "vcs"
+    tag-pattern = '(?x)^release-(?P<version>\d+\.\d+\.\d+)$'
 
-    [tool.ruff.format]
-    preview = true
+    warning: Migrating build backend to Hatch, as version is set dynamically with "poetry-dynamic-versioning", which cannot be expressed with uv.
+    warning: The following settings from "[tool.poetry-dynamic-versioning]" have no equivalent in hatch-vcs, and were not migrated: "latest-tag".
     "#);
 }
 
 #[test]
-fn test_manage_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
-    let pyproject = fs::read_to_string(fixture_path.join("pyproject.toml")).unwrap();
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(fixture_path, project_path).unwrap();
+fn test_dry_run_dynamic_versioning_build_backend_uv() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_dynamic_versioning");
 
     apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("uv"), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     error: Could not automatically migrate the project to uv because of the following errors:
-    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
-    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
-    error: - "caret-or" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-single" dependency with version "^1.0|^2.0|^3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-table-version" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.0.1,<1.0.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "whitespace" dependency with version ">=7.0 <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-multiple" dependency with version ">=7.0  <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret" dependency with version "7.0 ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret-multiple" dependency with version "7.0  ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "python-caret-or" dependency with python marker "^3.11 || ^3.12" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-caret-or-single" dependency with python marker "^3.11 | ^3.12" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-whitespace" dependency with python marker "3.11 <=3.14" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    error: - Build backend cannot be migrated to uv, as version is set dynamically with "poetry-dynamic-versioning", which cannot be expressed with uv.
+    error: - Package distribution could not be migrated to uv build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
     "#);
-
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
-
-    // Assert that previous package manager files have not been removed.
-    assert!(project_path.join("poetry.lock").exists());
-    assert!(project_path.join("poetry.toml").exists());
-
-    // Assert that `uv.lock` file was not generated.
-    assert!(!project_path.join("uv.lock").exists());
 }
 
 #[test]
-fn test_manage_errors_ignore_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(fixture_path, project_path).unwrap();
+fn test_dry_run_dynamic_versioning_keep_current_build_backend() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_dynamic_versioning");
 
-    apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--ignore-errors").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--keep-current-build-backend"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
-    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
-    error: - "caret-or" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-single" dependency with version "^1.0|^2.0|^3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-table-version" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.0.1,<1.0.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "whitespace" dependency with version ">=7.0 <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-multiple" dependency with version ">=7.0  <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret" dependency with version "7.0 ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret-multiple" dependency with version "7.0  ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "python-caret-or" dependency with python marker "^3.11 || ^3.12" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-caret-or-single" dependency with python marker "^3.11 | ^3.12" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-whitespace" dependency with python marker "3.11 <=3.14" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] packages in [TIME]
-    Locking dependencies again using "uv lock" to remove constraints...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] packages in [TIME]
-    Partially migrated project from Poetry to uv, as errors occurred during the migration.
-    "#);
-
-    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    Migrated pyproject.toml:
     [project]
-    name = "foobar"
-    version = "0.0.1"
-    description = ""
-    authors = []
-    requires-python = ">=3.11"
-    dependencies = ["arrow==1.2.3"]
+    name = "foo"
+    description = "A fabulous project."
+    requires-python = ">=3.11,<4"
+    dependencies = ["arrow>=1.3.0,<2"]
+    dynamic = ["version"]
 
-    [dependency-groups]
-    dev = ["pytest==9.0.2"]
-    profiling = ["pyinstrument==5.1.2"]
+    [build-system]
+    requires = ["poetry-core>=1.0.0", "poetry-dynamic-versioning>=1.0.0,<2.0.0"]
+    build-backend = "poetry_dynamic_versioning.backend"
 
-    [tool.uv]
-    default-groups = "all"
+    [tool.poetry]
+    # Placeholder version, replaced at build time by `poetry-dynamic-versioning`.
+    version = "0.0.0"
+
+    [tool.poetry-dynamic-versioning]
+    enable = true
+    vcs = "git"
+    style = "pep440"
+    pattern = '(?x)^release-(?P<base>\d+\.\d+\.\d+)$'
+    latest-tag = true
     "#);
-
-    // Assert that previous package manager files are correctly removed.
-    assert!(!project_path.join("poetry.lock").exists());
-    assert!(!project_path.join("poetry.toml").exists());
 }
 
 #[test]
-fn test_manage_warnings() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_warnings");
+fn test_build_script() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/build_script");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
 
     copy_dir(fixture_path, project_path).unwrap();
 
-    apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] packages in [TIME]
-    Locking dependencies again using "uv lock" to remove constraints...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] packages in [TIME]
     Successfully migrated project from Poetry to uv!
 
-    warning: Could not find dependency "non-existing-dependency" listed in "extra-with-non-existing-dependencies" extra.
+    warning: Migrating build backend to Hatch, as build script "build.py" is used, which cannot be expressed with uv.
+    warning: Build script "build.py" is now run by a Hatch build hook generated in "hatch_build.py". Make sure that the script builds extension modules when run directly, and that built files are included in wheels.
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["hatchling", "setuptools"]
+    build-backend = "hatchling.build"
+
     [project]
     name = "foo"
-    version = "0.0.1"
+    version = "0.1.0"
+    description = "A fabulous project."
     requires-python = ">=3.11,<4"
-    dependencies = ["arrow>=1.2.3,<2"]
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
 
-    [project.optional-dependencies]
-    extra-with-non-existing-dependencies = []
+    [tool.hatch.build.targets.wheel.hooks.custom]
+    path = "hatch_build.py"
+    "#);
 
-    [dependency-groups]
-    dev = ["factory-boy>=3.2.1,<4"]
-    typing = ["mypy>=1.13.0,<2"]
-    profiling = ["pyinstrument>=5.0.2,<6"]
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("hatch_build.py")).unwrap(), @r#"
+    import subprocess
+    import sys
 
-    [tool.uv]
-    package = false
-    default-groups = [
-        "dev",
-        "typing",
-    ]
+    from hatchling.builders.hooks.plugin.interface import BuildHookInterface
+
+
+    class CustomBuildHook(BuildHookInterface):
+        """Run the build script previously used by Poetry."""
+
+        def initialize(self, version, build_data):
+            subprocess.check_call([sys.executable, "build.py"], cwd=self.root)
+
+            # Build scripts compile extension modules, so wheels are specific to the platform, and
+            # compiled files must be included even if they are ignored by VCS.
+            build_data["pure_python"] = False
+            build_data["infer_tag"] = True
+            build_data["artifacts"].extend(["*.so", "*.pyd"])
     "#);
+}
 
-    let uv_lock = toml::from_str::<UvLock>(
-        fs::read_to_string(project_path.join("uv.lock"))
-            .unwrap()
-            .as_str(),
+#[test]
+fn test_build_script_existing_build_hook_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/build_script");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+    fs::write(
+        project_path.join("hatch_build.py"),
+        "# Existing build hook\n",
     )
     .unwrap();
 
-    // Assert that locked versions in `uv.lock` match what was in `poetry.lock`.
-    let uv_lock_packages = uv_lock.package.unwrap();
-    let expected_locked_packages = Vec::from([
-        LockedPackage {
-            name: "arrow".to_string(),
-            version: "1.2.3".to_string(),
-        },
-        LockedPackage {
-            name: "factory-boy".to_string(),
-            version: "3.2.1".to_string(),
-        },
-        LockedPackage {
-            name: "faker".to_string(),
-            version: "33.1.0".to_string(),
-        },
-        LockedPackage {
-            name: "foo".to_string(),
-            version: "0.0.1".to_string(),
-        },
-        LockedPackage {
-            name: "mypy".to_string(),
-            version: "1.13.0".to_string(),
-        },
-        LockedPackage {
-            name: "mypy-extensions".to_string(),
-            version: "1.0.0".to_string(),
-        },
-        LockedPackage {
-            name: "python-dateutil".to_string(),
-            version: "2.7.0".to_string(),
-        },
-        LockedPackage {
-            name: "six".to_string(),
-            version: "1.15.0".to_string(),
-        },
-        LockedPackage {
-            name: "typing-extensions".to_string(),
-            version: "4.6.0".to_string(),
-        },
-    ]);
-    for package in expected_locked_packages {
-        assert!(uv_lock_packages.contains(&package));
-    }
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
 
-    // Assert that previous package manager files are correctly removed.
-    assert!(!project_path.join("poetry.lock").exists());
-    assert!(!project_path.join("poetry.toml").exists());
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - Build script "build.py" from "poetry.build" cannot be converted to Hatch, as "hatch_build.py" already exists.
+    Partially migrated project from Poetry to uv, as errors occurred during the migration.
+
+    warning: Migrating build backend to Hatch, as build script "build.py" is used, which cannot be expressed with uv.
+    warning: Files included in source distribution differ after migrating build backend to Hatch (added: "hatch_build.py").
+    "#);
+
+    // Assert that the existing build hook was not overwritten.
+    assert_eq!(
+        fs::read_to_string(project_path.join("hatch_build.py")).unwrap(),
+        "# Existing build hook\n"
+    );
 }
 
 #[test]
-fn test_manage_errors_dry_run() {
-    let project_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_dry_run_build_script() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/build_script");
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["hatchling", "setuptools"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.11,<4"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.hatch.build.targets.wheel.hooks.custom]
+    path = "hatch_build.py"
+
+    Generated hatch_build.py:
+    import subprocess
+    import sys
+
+    from hatchling.builders.hooks.plugin.interface import BuildHookInterface
+
+
+    class CustomBuildHook(BuildHookInterface):
+        """Run the build script previously used by Poetry."""
+
+        def initialize(self, version, build_data):
+            subprocess.check_call([sys.executable, "build.py"], cwd=self.root)
+
+            # Build scripts compile extension modules, so wheels are specific to the platform, and
+            # compiled files must be included even if they are ignored by VCS.
+            build_data["pure_python"] = False
+            build_data["infer_tag"] = True
+            build_data["artifacts"].extend(["*.so", "*.pyd"])
+
+    warning: Migrating build backend to Hatch, as build script "build.py" is used, which cannot be expressed with uv.
+    warning: Build script "build.py" is now run by a Hatch build hook generated in "hatch_build.py". Make sure that the script builds extension modules when run directly, and that built files are included in wheels.
+    "#);
+
+    // Assert that the build hook was not generated.
+    assert!(!project_path.join("hatch_build.py").exists());
+}
+
+#[test]
+fn test_dry_run_build_script_legacy() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/build_script_legacy");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["hatchling", "setuptools"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foo"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.11,<4"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.hatch.build.targets.wheel.hooks.custom]
+    path = "hatch_build.py"
+
+    Generated hatch_build.py:
+    import importlib.util
+    import os
+
+    from hatchling.builders.hooks.plugin.interface import BuildHookInterface
+
+
+    class CustomBuildHook(BuildHookInterface):
+        """Run the build script previously used by Poetry."""
+
+        def initialize(self, version, build_data):
+            # Poetry calls `build` function of the build script to update setuptools arguments, and
+            # builds extension modules with setuptools.
+            spec = importlib.util.spec_from_file_location(
+                "build_script", os.path.join(self.root, "build.py")
+            )
+            build_script = importlib.util.module_from_spec(spec)
+            spec.loader.exec_module(build_script)
+
+            setup_kwargs = {
+                "name": self.metadata.name,
+                "version": self.metadata.version,
+                "packages": [],
+                "py_modules": [],
+            }
+            build_script.build(setup_kwargs)
+
+            from setuptools import setup
+
+            cwd = os.getcwd()
+            os.chdir(self.root)
+            try:
+                setup(**setup_kwargs, script_args=["build_ext", "--inplace"])
+            finally:
+                os.chdir(cwd)
+
+            # Build scripts compile extension modules, so wheels are specific to the platform, and
+            # compiled files must be included even if they are ignored by VCS.
+            build_data["pure_python"] = False
+            build_data["infer_tag"] = True
+            build_data["artifacts"].extend(["*.so", "*.pyd"])
+
+    warning: Migrating build backend to Hatch, as build script "build.py" is used, which cannot be expressed with uv.
+    warning: Build script "build.py" is now run by a Hatch build hook generated in "hatch_build.py", that calls its "build" function and builds extension modules with setuptools. Make sure that "setuptools" is part of build requirements, and that built files are included in wheels.
+    "#);
+
+    // Assert that the build hook was not generated.
+    assert!(!project_path.join("hatch_build.py").exists());
+}
+
+#[test]
+fn test_dry_run_build_script_build_backend_uv() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/build_script");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("uv"), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     error: Could not automatically migrate the project to uv because of the following errors:
-    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
-    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
-    error: - "caret-or" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-single" dependency with version "^1.0|^2.0|^3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-table-version" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.0.1,<1.0.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "whitespace" dependency with version ">=7.0 <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-multiple" dependency with version ">=7.0  <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret" dependency with version "7.0 ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret-multiple" dependency with version "7.0  ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "python-caret-or" dependency with python marker "^3.11 || ^3.12" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-caret-or-single" dependency with python marker "^3.11 | ^3.12" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-whitespace" dependency with python marker "3.11 <=3.14" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    error: - Build backend cannot be migrated to uv, as build script "build.py" is used, which cannot be expressed with uv.
+    error: - Package distribution could not be migrated to uv build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
     "#);
-
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
-
-    // Assert that `uv.lock` file was not generated.
-    assert!(!project_path.join("uv.lock").exists());
 }
 
 #[test]
-fn test_manage_errors_dry_run_ignore_errors() {
-    let project_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_dry_run_prereleases() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_prereleases");
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--ignore-errors").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
-    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
-    error: - "caret-or" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-single" dependency with version "^1.0|^2.0|^3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^2.0  ||  ^3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-table-version" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.0.1,<1.0.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "whitespace" dependency with version ">=7.0 <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-multiple" dependency with version ">=7.0  <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret" dependency with version "7.0 ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "whitespace-caret-multiple" dependency with version "7.0  ^7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
-    error: - "python-caret-or" dependency with python marker "^3.11 || ^3.12" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-caret-or-single" dependency with python marker "^3.11 | ^3.12" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
-    error: - "python-whitespace" dependency with python marker "3.11 <=3.14" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
     Migrated pyproject.toml:
     [project]
-    name = "foobar"
+    name = ""
     version = "0.0.1"
-    description = ""
-    authors = []
-    requires-python = ">=3.11"
-    dependencies = ["arrow==1.2.3"]
+    requires-python = ">=3.12,<4"
+    dependencies = [
+        "arrow>=1.3.0,<2",
+        "foo>=1.0.0b1",
+        "bar>=2.0,<3",
+    ]
+
+    [project.optional-dependencies]
+    baz = ["baz>=3.0,<4"]
 
     [dependency-groups]
-    dev = ["pytest==9.0.2"]
-    profiling = ["pyinstrument==5.1.2"]
+    dev = ["qux>=4.0,<5"]
 
     [tool.uv]
+    package = false
     default-groups = "all"
+
+    warning: Pre-releases are allowed for "bar", but uv only allows pre-releases for dependencies whose version specifiers include a pre-release (e.g. ">=1.0.0b1"). Consider using such a specifier, or setting "prerelease = "allow"" under "[tool.uv]" to allow pre-releases for all dependencies.
+    "#);
+}
+
+#[test]
+fn test_dry_run_prereleases_specifiers() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_prereleases_specifiers");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = ""
+    version = "0.0.1"
+    requires-python = ">=3.12,<4"
+    dependencies = [
+        "arrow>=1.3.0,<2",
+        "foo>=1.0.0b1",
+    ]
+
+    [tool.uv]
+    package = false
     "#);
+}
+
+#[test]
+fn test_dry_run_minimal() {
+    let project_path = Path::new(FIXTURES_PATH).join("minimal");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.0.1"
+
+    [tool.ruff]
+    fix = true
+
+    [tool.ruff.format]
+    preview = true
+    "###);
 
     // Assert that `pyproject.toml` was not updated.
     assert_eq!(
@@ -1339,9 +1374,8 @@ fn test_manage_errors_dry_run_ignore_errors() {
 }
 
 #[test]
-fn test_manage_warnings_dry_run() {
-    let project_path = Path::new(FIXTURES_PATH).join("with_migration_warnings");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_preserves_existing_project() {
+    let project_path = Path::new(FIXTURES_PATH).join("existing_project");
 
     assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
@@ -1351,27 +1385,985 @@ fn test_manage_warnings_dry_run() {
     ----- stderr -----
     Migrated pyproject.toml:
     [project]
-    name = "foo"
-    version = "0.0.1"
-    requires-python = ">=3.11,<4"
+    name = "foobar"
+    version = "1.0.0"
+    description = "A description"
+    requires-python = ">=3.13"
     dependencies = ["arrow>=1.2.3,<2"]
 
-    [project.optional-dependencies]
-    extra-with-non-existing-dependencies = []
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1,<4"]
+    typing = ["mypy>=1.13.0,<2"]
+
+    [tool.uv]
+    default-groups = "all"
+    "#);
+}
+
+#[test]
+fn test_replaces_existing_project() {
+    let project_path = Path::new(FIXTURES_PATH).join("existing_project");
+
+    assert_cmd_snapshot!(cli()
+        .arg(&project_path)
+        .arg("--dry-run")
+        .arg("--replace-project-section"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    description = "A description"
+    requires-python = ">=3.11,<4"
+    dependencies = ["arrow>=1.2.3,<2"]
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1,<4"]
+    typing = ["mypy>=1.13.0,<2"]
+
+    [tool.uv]
+    default-groups = "all"
+    "#);
+}
+
+#[test]
+fn test_pep_621() {
+    let project_path = Path::new(FIXTURES_PATH).join("pep_621");
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.11"
+    readme = "README.md"
+    license = "MIT"
+    maintainers = [{ name = "Dohn Joe", email = "dohn.joe@example.com" }]
+    keywords = ["foo"]
+    classifiers = ["Development Status :: 3 - Alpha"]
+    dependencies = [
+        "arrow==1.2.3",
+        "git-dep",
+        "private-dep==3.4.5",
+    ]
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1,<4"]
+    typing = ["mypy>=1.13.0,<2"]
+    profiling = ["pyinstrument>=5.0.2,<6"]
+
+    [tool.uv]
+    default-groups = [
+        "dev",
+        "typing",
+    ]
+
+    [[tool.uv.index]]
+    name = "PyPI"
+    url = "https://pypi.org/simple/"
+
+    [[tool.uv.index]]
+    name = "supplemental"
+    url = "https://supplemental.example.com/simple/"
+
+    [tool.uv.sources]
+    git-dep = { git = "https://example.com/foo/bar", tag = "v1.2.3" }
+    private-dep = { index = "supplemental" }
+
+    [tool.ruff]
+    fix = true
+
+    [tool.ruff.lint]
+    # This comment should be preserved.
+    fixable = ["I", "UP"]
+
+    [tool.ruff.format]
+    preview = true
+    "#);
+}
+
+#[test]
+fn test_manage_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
+    let pyproject = fs::read_to_string(fixture_path.join("pyproject.toml")).unwrap();
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
+    error: - Dependency "optional-not-in-extra" is marked as optional, but is not referenced by any extra in "[tool.poetry.extras]". Either reference it in an extra, or remove it.
+    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
+    error: - "caret-or" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-single" dependency with version "^1.0|^3.0|^5.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-table-version" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.2.1,<1.2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "operator-without-version" dependency with version ">= <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that previous package manager files have not been removed.
+    assert!(project_path.join("poetry.lock").exists());
+    assert!(project_path.join("poetry.toml").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_manage_errors_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--ignore-errors").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
+    error: - Dependency "optional-not-in-extra" is marked as optional, but is not referenced by any extra in "[tool.poetry.extras]". Either reference it in an extra, or remove it.
+    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
+    error: - "caret-or" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-single" dependency with version "^1.0|^3.0|^5.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-table-version" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.2.1,<1.2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "operator-without-version" dependency with version ">= <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Locking dependencies again using "uv lock" to remove constraints...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Partially migrated project from Poetry to uv, as errors occurred during the migration.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foobar"
+    version = "0.0.1"
+    description = ""
+    authors = []
+    requires-python = ">=3.11"
+    dependencies = ["arrow==1.2.3"]
+
+    [dependency-groups]
+    dev = ["pytest==9.0.2"]
+    profiling = ["pyinstrument==5.1.2"]
+
+    [tool.uv]
+    default-groups = "all"
+    "#);
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("poetry.lock").exists());
+    assert!(!project_path.join("poetry.toml").exists());
+}
+
+#[test]
+fn test_manage_warnings() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("with_migration_warnings");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Locking dependencies again using "uv lock" to remove constraints...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Successfully migrated project from Poetry to uv!
+
+    warning: Could not find dependency "non-existing-dependency" listed in "extra-with-non-existing-dependencies" extra.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11,<4"
+    dependencies = ["arrow>=1.2.3,<2"]
+
+    [project.optional-dependencies]
+    extra-with-non-existing-dependencies = []
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1,<4"]
+    typing = ["mypy>=1.13.0,<2"]
+    profiling = ["pyinstrument>=5.0.2,<6"]
+
+    [tool.uv]
+    package = false
+    default-groups = [
+        "dev",
+        "typing",
+    ]
+    "#);
+
+    let uv_lock = toml::from_str::<UvLock>(
+        fs::read_to_string(project_path.join("uv.lock"))
+            .unwrap()
+            .as_str(),
+    )
+    .unwrap();
+
+    // Assert that locked versions in `uv.lock` match what was in `poetry.lock`.
+    let uv_lock_packages = uv_lock.package.unwrap();
+    let expected_locked_packages = Vec::from([
+        LockedPackage {
+            name: "arrow".to_string(),
+            version: "1.2.3".to_string(),
+        },
+        LockedPackage {
+            name: "factory-boy".to_string(),
+            version: "3.2.1".to_string(),
+        },
+        LockedPackage {
+            name: "faker".to_string(),
+            version: "33.1.0".to_string(),
+        },
+        LockedPackage {
+            name: "foo".to_string(),
+            version: "0.0.1".to_string(),
+        },
+        LockedPackage {
+            name: "mypy".to_string(),
+            version: "1.13.0".to_string(),
+        },
+        LockedPackage {
+            name: "mypy-extensions".to_string(),
+            version: "1.0.0".to_string(),
+        },
+        LockedPackage {
+            name: "python-dateutil".to_string(),
+            version: "2.7.0".to_string(),
+        },
+        LockedPackage {
+            name: "six".to_string(),
+            version: "1.15.0".to_string(),
+        },
+        LockedPackage {
+            name: "typing-extensions".to_string(),
+            version: "4.6.0".to_string(),
+        },
+    ]);
+    for package in expected_locked_packages {
+        assert!(uv_lock_packages.contains(&package));
+    }
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("poetry.lock").exists());
+    assert!(!project_path.join("poetry.toml").exists());
+}
+
+#[test]
+fn test_manage_errors_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
+    error: - Dependency "optional-not-in-extra" is marked as optional, but is not referenced by any extra in "[tool.poetry.extras]". Either reference it in an extra, or remove it.
+    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
+    error: - "caret-or" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-single" dependency with version "^1.0|^3.0|^5.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-table-version" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.2.1,<1.2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "operator-without-version" dependency with version ">= <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_manage_errors_dry_run_ignore_errors() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_migration_errors");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--ignore-errors").arg("--dependency-groups-strategy").arg("set-default-groups-all"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - Could not migrate dependency groups with "set-default-groups-all" strategy because there are optional groups.
+    error: - Dependency "optional-not-in-extra" is marked as optional, but is not referenced by any extra in "[tool.poetry.extras]". Either reference it in an extra, or remove it.
+    error: - Found multiple files ("README.md", "README2.md") in "tool.poetry.readme". PEP 621 only supports setting one. Make sure to manually edit the section before migrating.
+    error: - "caret-or" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-single" dependency with version "^1.0|^3.0|^5.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-whitespaces" dependency with version " ^1.0 || ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-mix-single-double-whitespaces" dependency with version " ^1.0 | ^3.0  ||  ^5.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-and-pep-440" dependency with version "^1.0,<1.3||^2.0,<2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-table-version" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0||^5.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "caret-or-multiple-constraints" dependency with version "^1.0||^3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-single" dependency with version "~1.0|~2.0|~3.0" contains "|", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-whitespaces" dependency with version " ~1.0 || ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-mix-single-double-whitespaces" dependency with version " ~1.0 | ~2.0  ||  ~3.0 " contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-and-pep-440" dependency with version "~1.0,<1.1||~1.2.1,<1.2.2" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-table-version" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0||~3.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "tilde-or-multiple-constraints" dependency with version "~1.0||~2.0" contains "||", which is specific to Poetry and not supported by PEP 440. See https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#operator for guidance.
+    error: - "operator-without-version" dependency with version ">= <7.1" could not be transformed to PEP 440 format. Make sure to check https://osprey-oss.github.io/migrate-to-uv/supported-package-managers/#unsupported-version-specifiers.
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.0.1"
+    description = ""
+    authors = []
+    requires-python = ">=3.11"
+    dependencies = ["arrow==1.2.3"]
+
+    [dependency-groups]
+    dev = ["pytest==9.0.2"]
+    profiling = ["pyinstrument==5.1.2"]
+
+    [tool.uv]
+    default-groups = "all"
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_manage_warnings_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("with_migration_warnings");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foo"
+    version = "0.0.1"
+    requires-python = ">=3.11,<4"
+    dependencies = ["arrow>=1.2.3,<2"]
+
+    [project.optional-dependencies]
+    extra-with-non-existing-dependencies = []
+
+    [dependency-groups]
+    dev = ["factory-boy>=3.2.1,<4"]
+    typing = ["mypy>=1.13.0,<2"]
+    profiling = ["pyinstrument>=5.0.2,<6"]
+
+    [tool.uv]
+    package = false
+    default-groups = [
+        "dev",
+        "typing",
+    ]
+
+    warning: Could not find dependency "non-existing-dependency" listed in "extra-with-non-existing-dependencies" extra.
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_build_backend_auto_hatch() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_before =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    remove_dir_all(project_path.join("dist")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from Poetry to uv!
+
+    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.hatch.build.targets.sdist]
+    include = [
+        "packages_sdist_wheel",
+        "packages_sdist_wheel_2",
+        "packages_sdist",
+        "packages_sdist_2",
+        "packages_glob_sdist_wheel/**/*.py",
+        "packages_glob_sdist_wheel_2/**/*.py",
+        "packages_glob_sdist/**/*.py",
+        "packages_glob_sdist_2/**/*.py",
+        "from/packages_from_sdist_wheel",
+        "packages_to_sdist_wheel",
+        "from/packages_from_to_sdist_wheel",
+        "packages_sdist_wheel_with_excluded_files",
+        "text_file_sdist_wheel.txt",
+        "text_file_sdist.txt",
+    ]
+    exclude = [
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
+    ]
+
+    [tool.hatch.build.targets.sdist.force-include]
+    include_sdist = "include_sdist"
+    include_sdist_2 = "include_sdist_2"
+    include_sdist_3 = "include_sdist_3"
+    include_sdist_4 = "include_sdist_4"
+    include_sdist_wheel = "include_sdist_wheel"
+
+    [tool.hatch.build.targets.wheel]
+    include = [
+        "packages_sdist_wheel",
+        "packages_sdist_wheel_2",
+        "packages_wheel",
+        "packages_wheel_2",
+        "packages_glob_sdist_wheel/**/*.py",
+        "packages_glob_sdist_wheel_2/**/*.py",
+        "packages_glob_wheel/**/*.py",
+        "packages_glob_wheel_2/**/*.py",
+        "from/packages_from_sdist_wheel",
+        "packages_to_sdist_wheel",
+        "from/packages_from_to_sdist_wheel",
+        "packages_sdist_wheel_with_excluded_files",
+        "text_file_sdist_wheel.txt",
+        "text_file_wheel.txt",
+    ]
+    exclude = [
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
+    ]
+
+    [tool.hatch.build.targets.wheel.force-include]
+    include_sdist_wheel = "include_sdist_wheel"
+    include_wheel = "include_wheel"
+    include_wheel_2 = "include_wheel_2"
+
+    [tool.hatch.build.targets.wheel.sources]
+    "from/packages_from_sdist_wheel" = "packages_from_sdist_wheel"
+    packages_to_sdist_wheel = "to/packages_to_sdist_wheel"
+    "from/packages_from_to_sdist_wheel" = "to/packages_from_to_sdist_wheel"
+    "#);
+
+    build_packages(&PackageBuilder::Hatch, project_path);
+
+    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_after =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    assert_eq!(sdist_files_before, sdist_files_after);
+    assert_eq!(wheel_files_before, wheel_files_after);
+}
+
+#[test]
+fn test_build_backend_auto_uv() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_before =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    remove_dir_all(project_path.join("dist")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from Poetry to uv!
+    ");
+
+    apply_filters!();
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.uv.build-backend]
+    module-name = [
+        "packages_sdist_wheel",
+        "packages_sdist_wheel_2",
+        "packages_sdist",
+        "packages_sdist_2",
+        "packages_sdist_wheel_with_excluded_files",
+    ]
+    module-root = ""
+    source-exclude = [
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
+    ]
+    source-include = [
+        "packages_glob_sdist/**/*.py",
+        "packages_glob_sdist_2/**/*.py",
+        "text_file_sdist.txt",
+        "FILE_WITHOUT_EXTENSION_SDIST",
+        "include_sdist/**",
+        "include_sdist_2/**",
+        "include_sdist_3/**",
+        "include_sdist_4/**",
+        "INCLUDE_FILE_WITHOUT_EXTENSION_SDIST",
+    ]
+    wheel-exclude = [
+        "packages_sdist",
+        "packages_sdist_2",
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
+    ]
+    "#);
+
+    build_packages(&PackageBuilder::Uv, project_path);
+
+    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_after =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    assert_eq!(sdist_files_before, sdist_files_after);
+    assert_eq!(wheel_files_before, wheel_files_after);
+}
+
+#[test]
+fn test_build_backend_auto_keep_current_build_backend() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_before =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    remove_dir_all(project_path.join("dist")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--keep-current-build-backend"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from Poetry to uv!
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+
+    [build-system]
+    requires = ["poetry-core>=1.0.0"]
+    build-backend = "poetry.core.masonry.api"
+
+    [tool.poetry]
+    packages = [
+        { include = "packages_sdist_wheel" },
+        { include = "packages_sdist_wheel_2", format = ["sdist", "wheel"] },
+        { include = "packages_sdist", format = "sdist" },
+        { include = "packages_sdist_2", format = ["sdist"] },
+        { include = "packages_wheel", format = "wheel" },
+        { include = "packages_wheel_2", format = ["wheel"] },
+        # An empty array for `format` means that files are not included anywhere.
+        { include = "packages_nowhere", format = [] },
+        { include = "packages_glob_sdist_wheel/**/*.py" },
+        { include = "packages_glob_sdist_wheel_2/**/*.py", format = ["sdist", "wheel"] },
+        { include = "packages_glob_sdist/**/*.py", format = "sdist" },
+        { include = "packages_glob_sdist_2/**/*.py", format = ["sdist"] },
+        { include = "packages_glob_wheel/**/*.py", format = "wheel" },
+        { include = "packages_glob_wheel_2/**/*.py", format = ["wheel"] },
+        # An empty array for `format` means that files are not included anywhere.
+        { include = "packages_glob_nowhere/**/*.py", format = [] },
+        { include = "packages_from_sdist_wheel", from = "from" },
+        { include = "packages_to_sdist_wheel", to = "to" },
+        { include = "packages_from_to_sdist_wheel", from = "from", to = "to" },
+        { include = "packages_sdist_wheel_with_excluded_files" },
+        { include = "text_file_sdist_wheel.txt" },
+        { include = "text_file_sdist.txt", format = "sdist" },
+        { include = "text_file_wheel.txt", format = "wheel" },
+    ]
+    include = [
+        "include_sdist",
+        { path = "include_sdist_2" },
+        { path = "include_sdist_3", format = "sdist" },
+        { path = "include_sdist_4", format = ["sdist"] },
+        { path = "include_sdist_wheel", format = ["sdist", "wheel"] },
+        { path = "include_wheel", format = "wheel" },
+        { path = "include_wheel_2", format = ["wheel"] },
+        # An empty array for `format` means that files are not included anywhere.
+        { path = "include_nowhere", format = [] },
+    ]
+    exclude = [
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
+    ]
+    "#);
+
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
+    let wheel_files_after =
+        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+
+    assert_eq!(sdist_files_before, sdist_files_after);
+    assert_eq!(wheel_files_before, wheel_files_after);
+}
+
+#[test]
+fn test_build_backend_auto_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - Package distribution could not be migrated to uv nor Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    "#);
+}
+
+#[test]
+fn test_build_backend_auto_errors_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] package in [TIME]
+    Partially migrated project from Poetry to uv, as errors occurred during the migration.
+
+    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
+    warning: Files included in source distribution differ after migrating build backend to Hatch (removed: "bar.json", "bar.txt", "file_sdist_wheel.json", "from/foo.json", "from/foo.txt", "from/foo.yaml", "from/foobar.txt", "from_multi_level/a_directory/foo.txt", "from_multi_level_2/a_directory/another_directory/foo.txt").
+    warning: Files included in wheels differ after migrating build backend to Hatch (added: "from/packages_glob_from/foo/bar.py", "from/packages_glob_from_to/foo/bar.py", "packages_glob_to/foo/bar.py"; removed: "foo.json", "foo.txt", "packages_glob_from/foo/bar.py", "parent_directory/sub_directory/a_directory/another_directory/foo.txt", "parent_directory/sub_directory/a_directory/foo.txt", "parent_directory/sub_directory/bar.txt", "parent_directory/sub_directory/foobar.txt", "to/json/bar.json", "to/json/file_sdist_wheel.json", "to/json/from/foo.json", "to/packages_glob_from_to/foo/bar.py", "to/packages_glob_to/foo/bar.py", "to/yaml/foo.yaml").
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.hatch.build.targets.sdist]
+    include = ["foo"]
+
+    [tool.hatch.build.targets.wheel]
+    include = ["foo"]
+    "#);
+}
+
+#[test]
+fn test_build_backend_auto_errors_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - Package distribution could not be migrated to uv nor Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+}
+
+#[test]
+fn test_build_backend_auto_errors_dry_run_ignore_errors() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
 
-    [dependency-groups]
-    dev = ["factory-boy>=3.2.1,<4"]
-    typing = ["mypy>=1.13.0,<2"]
-    profiling = ["pyinstrument>=5.0.2,<6"]
+    [tool.hatch.build.targets.sdist]
+    include = ["foo"]
 
-    [tool.uv]
-    package = false
-    default-groups = [
-        "dev",
-        "typing",
-    ]
+    [tool.hatch.build.targets.wheel]
+    include = ["foo"]
 
-    warning: Could not find dependency "non-existing-dependency" listed in "extra-with-non-existing-dependencies" extra.
+    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
+    warning: Files included in source distribution differ after migrating build backend to Hatch (removed: "bar.json", "bar.txt", "file_sdist_wheel.json", "from/foo.json", "from/foo.txt", "from/foo.yaml", "from/foobar.txt", "from_multi_level/a_directory/foo.txt", "from_multi_level_2/a_directory/another_directory/foo.txt").
+    warning: Files included in wheels differ after migrating build backend to Hatch (added: "from/packages_glob_from/foo/bar.py", "from/packages_glob_from_to/foo/bar.py", "packages_glob_to/foo/bar.py"; removed: "foo.json", "foo.txt", "packages_glob_from/foo/bar.py", "parent_directory/sub_directory/a_directory/another_directory/foo.txt", "parent_directory/sub_directory/a_directory/foo.txt", "parent_directory/sub_directory/bar.txt", "parent_directory/sub_directory/foobar.txt", "to/json/bar.json", "to/json/file_sdist_wheel.json", "to/json/from/foo.json", "to/packages_glob_from_to/foo/bar.py", "to/packages_glob_to/foo/bar.py", "to/yaml/foo.yaml").
     "#);
 
     // Assert that `pyproject.toml` was not updated.
@@ -1379,13 +2371,10 @@ fn test_manage_warnings_dry_run() {
         pyproject,
         fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
     );
-
-    // Assert that `uv.lock` file was not generated.
-    assert!(!project_path.join("uv.lock").exists());
 }
 
 #[test]
-fn test_build_backend_auto_hatch() {
+fn test_build_backend_hatch() {
     let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch");
 
     let tmp_dir = tempdir().unwrap();
@@ -1401,16 +2390,13 @@ fn test_build_backend_auto_hatch() {
 
     remove_dir_all(project_path.join("dist")).unwrap();
 
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("hatch"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
@@ -1506,38 +2492,83 @@ fn test_build_backend_auto_hatch() {
 }
 
 #[test]
-fn test_build_backend_auto_uv() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv");
+fn test_build_backend_hatch_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
 
     copy_dir(&fixture_path, project_path).unwrap();
 
+    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
     build_packages(&PackageBuilder::Poetry, project_path);
 
-    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_before =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("hatch"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
 
-    remove_dir_all(project_path.join("dist")).unwrap();
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    "#);
+}
 
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+#[test]
+fn test_build_backend_hatch_errors_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(&fixture_path, project_path).unwrap();
+
+    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
+    build_packages(&PackageBuilder::Poetry, project_path);
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("hatch").arg("--ignore-errors"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Successfully migrated project from Poetry to uv!
+    error: The following errors occurred during the migration:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] package in [TIME]
+    Partially migrated project from Poetry to uv, as errors occurred during the migration.
 
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
-    ");
+    warning: Files included in source distribution differ after migrating build backend to Hatch (removed: "bar.json", "bar.txt", "file_sdist_wheel.json", "from/foo.json", "from/foo.txt", "from/foo.yaml", "from/foobar.txt", "from_multi_level/a_directory/foo.txt", "from_multi_level_2/a_directory/another_directory/foo.txt").
+    warning: Files included in wheels differ after migrating build backend to Hatch (added: "from/packages_glob_from/foo/bar.py", "from/packages_glob_from_to/foo/bar.py", "packages_glob_to/foo/bar.py"; removed: "foo.json", "foo.txt", "packages_glob_from/foo/bar.py", "parent_directory/sub_directory/a_directory/another_directory/foo.txt", "parent_directory/sub_directory/a_directory/foo.txt", "parent_directory/sub_directory/bar.txt", "parent_directory/sub_directory/foobar.txt", "to/json/bar.json", "to/json/file_sdist_wheel.json", "to/json/from/foo.json", "to/packages_glob_from_to/foo/bar.py", "to/packages_glob_to/foo/bar.py", "to/yaml/foo.yaml").
+    "#);
 
-    apply_filters!();
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
     [build-system]
-    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
-    build-backend = "uv_build"
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
 
     [project]
     name = "foobar"
@@ -1554,51 +2585,110 @@ fn test_build_backend_auto_uv() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.uv.build-backend]
-    module-name = [
-        "packages_sdist_wheel",
-        "packages_sdist_wheel_2",
-        "packages_sdist",
-        "packages_sdist_2",
-        "packages_sdist_wheel_with_excluded_files",
-    ]
-    module-root = ""
-    source-exclude = [
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
-    ]
-    source-include = [
-        "packages_glob_sdist/**/*.py",
-        "packages_glob_sdist_2/**/*.py",
-        "text_file_sdist.txt",
-        "FILE_WITHOUT_EXTENSION_SDIST",
-        "include_sdist/**",
-        "include_sdist_2/**",
-        "include_sdist_3/**",
-        "include_sdist_4/**",
-        "INCLUDE_FILE_WITHOUT_EXTENSION_SDIST",
-    ]
-    wheel-exclude = [
-        "packages_sdist",
-        "packages_sdist_2",
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
-    ]
+    [tool.hatch.build.targets.sdist]
+    include = ["foo"]
+
+    [tool.hatch.build.targets.wheel]
+    include = ["foo"]
     "#);
+}
 
-    build_packages(&PackageBuilder::Uv, project_path);
+#[test]
+fn test_build_backend_hatch_errors_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
 
-    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_after =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("hatch"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
 
-    assert_eq!(sdist_files_before, sdist_files_after);
-    assert_eq!(wheel_files_before, wheel_files_after);
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
 }
 
 #[test]
-fn test_build_backend_auto_keep_current_build_backend() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch");
+fn test_build_backend_hatch_errors_dry_run_ignore_errors() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("hatch").arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
+    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
+    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
+    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
+    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
+    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["hatchling"]
+    build-backend = "hatchling.build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.hatch.build.targets.sdist]
+    include = ["foo"]
+
+    [tool.hatch.build.targets.wheel]
+    include = ["foo"]
+
+    warning: Files included in source distribution differ after migrating build backend to Hatch (removed: "bar.json", "bar.txt", "file_sdist_wheel.json", "from/foo.json", "from/foo.txt", "from/foo.yaml", "from/foobar.txt", "from_multi_level/a_directory/foo.txt", "from_multi_level_2/a_directory/another_directory/foo.txt").
+    warning: Files included in wheels differ after migrating build backend to Hatch (added: "from/packages_glob_from/foo/bar.py", "from/packages_glob_from_to/foo/bar.py", "packages_glob_to/foo/bar.py"; removed: "foo.json", "foo.txt", "packages_glob_from/foo/bar.py", "parent_directory/sub_directory/a_directory/another_directory/foo.txt", "parent_directory/sub_directory/a_directory/foo.txt", "parent_directory/sub_directory/bar.txt", "parent_directory/sub_directory/foobar.txt", "to/json/bar.json", "to/json/file_sdist_wheel.json", "to/json/from/foo.json", "to/packages_glob_from_to/foo/bar.py", "to/packages_glob_to/foo/bar.py", "to/yaml/foo.yaml").
+    "#);
+
+    // Assert that `pyproject.toml` was not updated.
+    assert_eq!(
+        pyproject,
+        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
+    );
+}
+
+#[test]
+fn test_build_backend_uv() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
@@ -1613,7 +2703,7 @@ fn test_build_backend_auto_keep_current_build_backend() {
 
     remove_dir_all(project_path.join("dist")).unwrap();
 
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--keep-current-build-backend"), @r"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("uv"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -1622,62 +2712,60 @@ fn test_build_backend_auto_keep_current_build_backend() {
     Successfully migrated project from Poetry to uv!
     ");
 
+    apply_filters!();
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
+
     [project]
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
     authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
 
-    [build-system]
-    requires = ["poetry-core>=1.0.0"]
-    build-backend = "poetry.core.masonry.api"
-
-    [tool.poetry]
-    packages = [
-        { include = "packages_sdist_wheel" },
-        { include = "packages_sdist_wheel_2", format = ["sdist", "wheel"] },
-        { include = "packages_sdist", format = "sdist" },
-        { include = "packages_sdist_2", format = ["sdist"] },
-        { include = "packages_wheel", format = "wheel" },
-        { include = "packages_wheel_2", format = ["wheel"] },
-        # An empty array for `format` means that files are not included anywhere.
-        { include = "packages_nowhere", format = [] },
-        { include = "packages_glob_sdist_wheel/**/*.py" },
-        { include = "packages_glob_sdist_wheel_2/**/*.py", format = ["sdist", "wheel"] },
-        { include = "packages_glob_sdist/**/*.py", format = "sdist" },
-        { include = "packages_glob_sdist_2/**/*.py", format = ["sdist"] },
-        { include = "packages_glob_wheel/**/*.py", format = "wheel" },
-        { include = "packages_glob_wheel_2/**/*.py", format = ["wheel"] },
-        # An empty array for `format` means that files are not included anywhere.
-        { include = "packages_glob_nowhere/**/*.py", format = [] },
-        { include = "packages_from_sdist_wheel", from = "from" },
-        { include = "packages_to_sdist_wheel", to = "to" },
-        { include = "packages_from_to_sdist_wheel", from = "from", to = "to" },
-        { include = "packages_sdist_wheel_with_excluded_files" },
-        { include = "text_file_sdist_wheel.txt" },
-        { include = "text_file_sdist.txt", format = "sdist" },
-        { include = "text_file_wheel.txt", format = "wheel" },
+    [tool.uv.build-backend]
+    module-name = [
+        "packages_sdist_wheel",
+        "packages_sdist_wheel_2",
+        "packages_sdist",
+        "packages_sdist_2",
+        "packages_sdist_wheel_with_excluded_files",
     ]
-    include = [
-        "include_sdist",
-        { path = "include_sdist_2" },
-        { path = "include_sdist_3", format = "sdist" },
-        { path = "include_sdist_4", format = ["sdist"] },
-        { path = "include_sdist_wheel", format = ["sdist", "wheel"] },
-        { path = "include_wheel", format = "wheel" },
-        { path = "include_wheel_2", format = ["wheel"] },
-        # An empty array for `format` means that files are not included anywhere.
-        { path = "include_nowhere", format = [] },
+    module-root = ""
+    source-exclude = [
+        "packages_sdist_wheel_with_excluded_files/bar.py",
+        "packages_sdist_wheel_with_excluded_files/foobar",
     ]
-    exclude = [
+    source-include = [
+        "packages_glob_sdist/**/*.py",
+        "packages_glob_sdist_2/**/*.py",
+        "text_file_sdist.txt",
+        "FILE_WITHOUT_EXTENSION_SDIST",
+        "include_sdist/**",
+        "include_sdist_2/**",
+        "include_sdist_3/**",
+        "include_sdist_4/**",
+        "INCLUDE_FILE_WITHOUT_EXTENSION_SDIST",
+    ]
+    wheel-exclude = [
+        "packages_sdist",
+        "packages_sdist_2",
         "packages_sdist_wheel_with_excluded_files/bar.py",
         "packages_sdist_wheel_with_excluded_files/foobar",
     ]
     "#);
 
-    build_packages(&PackageBuilder::Poetry, project_path);
+    build_packages(&PackageBuilder::Uv, project_path);
 
     let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
     let wheel_files_after =
@@ -1688,8 +2776,8 @@ fn test_build_backend_auto_keep_current_build_backend() {
 }
 
 #[test]
-fn test_build_backend_auto_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+fn test_build_backend_uv_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv_incompatible");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
@@ -1699,31 +2787,38 @@ fn test_build_backend_auto_errors() {
     // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
     build_packages(&PackageBuilder::Poetry, project_path);
 
-    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("uv"), @r#"
     success: false
     exit_code: 1
     ----- stdout -----
 
     ----- stderr -----
     error: Could not automatically migrate the project to uv because of the following errors:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - Package distribution could not be migrated to uv nor Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    error: - "poetry.packages" cannot be converted to uv, as packages are located in different directories with "from", which cannot be expressed with uv.
+    error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
+    error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
+    error: - "text_file_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and is a file, which cannot be expressed with uv.
+    error: - "include_sdist_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, which cannot be expressed with uv.
+    error: - "include_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "include_wheel_2" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - Package distribution could not be migrated to uv build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
     "#);
 }
 
 #[test]
-fn test_build_backend_auto_errors_ignore_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
+fn test_build_backend_uv_errors_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv_incompatible");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
@@ -1734,37 +2829,44 @@ fn test_build_backend_auto_errors_ignore_errors() {
     build_packages(&PackageBuilder::Poetry, project_path);
 
     apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--ignore-errors"), @r#"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("uv").arg("--ignore-errors"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
     error: The following errors occurred during the migration:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+    error: - "poetry.packages" cannot be converted to uv, as packages are located in different directories with "from", which cannot be expressed with uv.
+    error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
+    error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
+    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
+    error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
+    error: - "text_file_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and is a file, which cannot be expressed with uv.
+    error: - "include_sdist_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, which cannot be expressed with uv.
+    error: - "include_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
+    error: - "include_wheel_2" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
     Locking dependencies with constraints from existing lock file(s) using "uv lock"...
     Using [PYTHON_INTERPRETER]
     Resolved [PACKAGES] package in [TIME]
     Partially migrated project from Poetry to uv, as errors occurred during the migration.
 
-    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    warning: Files included in source distribution differ after migrating build backend to uv (removed: "from/packages_from_sdist_wheel/__init__.py", "from/packages_from_sdist_wheel/foo.py", "from/packages_from_to_sdist_wheel/__init__.py", "from/packages_from_to_sdist_wheel/foo.py", "from/packages_glob_from_to_sdist_wheel/__init__.py", "from/packages_glob_from_to_sdist_wheel/foo/bar.py", "include_sdist_wheel/__init__.py", "include_sdist_wheel/foo.py", "packages_glob_sdist_wheel/__init__.py", "packages_glob_sdist_wheel/foo/bar.py", "packages_glob_sdist_wheel_2/__init__.py", "packages_glob_sdist_wheel_2/foo/bar.py", "packages_glob_to_sdist_wheel/__init__.py", "packages_glob_to_sdist_wheel/foo/bar.py", "packages_to_sdist_wheel/__init__.py", "packages_to_sdist_wheel/foo.py", "text_file_sdist_wheel.txt").
+    warning: Files included in wheels differ after migrating build backend to uv (removed: "include_sdist_wheel/__init__.py", "include_sdist_wheel/foo.py", "include_wheel/__init__.py", "include_wheel/foo.py", "include_wheel_2/__init__.py", "include_wheel_2/foo.py", "packages_from_sdist_wheel/__init__.py", "packages_from_sdist_wheel/foo.py", "packages_glob_sdist_wheel/__init__.py", "packages_glob_sdist_wheel/foo/bar.py", "packages_glob_sdist_wheel_2/__init__.py", "packages_glob_sdist_wheel_2/foo/bar.py", "packages_glob_wheel/__init__.py", "packages_glob_wheel/foo/bar.py", "packages_glob_wheel_2/__init__.py", "packages_glob_wheel_2/foo/bar.py", "packages_wheel/__init__.py", "packages_wheel/foo.py", "packages_wheel_2/__init__.py", "packages_wheel_2/foo.py", "text_file_sdist_wheel.txt", "text_file_wheel.txt", "to/packages_from_to_sdist_wheel/__init__.py", "to/packages_from_to_sdist_wheel/foo.py", "to/packages_glob_from_to_sdist_wheel/__init__.py", "to/packages_glob_from_to_sdist_wheel/foo/bar.py", "to/packages_glob_to_sdist_wheel/__init__.py", "to/packages_glob_to_sdist_wheel/foo/bar.py", "to/packages_to_sdist_wheel/__init__.py", "to/packages_to_sdist_wheel/foo.py").
     "#);
 
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
     [build-system]
-    requires = ["hatchling"]
-    build-backend = "hatchling.build"
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
 
     [project]
     name = "foobar"
@@ -1781,81 +2883,111 @@ fn test_build_backend_auto_errors_ignore_errors() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.hatch.build.targets.sdist]
-    include = ["foo"]
-
-    [tool.hatch.build.targets.wheel]
-    include = ["foo"]
+    [tool.uv.build-backend]
+    module-name = ["foo"]
+    module-root = ""
     "#);
 }
 
 #[test]
-fn test_build_backend_auto_errors_dry_run() {
-    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_build_backend_packages_from_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/packages_from");
 
+    apply_filters!();
     assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
-    success: false
-    exit_code: 1
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Could not automatically migrate the project to uv because of the following errors:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - Package distribution could not be migrated to uv nor Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.uv.build-backend]
+    module-name = [
+        "foo",
+        "bar",
+    ]
+    module-root = "src"
+    source-include = ["src/data/*.json"]
     "#);
+}
 
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
+#[test]
+fn test_build_backend_vcs_ignored_files_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/vcs_ignored_files");
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.uv.build-backend]
+    module-name = ["foo"]
+    module-root = ""
+
+    warning: Files included in source distribution differ after migrating build backend to uv (added: "foo/_speedups.so").
+    warning: Files included in wheels differ after migrating build backend to uv (added: "foo/_speedups.so").
+    "#);
 }
 
 #[test]
-fn test_build_backend_auto_errors_dry_run_ignore_errors() {
-    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_build_backend_file_scripts_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/file_scripts");
 
     apply_filters!();
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--ignore-errors"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
     Migrated pyproject.toml:
     [build-system]
-    requires = ["hatchling"]
-    build-backend = "hatchling.build"
+    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
+    build-backend = "uv_build"
 
     [project]
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -1866,52 +2998,31 @@ fn test_build_backend_auto_errors_dry_run_ignore_errors() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.hatch.build.targets.sdist]
-    include = ["foo"]
+    [project.optional-dependencies]
+    cli = ["click>=8.1.0,<9"]
 
-    [tool.hatch.build.targets.wheel]
-    include = ["foo"]
+    [project.scripts]
+    console-script = "foo:run"
+    cli-script = "foo:cli [cli]"
 
-    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    [tool.uv.build-backend]
+    module-name = ["foo"]
+    module-root = ""
+    data = { scripts = "bin" }
     "#);
-
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
 }
 
 #[test]
-fn test_build_backend_hatch() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch");
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    build_packages(&PackageBuilder::Poetry, project_path);
-
-    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_before =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
+fn test_build_backend_file_scripts_hatch_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/file_scripts");
 
-    remove_dir_all(project_path.join("dist")).unwrap();
-
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("hatch"), @r"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--build-backend").arg("hatch").arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
-    ");
-
-    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    Migrated pyproject.toml:
     [build-system]
     requires = ["hatchling"]
     build-backend = "hatchling.build"
@@ -1920,7 +3031,6 @@ fn test_build_backend_hatch() {
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -1931,161 +3041,46 @@ fn test_build_backend_hatch() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.hatch.build.targets.sdist]
-    include = [
-        "packages_sdist_wheel",
-        "packages_sdist_wheel_2",
-        "packages_sdist",
-        "packages_sdist_2",
-        "packages_glob_sdist_wheel/**/*.py",
-        "packages_glob_sdist_wheel_2/**/*.py",
-        "packages_glob_sdist/**/*.py",
-        "packages_glob_sdist_2/**/*.py",
-        "from/packages_from_sdist_wheel",
-        "packages_to_sdist_wheel",
-        "from/packages_from_to_sdist_wheel",
-        "packages_sdist_wheel_with_excluded_files",
-        "text_file_sdist_wheel.txt",
-        "text_file_sdist.txt",
-    ]
-    exclude = [
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
-    ]
+    [project.optional-dependencies]
+    cli = ["click>=8.1.0,<9"]
 
-    [tool.hatch.build.targets.sdist.force-include]
-    include_sdist = "include_sdist"
-    include_sdist_2 = "include_sdist_2"
-    include_sdist_3 = "include_sdist_3"
-    include_sdist_4 = "include_sdist_4"
-    include_sdist_wheel = "include_sdist_wheel"
+    [project.scripts]
+    console-script = "foo:run"
+    cli-script = "foo:cli [cli]"
 
-    [tool.hatch.build.targets.wheel]
+    [tool.hatch.build.targets.sdist]
     include = [
-        "packages_sdist_wheel",
-        "packages_sdist_wheel_2",
-        "packages_wheel",
-        "packages_wheel_2",
-        "packages_glob_sdist_wheel/**/*.py",
-        "packages_glob_sdist_wheel_2/**/*.py",
-        "packages_glob_wheel/**/*.py",
-        "packages_glob_wheel_2/**/*.py",
-        "from/packages_from_sdist_wheel",
-        "packages_to_sdist_wheel",
-        "from/packages_from_to_sdist_wheel",
-        "packages_sdist_wheel_with_excluded_files",
-        "text_file_sdist_wheel.txt",
-        "text_file_wheel.txt",
-    ]
-    exclude = [
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
+        "foo",
+        "bin/file-script.sh",
     ]
 
-    [tool.hatch.build.targets.wheel.force-include]
-    include_sdist_wheel = "include_sdist_wheel"
-    include_wheel = "include_wheel"
-    include_wheel_2 = "include_wheel_2"
-
-    [tool.hatch.build.targets.wheel.sources]
-    "from/packages_from_sdist_wheel" = "packages_from_sdist_wheel"
-    packages_to_sdist_wheel = "to/packages_to_sdist_wheel"
-    "from/packages_from_to_sdist_wheel" = "to/packages_from_to_sdist_wheel"
-    "#);
-
-    build_packages(&PackageBuilder::Hatch, project_path);
-
-    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_after =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
-
-    assert_eq!(sdist_files_before, sdist_files_after);
-    assert_eq!(wheel_files_before, wheel_files_after);
-}
-
-#[test]
-fn test_build_backend_hatch_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
-    build_packages(&PackageBuilder::Poetry, project_path);
-
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("hatch"), @r#"
-    success: false
-    exit_code: 1
-    ----- stdout -----
+    [tool.hatch.build.targets.wheel]
+    include = ["foo"]
 
-    ----- stderr -----
-    error: Could not automatically migrate the project to uv because of the following errors:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
+    [tool.hatch.build.targets.wheel.shared-scripts]
+    "bin/file-script.sh" = "file-script.sh"
     "#);
 }
 
 #[test]
-fn test_build_backend_hatch_errors_ignore_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
-    build_packages(&PackageBuilder::Poetry, project_path);
+fn test_build_backend_file_scripts_flit_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/file_scripts");
 
-    apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("hatch").arg("--ignore-errors"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--build-backend").arg("flit").arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] package in [TIME]
-    Partially migrated project from Poetry to uv, as errors occurred during the migration.
-
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
-    "#);
-
-    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    Migrated pyproject.toml:
     [build-system]
-    requires = ["hatchling"]
-    build-backend = "hatchling.build"
+    requires = ["flit_core>=3.12,<4"]
+    build-backend = "flit_core.buildapi"
 
     [project]
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -2096,70 +3091,68 @@ fn test_build_backend_hatch_errors_ignore_errors() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.hatch.build.targets.sdist]
-    include = ["foo"]
+    [project.optional-dependencies]
+    cli = ["click>=8.1.0,<9"]
 
-    [tool.hatch.build.targets.wheel]
-    include = ["foo"]
+    [project.scripts]
+    console-script = "foo:run"
+    cli-script = "foo:cli [cli]"
+
+    [tool.flit.module]
+    name = "foo"
+
+    warning: File script "bin/file-script.sh" from "poetry.scripts" could not be migrated, as file scripts cannot be expressed with Flit.
+    warning: Build backend was migrated to Flit. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     "#);
 }
 
 #[test]
-fn test_build_backend_hatch_errors_dry_run() {
-    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_build_backend_file_scripts_keep_current_build_backend_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/file_scripts");
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("hatch"), @r#"
-    success: false
-    exit_code: 1
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--keep-current-build-backend").arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Could not automatically migrate the project to uv because of the following errors:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - Package distribution could not be migrated to Hatch build backend due to the issues above. Consider keeping the current build backend with "--keep-current-build-backend".
-    "#);
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
 
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
+    [project.optional-dependencies]
+    cli = ["click>=8.1.0,<9"]
+
+    [project.scripts]
+    console-script = "foo:run"
+    cli-script = "foo:cli [cli]"
+
+    [build-system]
+    requires = ["poetry-core>=2.0.0"]
+    build-backend = "poetry.core.masonry.api"
+
+    [tool.poetry]
+    packages = [{ include = "foo" }]
+
+    [tool.poetry.scripts]
+    file-script = { reference = "bin/file-script.sh", type = "file" }
+    "#);
 }
 
 #[test]
-fn test_build_backend_hatch_errors_dry_run_ignore_errors() {
-    let project_path = Path::new(FIXTURES_PATH).join("build_backend/hatch_incompatible");
-    let pyproject = fs::read_to_string(project_path.join("pyproject.toml")).unwrap();
+fn test_build_backend_file_scripts_uv_incompatible_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/file_scripts_uv_incompatible");
 
-    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("hatch").arg("--ignore-errors"), @r#"
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
-
-    ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - "foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" on a file, which cannot be expressed with Hatch.
-    error: - "bar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "to" on a file, which cannot be expressed with Hatch.
-    error: - "foobar.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "a_directory/another_directory/foo.txt" from "poetry.packages.include" cannot be converted to Hatch, as it uses "from" and "to" on a file, which cannot be expressed with Hatch.
-    error: - "packages_glob_from/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "packages_glob_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "packages_glob_from_to/**/*.py" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from", which cannot be expressed with Hatch.
-    error: - "**/*.json" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "to", which cannot be expressed with Hatch.
-    error: - "**/*.yaml" from "poetry.packages.include" cannot be converted to Hatch, as it uses glob pattern with "from" and "to", which cannot be expressed with Hatch.
+
+    ----- stderr -----
     Migrated pyproject.toml:
     [build-system]
     requires = ["hatchling"]
@@ -2169,7 +3162,6 @@ fn test_build_backend_hatch_errors_dry_run_ignore_errors() {
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -2180,40 +3172,39 @@ fn test_build_backend_hatch_errors_dry_run_ignore_errors() {
         "Programming Language :: Python :: 3.14",
     ]
 
+    [project.optional-dependencies]
+    cli = ["click>=8.1.0,<9"]
+
+    [project.scripts]
+    console-script = "foo:run"
+    cli-script = "foo:cli [cli]"
+
     [tool.hatch.build.targets.sdist]
-    include = ["foo"]
+    include = [
+        "foo",
+        "bin/file-script.sh",
+    ]
 
     [tool.hatch.build.targets.wheel]
     include = ["foo"]
 
-    warning: Build backend was migrated to Hatch. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
-    "#);
+    [tool.hatch.build.targets.wheel.shared-scripts]
+    "bin/file-script.sh" = "file-script.sh"
 
-    // Assert that `pyproject.toml` was not updated.
-    assert_eq!(
-        pyproject,
-        fs::read_to_string(project_path.join("pyproject.toml")).unwrap()
-    );
+    warning: Migrating build backend to Hatch, as package distribution is too complex to be expressed with uv.
+    "#);
 }
 
 #[test]
-fn test_build_backend_uv() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv");
+fn test_build_backend_setuptools() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/single_package");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
 
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    build_packages(&PackageBuilder::Poetry, project_path);
-
-    let sdist_files_before = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_before =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
-
-    remove_dir_all(project_path.join("dist")).unwrap();
+    copy_dir(fixture_path, project_path).unwrap();
 
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("uv"), @r"
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("setuptools"), @r"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -2221,20 +3212,18 @@ fn test_build_backend_uv() {
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
 
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    warning: Build backend was migrated to setuptools. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
-    apply_filters!();
     insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
     [build-system]
-    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
-    build-backend = "uv_build"
+    requires = ["setuptools>=77"]
+    build-backend = "setuptools.build_meta"
 
     [project]
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -2245,149 +3234,115 @@ fn test_build_backend_uv() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.uv.build-backend]
-    module-name = [
-        "packages_sdist_wheel",
-        "packages_sdist_wheel_2",
-        "packages_sdist",
-        "packages_sdist_2",
-        "packages_sdist_wheel_with_excluded_files",
-    ]
-    module-root = ""
-    source-exclude = [
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
-    ]
-    source-include = [
-        "packages_glob_sdist/**/*.py",
-        "packages_glob_sdist_2/**/*.py",
-        "text_file_sdist.txt",
-        "FILE_WITHOUT_EXTENSION_SDIST",
-        "include_sdist/**",
-        "include_sdist_2/**",
-        "include_sdist_3/**",
-        "include_sdist_4/**",
-        "INCLUDE_FILE_WITHOUT_EXTENSION_SDIST",
-    ]
-    wheel-exclude = [
-        "packages_sdist",
-        "packages_sdist_2",
-        "packages_sdist_wheel_with_excluded_files/bar.py",
-        "packages_sdist_wheel_with_excluded_files/foobar",
+    [tool.setuptools.packages.find]
+    where = ["src"]
+    include = [
+        "foo",
+        "foo.*",
     ]
     "#);
 
-    build_packages(&PackageBuilder::Uv, project_path);
-
-    let sdist_files_after = get_tar_gz_entries(&project_path.join("dist"), "foobar-0.1.0.tar.gz");
-    let wheel_files_after =
-        get_zip_entries(&project_path.join("dist"), "foobar-0.1.0-py3-none-any.whl");
-
-    assert_eq!(sdist_files_before, sdist_files_after);
-    assert_eq!(wheel_files_before, wheel_files_after);
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("MANIFEST.in")).unwrap(), @r"
+    graft src/foo
+    include CHANGELOG.md
+    graft docs
+    prune src/foo/tests
+    global-exclude *.py[cod]
+    ");
 }
 
 #[test]
-fn test_build_backend_uv_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv_incompatible");
+fn test_build_backend_setuptools_existing_manifest_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/single_package");
 
     let tmp_dir = tempdir().unwrap();
     let project_path = tmp_dir.path();
 
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
-    build_packages(&PackageBuilder::Poetry, project_path);
+    copy_dir(fixture_path, project_path).unwrap();
+    fs::write(project_path.join("MANIFEST.in"), "include README.md\n").unwrap();
 
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("uv"), @r#"
-    success: false
-    exit_code: 1
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--build-backend").arg("setuptools").arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: Could not automatically migrate the project to uv because of the following errors:
-    error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_from_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
-    error: - "text_file_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and is a file, which cannot be expressed with uv.
-    error: - "include_sdist_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, which cannot be expressed with uv.
-    error: - "include_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "include_wheel_2" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - Package distribution could not be migrated to uv build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
+    error: The following errors occurred during the migration:
+    error: - Package distribution cannot be converted to setuptools, as "MANIFEST.in" already exists.
+    Partially migrated project from Poetry to uv, as errors occurred during the migration.
+
+    warning: Build backend was migrated to setuptools. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     "#);
+
+    // Assert that the existing manifest was not overwritten.
+    assert_eq!(
+        fs::read_to_string(project_path.join("MANIFEST.in")).unwrap(),
+        "include README.md\n"
+    );
 }
 
 #[test]
-fn test_build_backend_uv_errors_ignore_errors() {
-    let fixture_path = Path::new(FIXTURES_PATH).join("build_backend/uv_incompatible");
-
-    let tmp_dir = tempdir().unwrap();
-    let project_path = tmp_dir.path();
-
-    copy_dir(&fixture_path, project_path).unwrap();
-
-    // Ensure that the project is valid for Poetry, even if we cannot convert it to uv.
-    build_packages(&PackageBuilder::Poetry, project_path);
+fn test_build_backend_flit_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/single_package");
 
-    apply_filters!();
-    assert_cmd_snapshot!(cli().arg(project_path).arg("--build-backend").arg("uv").arg("--ignore-errors"), @r#"
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("flit"), @r#"
     success: true
     exit_code: 0
     ----- stdout -----
 
     ----- stderr -----
-    error: The following errors occurred during the migration:
-    error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_from_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
-    error: - "text_file_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and is a file, which cannot be expressed with uv.
-    error: - "include_sdist_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, which cannot be expressed with uv.
-    error: - "include_wheel" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    error: - "include_wheel_2" from "poetry.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
-    Locking dependencies with constraints from existing lock file(s) using "uv lock"...
-    Using [PYTHON_INTERPRETER]
-    Resolved [PACKAGES] package in [TIME]
-    Partially migrated project from Poetry to uv, as errors occurred during the migration.
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["flit_core>=3.12,<4"]
+    build-backend = "flit_core.buildapi"
+
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
+    classifiers = [
+        "Programming Language :: Python :: 3",
+        "Programming Language :: Python :: 3.10",
+        "Programming Language :: Python :: 3.11",
+        "Programming Language :: Python :: 3.12",
+        "Programming Language :: Python :: 3.13",
+        "Programming Language :: Python :: 3.14",
+    ]
+
+    [tool.flit.module]
+    name = "foo"
+
+    [tool.flit.sdist]
+    include = [
+        "CHANGELOG.md",
+        "docs",
+    ]
+    exclude = ["src/foo/tests"]
 
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    warning: Build backend was migrated to Flit. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     "#);
+}
 
-    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+#[test]
+fn test_build_backend_pdm_backend_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/packages_from");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("pdm-backend"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
     [build-system]
-    requires = ["uv_build>=[LOWER_BOUND],<[UPPER_BOUND]"]
-    build-backend = "uv_build"
+    requires = ["pdm-backend"]
+    build-backend = "pdm.backend"
 
     [project]
     name = "foobar"
     version = "0.1.0"
     description = "A fabulous project."
-    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
     requires-python = ">=3.10"
     classifiers = [
         "Programming Language :: Python :: 3",
@@ -2398,9 +3353,60 @@ fn test_build_backend_uv_errors_ignore_errors() {
         "Programming Language :: Python :: 3.14",
     ]
 
-    [tool.uv.build-backend]
-    module-name = ["foo"]
-    module-root = ""
+    [tool.pdm.build]
+    includes = [
+        "src/foo",
+        "src/bar",
+    ]
+    source-includes = ["src/data/*.json"]
+    package-dir = "src"
+
+    warning: Build backend was migrated to pdm-backend. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    "#);
+}
+
+#[test]
+fn test_build_backend_setuptools_errors_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/uv_incompatible");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("setuptools"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "packages_wheel" from "poetry.packages.include" cannot be converted to setuptools, as it is configured to be added to wheels only, which cannot be expressed with setuptools.
+    error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to setuptools, as it is configured to be added to wheels only, which cannot be expressed with setuptools.
+    error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses globs, which cannot be expressed with setuptools.
+    error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses globs, which cannot be expressed with setuptools.
+    error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses globs, which cannot be expressed with setuptools.
+    error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses globs, which cannot be expressed with setuptools.
+    error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to setuptools, as it uses "to", which cannot be expressed with setuptools.
+    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to setuptools, as it uses "to", which cannot be expressed with setuptools.
+    error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses "to", which cannot be expressed with setuptools.
+    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to setuptools, as it uses "to", which cannot be expressed with setuptools.
+    error: - "text_file_wheel.txt" from "poetry.packages.include" cannot be converted to setuptools, as it is configured to be added to wheels only, which cannot be expressed with setuptools.
+    error: - "include_sdist_wheel" from "poetry.include" cannot be converted to setuptools, as it is configured to be added to wheels, which cannot be expressed with setuptools.
+    error: - "include_wheel" from "poetry.include" cannot be converted to setuptools, as it is configured to be added to wheels, which cannot be expressed with setuptools.
+    error: - "include_wheel_2" from "poetry.include" cannot be converted to setuptools, as it is configured to be added to wheels, which cannot be expressed with setuptools.
+    error: - Package distribution could not be migrated to setuptools build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
+    "#);
+}
+
+#[test]
+fn test_build_backend_flit_build_script_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("build_backend/build_script");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run").arg("--build-backend").arg("flit"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - Build backend cannot be migrated to Flit, as build script "build.py" is used, which cannot be expressed with Flit.
+    error: - Package distribution could not be migrated to Flit build backend due to the issues above. Consider using Hatch build backend with "--build-backend hatch".
     "#);
 }
 
@@ -2416,19 +3422,17 @@ fn test_build_backend_uv_errors_dry_run() {
 
     ----- stderr -----
     error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "poetry.packages" cannot be converted to uv, as packages are located in different directories with "from", which cannot be expressed with uv.
     error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
     error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
     error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_from_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
@@ -2462,19 +3466,17 @@ fn test_build_backend_uv_errors_dry_run_ignore_errors() {
 
     ----- stderr -----
     error: The following errors occurred during the migration:
+    error: - "poetry.packages" cannot be converted to uv, as packages are located in different directories with "from", which cannot be expressed with uv.
     error: - "packages_wheel" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
     error: - "packages_wheel_2" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, which cannot be expressed with uv.
     error: - "packages_glob_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_sdist_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
     error: - "packages_glob_wheel_2/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to wheels only, and uses globs, which cannot be expressed with uv.
-    error: - "packages_from_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
-    error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_from_to_sdist_wheel" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
-    error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "from", which cannot be expressed with uv.
     error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it uses "to", which cannot be expressed with uv.
     error: - "packages_glob_from_to_sdist_wheel/**/*.py" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and uses globs, which cannot be expressed with uv.
     error: - "text_file_sdist_wheel.txt" from "poetry.packages.include" cannot be converted to uv, as it is configured to be added to both source distribution and wheels, and is a file, which cannot be expressed with uv.
@@ -2506,7 +3508,8 @@ fn test_build_backend_uv_errors_dry_run_ignore_errors() {
     module-name = ["foo"]
     module-root = ""
 
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
+    warning: Files included in source distribution differ after migrating build backend to uv (removed: "from/packages_from_sdist_wheel/__init__.py", "from/packages_from_sdist_wheel/foo.py", "from/packages_from_to_sdist_wheel/__init__.py", "from/packages_from_to_sdist_wheel/foo.py", "from/packages_glob_from_to_sdist_wheel/__init__.py", "from/packages_glob_from_to_sdist_wheel/foo/bar.py", "include_sdist_wheel/__init__.py", "include_sdist_wheel/foo.py", "packages_glob_sdist_wheel/__init__.py", "packages_glob_sdist_wheel/foo/bar.py", "packages_glob_sdist_wheel_2/__init__.py", "packages_glob_sdist_wheel_2/foo/bar.py", "packages_glob_to_sdist_wheel/__init__.py", "packages_glob_to_sdist_wheel/foo/bar.py", "packages_to_sdist_wheel/__init__.py", "packages_to_sdist_wheel/foo.py", "text_file_sdist_wheel.txt").
+    warning: Files included in wheels differ after migrating build backend to uv (removed: "include_sdist_wheel/__init__.py", "include_sdist_wheel/foo.py", "include_wheel/__init__.py", "include_wheel/foo.py", "include_wheel_2/__init__.py", "include_wheel_2/foo.py", "packages_from_sdist_wheel/__init__.py", "packages_from_sdist_wheel/foo.py", "packages_glob_sdist_wheel/__init__.py", "packages_glob_sdist_wheel/foo/bar.py", "packages_glob_sdist_wheel_2/__init__.py", "packages_glob_sdist_wheel_2/foo/bar.py", "packages_glob_wheel/__init__.py", "packages_glob_wheel/foo/bar.py", "packages_glob_wheel_2/__init__.py", "packages_glob_wheel_2/foo/bar.py", "packages_wheel/__init__.py", "packages_wheel/foo.py", "packages_wheel_2/__init__.py", "packages_wheel_2/foo.py", "text_file_sdist_wheel.txt", "text_file_wheel.txt", "to/packages_from_to_sdist_wheel/__init__.py", "to/packages_from_to_sdist_wheel/foo.py", "to/packages_glob_from_to_sdist_wheel/__init__.py", "to/packages_glob_from_to_sdist_wheel/foo/bar.py", "to/packages_glob_to_sdist_wheel/__init__.py", "to/packages_glob_to_sdist_wheel/foo/bar.py", "to/packages_to_sdist_wheel/__init__.py", "to/packages_to_sdist_wheel/foo.py").
     "#);
 
     // Assert that `pyproject.toml` was not updated.
@@ -2540,8 +3543,6 @@ fn test_build_backend_implicit_package() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2604,8 +3605,6 @@ fn test_build_backend_implicit_package_src() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2668,8 +3667,6 @@ fn test_build_backend_implicit_package_src_and_no_src() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2732,8 +3729,6 @@ fn test_build_backend_uses_namespace() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2799,8 +3794,6 @@ fn test_build_backend_implicit_package_no_package_metadata() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2863,8 +3856,6 @@ fn test_build_backend_implicit_package_no_package_metadata_uv() {
 
     ----- stderr -----
     Successfully migrated project from Poetry to uv!
-
-    warning: Build backend was migrated to uv. It is highly recommended to check that files and data included in the source distribution and wheels are the same after the migration.
     ");
 
     apply_filters!();
@@ -2960,3 +3951,56 @@ fn test_build_backend_implicit_package_no_package_metadata_hatch() {
     assert_eq!(sdist_files_before, sdist_files_after);
     assert_eq!(wheel_files_before, wheel_files_after);
 }
+
+#[test]
+fn test_license_free_form_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("license/free_form");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
+    license = "MIT"
+    license-files = [
+        "COPYING.txt",
+        "LICENSE",
+    ]
+    classifiers = ["Development Status :: 5 - Production/Stable"]
+
+    warning: License "MIT License" from "poetry.license" was converted to SPDX license expression "MIT".
+    "#);
+}
+
+#[test]
+fn test_license_unknown_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("license/unknown");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [project]
+    name = "foobar"
+    version = "0.1.0"
+    description = "A fabulous project."
+    requires-python = ">=3.10"
+    license = { text = "My Own License" }
+    classifiers = [
+        "Development Status :: 5 - Production/Stable",
+        "License :: Other/Proprietary License",
+    ]
+
+    warning: License "My Own License" from "poetry.license" is not a valid SPDX license expression, so it was migrated to "project.license.text", which is deprecated. Consider replacing it with an SPDX license expression (<https://spdx.org/licenses/>).
+    "#);
+}
@@ -0,0 +1,304 @@
+use crate::common::{apply_filters, cli};
+use dircpy::copy_dir;
+use insta_cmd::assert_cmd_snapshot;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+mod common;
+
+const FIXTURES_PATH: &str = "tests/fixtures/setuptools";
+
+#[test]
+fn test_complete_workflow() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("setup_py");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    apply_filters!();
+    assert_cmd_snapshot!(cli().arg(project_path), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Locking dependencies using "uv lock"...
+    Using [PYTHON_INTERPRETER]
+    Resolved [PACKAGES] packages in [TIME]
+    Successfully migrated project from setuptools to uv!
+    "#);
+
+    // Assert that previous package manager files are correctly removed.
+    assert!(!project_path.join("setup.py").exists());
+
+    // Assert that `uv.lock` file was generated.
+    assert!(project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_skip_lock_setup_cfg() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("setup_cfg");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from setuptools to uv!
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["setuptools>=61"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "foo"
+    version = "1.2.3"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.11"
+    readme = "README.md"
+    license = "MIT"
+    keywords = [
+        "foo",
+        "bar",
+    ]
+    classifiers = [
+        "Development Status :: 5 - Production/Stable",
+        "Programming Language :: Python :: 3",
+    ]
+    dependencies = [
+        "arrow>=1.2.3",
+        "requests[security]>=2.31 ; python_full_version >= '3.12'",
+    ]
+
+    [project.optional-dependencies]
+    http = [
+        "httpx>=0.28",
+        "certifi",
+    ]
+    test = ["pytest>=8"]
+
+    [project.urls]
+    Homepage = "https://example.com"
+    Repository = "https://github.com/foo/foo"
+    Changelog = "https://github.com/foo/foo/blob/main/CHANGELOG.md"
+
+    [project.scripts]
+    foo = "foo.cli:main"
+
+    [project.entry-points.pytest11]
+    foo = "foo.pytest_plugin"
+
+    [tool.setuptools]
+    package-dir = { "" = "src" }
+    include-package-data = true
+
+    [tool.setuptools.packages.find]
+    where = ["src"]
+    exclude = ["tests*"]
+    namespaces = false
+
+    [tool.setuptools.package-data]
+    foo = ["py.typed"]
+    "#);
+
+    // Assert that migrated sections are removed from `setup.cfg`, while other sections are kept.
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("setup.cfg")).unwrap(), @r"
+    [flake8]
+    max-line-length = 120
+    ");
+
+    // Assert that `setup.py` is removed.
+    assert!(!project_path.join("setup.py").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_skip_lock_setup_py() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("setup_py");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Successfully migrated project from setuptools to uv!
+    ");
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["setuptools>=61", "wheel"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "foo"
+    version = "1.2.3"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.11"
+    dependencies = [
+        "arrow>=1.2.3",
+        "requests[security]>=2.31 ; python_full_version >= '3.12'",
+    ]
+
+    [project.optional-dependencies]
+    http = [
+        "httpx>=0.28",
+        "certifi",
+    ]
+
+    [project.urls]
+    Homepage = "https://example.com"
+
+    [project.scripts]
+    foo = "foo.cli:main"
+
+    [tool.setuptools.packages.find]
+    exclude = [
+        "tests",
+        "tests.*",
+    ]
+    namespaces = false
+    "#);
+
+    // Assert that `setup.py` is removed.
+    assert!(!project_path.join("setup.py").exists());
+
+    // Assert that `uv.lock` file was not generated.
+    assert!(!project_path.join("uv.lock").exists());
+}
+
+#[test]
+fn test_non_static_arguments() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("non_static");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock"), @r#"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Could not automatically migrate the project to uv because of the following errors:
+    error: - "long_description" argument of "setup()" in "setup.py" is not a literal value, so it could not be statically extracted.
+    error: - "cmdclass" argument of "setup()" in "setup.py" is not a literal value, so it could not be statically extracted.
+    "#);
+
+    // Assert that `pyproject.toml` was not created.
+    assert!(!project_path.join("pyproject.toml").exists());
+
+    // Assert that `setup.py` is not removed.
+    assert!(project_path.join("setup.py").exists());
+}
+
+#[test]
+fn test_non_static_arguments_ignore_errors() {
+    let fixture_path = Path::new(FIXTURES_PATH).join("non_static");
+
+    let tmp_dir = tempdir().unwrap();
+    let project_path = tmp_dir.path();
+
+    copy_dir(fixture_path, project_path).unwrap();
+
+    assert_cmd_snapshot!(cli().arg(project_path).arg("--skip-lock").arg("--ignore-errors"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The following errors occurred during the migration:
+    error: - "long_description" argument of "setup()" in "setup.py" is not a literal value, so it could not be statically extracted.
+    error: - "cmdclass" argument of "setup()" in "setup.py" is not a literal value, so it could not be statically extracted.
+    Partially migrated project from setuptools to uv, as errors occurred during the migration.
+    "#);
+
+    insta::assert_snapshot!(fs::read_to_string(project_path.join("pyproject.toml")).unwrap(), @r#"
+    [build-system]
+    requires = ["setuptools>=61"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "foo"
+    version = "1.2.3"
+    dependencies = ["arrow>=1.2.3"]
+    "#);
+
+    // Assert that `setup.py` is not removed, as it holds arguments that were not migrated.
+    assert!(project_path.join("setup.py").exists());
+}
+
+#[test]
+fn test_dry_run() {
+    let project_path = Path::new(FIXTURES_PATH).join("setup_py");
+
+    assert_cmd_snapshot!(cli().arg(&project_path).arg("--dry-run"), @r#"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Migrated pyproject.toml:
+    [build-system]
+    requires = ["setuptools>=61", "wheel"]
+    build-backend = "setuptools.build_meta"
+
+    [project]
+    name = "foo"
+    version = "1.2.3"
+    description = "A fabulous project."
+    authors = [{ name = "John Doe", email = "john.doe@example.com" }]
+    requires-python = ">=3.11"
+    dependencies = [
+        "arrow>=1.2.3",
+        "requests[security]>=2.31 ; python_full_version >= '3.12'",
+    ]
+
+    [project.optional-dependencies]
+    http = [
+        "httpx>=0.28",
+        "certifi",
+    ]
+
+    [project.urls]
+    Homepage = "https://example.com"
+
+    [project.scripts]
+    foo = "foo.cli:main"
+
+    [tool.setuptools.packages.find]
+    exclude = [
+        "tests",
+        "tests.*",
+    ]
+    namespaces = false
+    "#);
+
+    // Assert that `pyproject.toml` was not created.
+    assert!(!project_path.join("pyproject.toml").exists());
+
+    // Assert that previous package manager files have not been removed.
+    assert!(project_path.join("setup.py").exists());
+}